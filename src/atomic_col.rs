@@ -0,0 +1,349 @@
+use crate::{
+    PinnedConcurrentCol,
+    errors::{PinnedConcurrentColError, expect_ok},
+    states::FilledState,
+};
+use core::{fmt::Debug, sync::atomic::Ordering};
+use orx_fixed_vec::ConcurrentFixedVec;
+use orx_pinned_vec::ConcurrentPinnedVec;
+use orx_split_vec::{ConcurrentSplitVec, Doubling, Linear};
+
+/// An atomic integer type which can be the element type of a [`ConcurrentAtomicCol`],
+/// such as `AtomicUsize` or `AtomicI64`.
+///
+/// The default value of the atomic type is zero; hence, new allocations of the collection are zeroed.
+pub trait AtomicInteger: Default + Send + Sync {
+    /// Underlying integer type.
+    type Value: Copy + Eq + Debug;
+
+    /// Loads the value; see `AtomicUsize::load`.
+    fn load(&self, order: Ordering) -> Self::Value;
+
+    /// Stores the value; see `AtomicUsize::store`.
+    fn store(&self, value: Self::Value, order: Ordering);
+
+    /// Stores the value and returns the previous value; see `AtomicUsize::swap`.
+    fn swap(&self, value: Self::Value, order: Ordering) -> Self::Value;
+
+    /// Adds to the current value and returns the previous value; see `AtomicUsize::fetch_add`.
+    fn fetch_add(&self, value: Self::Value, order: Ordering) -> Self::Value;
+
+    /// Subtracts from the current value and returns the previous value; see `AtomicUsize::fetch_sub`.
+    fn fetch_sub(&self, value: Self::Value, order: Ordering) -> Self::Value;
+
+    /// Stores `new` if the current value is `current`; see `AtomicUsize::compare_exchange`.
+    fn compare_exchange(
+        &self,
+        current: Self::Value,
+        new: Self::Value,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<Self::Value, Self::Value>;
+
+    /// Updates the value with `f` until it succeeds or `f` returns None; see `AtomicUsize::fetch_update`.
+    fn fetch_update<F>(
+        &self,
+        set_order: Ordering,
+        fetch_order: Ordering,
+        f: F,
+    ) -> Result<Self::Value, Self::Value>
+    where
+        F: FnMut(Self::Value) -> Option<Self::Value>;
+}
+
+macro_rules! impl_atomic_integer {
+    ($width:literal, $atomic:ident, $value:ty) => {
+        #[cfg(target_has_atomic = $width)]
+        impl AtomicInteger for core::sync::atomic::$atomic {
+            type Value = $value;
+
+            #[inline(always)]
+            fn load(&self, order: Ordering) -> Self::Value {
+                self.load(order)
+            }
+
+            #[inline(always)]
+            fn store(&self, value: Self::Value, order: Ordering) {
+                self.store(value, order)
+            }
+
+            #[inline(always)]
+            fn swap(&self, value: Self::Value, order: Ordering) -> Self::Value {
+                self.swap(value, order)
+            }
+
+            #[inline(always)]
+            fn fetch_add(&self, value: Self::Value, order: Ordering) -> Self::Value {
+                self.fetch_add(value, order)
+            }
+
+            #[inline(always)]
+            fn fetch_sub(&self, value: Self::Value, order: Ordering) -> Self::Value {
+                self.fetch_sub(value, order)
+            }
+
+            #[inline(always)]
+            fn compare_exchange(
+                &self,
+                current: Self::Value,
+                new: Self::Value,
+                success: Ordering,
+                failure: Ordering,
+            ) -> Result<Self::Value, Self::Value> {
+                self.compare_exchange(current, new, success, failure)
+            }
+
+            #[inline(always)]
+            fn fetch_update<F>(
+                &self,
+                set_order: Ordering,
+                fetch_order: Ordering,
+                f: F,
+            ) -> Result<Self::Value, Self::Value>
+            where
+                F: FnMut(Self::Value) -> Option<Self::Value>,
+            {
+                self.fetch_update(set_order, fetch_order, f)
+            }
+        }
+    };
+}
+
+impl_atomic_integer!("8", AtomicU8, u8);
+impl_atomic_integer!("16", AtomicU16, u16);
+impl_atomic_integer!("32", AtomicU32, u32);
+impl_atomic_integer!("64", AtomicU64, u64);
+impl_atomic_integer!("ptr", AtomicUsize, usize);
+impl_atomic_integer!("8", AtomicI8, i8);
+impl_atomic_integer!("16", AtomicI16, i16);
+impl_atomic_integer!("32", AtomicI32, i32);
+impl_atomic_integer!("64", AtomicI64, i64);
+impl_atomic_integer!("ptr", AtomicIsize, isize);
+
+/// A safe concurrent collection of atomic integers built on top of a [`PinnedConcurrentCol`], which can be updated in place through a shared reference.
+///
+/// * Every position is conceptually zero until it is updated: the memory is zeroed on allocation through the [`FilledState`].
+/// * Updating a position beyond the current capacity grows the collection following the write permit protocol of the state.
+/// * Reading a position which is not yet allocated yields zero without growing the collection.
+///
+/// The read-modify-write methods use `AcqRel` ordering, loads use `Acquire` and stores use `Release` ordering.
+/// A reference to the atomic at a position can be obtained by [`ConcurrentAtomicCol::atomic`] to use other orderings.
+///
+/// The collection satisfies the safety requirements of the core since every allocated position holds a valid atomic
+/// which is never written by the core once allocated, and is only mutated through its atomic methods.
+///
+/// # Examples
+///
+/// ```
+/// use orx_pinned_concurrent_col::prelude::*;
+/// use std::sync::atomic::AtomicU64;
+///
+/// let histogram: ConcurrentAtomicCol<AtomicU64> = ConcurrentAtomicCol::new();
+///
+/// std::thread::scope(|s| {
+///     for t in 0..4 {
+///         let histogram = &histogram;
+///         s.spawn(move || {
+///             for i in 0..100 {
+///                 histogram.fetch_add((t + i) % 10, 1);
+///             }
+///         });
+///     }
+/// });
+///
+/// assert_eq!(histogram.load(3), 40);
+/// assert_eq!(histogram.load(1000), 0);
+/// assert_eq!(histogram.iter().map(|x| x.load(std::sync::atomic::Ordering::Relaxed)).sum::<u64>(), 400);
+///
+/// assert_eq!(histogram.compare_exchange(3, 40, 0), Ok(40));
+/// assert_eq!(histogram.fetch_update(5, |x| x.checked_sub(50)), Err(40));
+/// ```
+pub struct ConcurrentAtomicCol<A, P = ConcurrentSplitVec<A, Doubling>>
+where
+    A: AtomicInteger,
+    P: ConcurrentPinnedVec<A>,
+{
+    col: PinnedConcurrentCol<A, P, FilledState<A>>,
+}
+
+unsafe impl<A: AtomicInteger, P: ConcurrentPinnedVec<A>> Send for ConcurrentAtomicCol<A, P> {}
+
+unsafe impl<A: AtomicInteger, P: ConcurrentPinnedVec<A>> Sync for ConcurrentAtomicCol<A, P> {}
+
+impl<A: AtomicInteger> ConcurrentAtomicCol<A, ConcurrentSplitVec<A, Doubling>> {
+    /// Creates a new collection using a `SplitVec<A, Doubling>` as the underlying storage.
+    pub fn new() -> Self {
+        Self::with_doubling_growth()
+    }
+
+    /// Creates a new collection using a `SplitVec<A, Doubling>` as the underlying storage.
+    pub fn with_doubling_growth() -> Self {
+        Self {
+            col: PinnedConcurrentCol::with_doubling_growth(),
+        }
+    }
+}
+
+impl<A: AtomicInteger> Default for ConcurrentAtomicCol<A, ConcurrentSplitVec<A, Doubling>> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<A: AtomicInteger> ConcurrentAtomicCol<A, ConcurrentSplitVec<A, Linear>> {
+    /// Creates a new collection using a `SplitVec<A, Linear>` as the underlying storage.
+    ///
+    /// See [`PinnedConcurrentCol::with_linear_growth`] for details of the arguments.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `fragments_capacity == 0`.
+    pub fn with_linear_growth(
+        constant_fragment_capacity_exponent: usize,
+        fragments_capacity: usize,
+    ) -> Self {
+        Self {
+            col: PinnedConcurrentCol::with_linear_growth(
+                constant_fragment_capacity_exponent,
+                fragments_capacity,
+            ),
+        }
+    }
+}
+
+impl<A: AtomicInteger> ConcurrentAtomicCol<A, ConcurrentFixedVec<A>> {
+    /// Creates a new collection using a `FixedVec<A>` as the underlying storage.
+    ///
+    /// Note that positions at or beyond the `fixed_capacity` cannot be updated.
+    pub fn with_fixed_capacity(fixed_capacity: usize) -> Self {
+        Self {
+            col: PinnedConcurrentCol::with_fixed_capacity(fixed_capacity),
+        }
+    }
+}
+
+impl<A, P> ConcurrentAtomicCol<A, P>
+where
+    A: AtomicInteger,
+    P: ConcurrentPinnedVec<A>,
+{
+    /// Returns the current allocated capacity of the collection.
+    pub fn capacity(&self) -> usize {
+        self.col.capacity()
+    }
+
+    /// Returns maximum possible capacity that the collection can concurrently grow to.
+    pub fn maximum_capacity(&self) -> usize {
+        self.col.maximum_capacity()
+    }
+
+    /// Returns a reference to the atomic at the `idx`-th position if it is allocated; returns None otherwise.
+    pub fn get(&self, idx: usize) -> Option<&A> {
+        // SAFETY: all allocated positions are filled with zeroed atomics
+        unsafe { self.col.get(idx) }
+    }
+
+    /// Returns a reference to the atomic at the `idx`-th position, growing the collection whenever necessary.
+    ///
+    /// See [`ConcurrentAtomicCol::try_atomic`] for the fallible version of the method.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `idx`-th position cannot be allocated; i.e., if [`ConcurrentAtomicCol::try_atomic`] returns an error.
+    pub fn atomic(&self, idx: usize) -> &A {
+        expect_ok(self.try_atomic(idx))
+    }
+
+    /// Returns a reference to the atomic at the `idx`-th position, growing the collection whenever necessary;
+    /// returns an error rather than panicking if the position cannot be allocated.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`PinnedConcurrentCol::try_write`] for the `idx`-th position.
+    pub fn try_atomic(&self, idx: usize) -> Result<&A, PinnedConcurrentColError> {
+        self.col.try_allocate(idx)?;
+        // SAFETY: the position is allocated, and all allocated positions are filled with zeroed atomics
+        Ok(unsafe { &*self.col.ptr_mut_at(idx) })
+    }
+
+    /// Returns an iterator over the atomics of all allocated positions.
+    pub fn iter(&self) -> impl Iterator<Item = &A> {
+        (0..self.capacity()).filter_map(|idx| self.get(idx))
+    }
+
+    /// Loads the value at the `idx`-th position; returns zero if the position is not allocated yet.
+    pub fn load(&self, idx: usize) -> A::Value {
+        match self.get(idx) {
+            Some(atomic) => atomic.load(Ordering::Acquire),
+            None => A::default().load(Ordering::Relaxed),
+        }
+    }
+
+    /// Stores the `value` to the `idx`-th position.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `idx`-th position cannot be allocated.
+    pub fn store(&self, idx: usize, value: A::Value) {
+        self.atomic(idx).store(value, Ordering::Release)
+    }
+
+    /// Stores the `value` to the `idx`-th position and returns the previous value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `idx`-th position cannot be allocated.
+    pub fn swap(&self, idx: usize, value: A::Value) -> A::Value {
+        self.atomic(idx).swap(value, Ordering::AcqRel)
+    }
+
+    /// Adds the `value` to the `idx`-th position and returns the previous value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `idx`-th position cannot be allocated.
+    pub fn fetch_add(&self, idx: usize, value: A::Value) -> A::Value {
+        self.atomic(idx).fetch_add(value, Ordering::AcqRel)
+    }
+
+    /// Subtracts the `value` from the `idx`-th position and returns the previous value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `idx`-th position cannot be allocated.
+    pub fn fetch_sub(&self, idx: usize, value: A::Value) -> A::Value {
+        self.atomic(idx).fetch_sub(value, Ordering::AcqRel)
+    }
+
+    /// Stores `new` to the `idx`-th position if its value is `current`.
+    ///
+    /// Returns the previous value, which is `Ok(current)` if the value is updated, and `Err(previous)` otherwise.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `idx`-th position cannot be allocated.
+    pub fn compare_exchange(
+        &self,
+        idx: usize,
+        current: A::Value,
+        new: A::Value,
+    ) -> Result<A::Value, A::Value> {
+        self.atomic(idx)
+            .compare_exchange(current, new, Ordering::AcqRel, Ordering::Acquire)
+    }
+
+    /// Updates the value at the `idx`-th position with `f`, retrying until it succeeds or `f` returns None.
+    ///
+    /// Returns `Ok(previous)` if the value is updated, and `Err(previous)` if `f` returns None.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `idx`-th position cannot be allocated.
+    pub fn fetch_update<F>(&self, idx: usize, f: F) -> Result<A::Value, A::Value>
+    where
+        F: FnMut(A::Value) -> Option<A::Value>,
+    {
+        self.atomic(idx)
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, f)
+    }
+}
@@ -0,0 +1,276 @@
+use crate::{
+    PinnedConcurrentCol, errors::PinnedConcurrentColError, growth_policy::GrowthPolicy,
+    memory_fill::MemoryFill, spin::SpinStrategy, state::ConcurrentState,
+};
+use core::marker::PhantomData;
+use orx_fixed_vec::FixedVec;
+use orx_pinned_vec::{CapacityState, IntoConcurrentPinnedVec};
+use orx_split_vec::{Doubling, Linear, SplitVec};
+
+/// Builder of a [`PinnedConcurrentCol`] which allows configuring the collection from a single entry point.
+///
+/// The builder covers:
+/// * the underlying storage: [`with_doubling_growth`](PinnedConcurrentColBuilder::with_doubling_growth) (default),
+///   [`with_linear_growth`](PinnedConcurrentColBuilder::with_linear_growth), [`with_fixed_capacity`](PinnedConcurrentColBuilder::with_fixed_capacity)
+///   or any pinned vector with [`with_pinned_vec`](PinnedConcurrentColBuilder::with_pinned_vec);
+/// * the [`initial_capacity`](PinnedConcurrentColBuilder::initial_capacity) to be allocated up front;
+/// * the [`maximum_capacity`](PinnedConcurrentColBuilder::maximum_capacity) that the collection must be able to concurrently grow to;
+/// * the [`MemoryFill`] to fill new allocations with, overwriting the [`ConcurrentState::fill_memory_with`];
+/// * the [`spin_strategy`](PinnedConcurrentColBuilder::spin_strategy), overwriting the [`ConcurrentState::spin_strategy`]; and
+/// * the [`growth_policy`](PinnedConcurrentColBuilder::growth_policy), overwriting the [`ConcurrentState::growth_policy`].
+///
+/// The combinations are validated by [`build`](PinnedConcurrentColBuilder::build), which returns an error rather than panicking.
+///
+/// # Examples
+///
+/// ```
+/// use orx_pinned_concurrent_col::prelude::*;
+///
+/// let col: PinnedConcurrentCol<Option<u64>, _, FilledState<_>> = PinnedConcurrentColBuilder::new()
+///     .with_linear_growth(10, 4)
+///     .initial_capacity(2048)
+///     .maximum_capacity(100_000)
+///     .spin_strategy(SpinStrategy::ExponentialBackoff { max_exponent: 6 })
+///     .build()
+///     .expect("valid configuration");
+///
+/// assert_eq!(col.capacity(), 2048);
+/// assert!(col.maximum_capacity() >= 100_000);
+/// assert_eq!(unsafe { col.get(2047) }, Some(&None));
+///
+/// let result = PinnedConcurrentColBuilder::<Option<u64>, FilledState<_>>::new()
+///     .without_memory_fill()
+///     .build();
+/// assert_eq!(result.err(), Some(PinnedConcurrentColError::MissingMemoryFill));
+/// ```
+pub struct PinnedConcurrentColBuilder<T, S, V = SplitVec<T, Doubling>>
+where
+    S: ConcurrentState<T>,
+    V: IntoConcurrentPinnedVec<T>,
+{
+    pinned_vec: V,
+    initial_capacity: usize,
+    maximum_capacity: Option<usize>,
+    fill_memory_with: Option<Option<MemoryFill<T>>>,
+    spin_strategy: Option<SpinStrategy>,
+    growth_policy: Option<GrowthPolicy>,
+    phantom: PhantomData<S>,
+}
+
+impl<T, S> PinnedConcurrentColBuilder<T, S, SplitVec<T, Doubling>>
+where
+    S: ConcurrentState<T>,
+{
+    /// Creates a new builder with the default configuration:
+    /// * the storage is a `SplitVec<T, Doubling>`,
+    /// * no capacity is allocated up front,
+    /// * memory fill and spin strategy are defined by the concurrent state.
+    pub fn new() -> Self {
+        Self::from_pinned_vec(SplitVec::with_doubling_growth_and_max_concurrent_capacity())
+    }
+}
+
+impl<T, S> Default for PinnedConcurrentColBuilder<T, S, SplitVec<T, Doubling>>
+where
+    S: ConcurrentState<T>,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, S, V> PinnedConcurrentColBuilder<T, S, V>
+where
+    S: ConcurrentState<T>,
+    V: IntoConcurrentPinnedVec<T>,
+{
+    fn from_pinned_vec(pinned_vec: V) -> Self {
+        Self {
+            pinned_vec,
+            initial_capacity: 0,
+            maximum_capacity: None,
+            fill_memory_with: None,
+            spin_strategy: None,
+            growth_policy: None,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Uses the `pinned_vec` as the underlying storage; the elements already in the vector are kept in the collection.
+    pub fn with_pinned_vec<W>(self, pinned_vec: W) -> PinnedConcurrentColBuilder<T, S, W>
+    where
+        W: IntoConcurrentPinnedVec<T>,
+    {
+        PinnedConcurrentColBuilder {
+            pinned_vec,
+            initial_capacity: self.initial_capacity,
+            maximum_capacity: self.maximum_capacity,
+            fill_memory_with: self.fill_memory_with,
+            spin_strategy: self.spin_strategy,
+            growth_policy: self.growth_policy,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Uses a new `SplitVec<T, Doubling>` as the underlying storage; see [`PinnedConcurrentCol::with_doubling_growth`].
+    pub fn with_doubling_growth(self) -> PinnedConcurrentColBuilder<T, S, SplitVec<T, Doubling>> {
+        self.with_pinned_vec(SplitVec::with_doubling_growth_and_max_concurrent_capacity())
+    }
+
+    /// Uses a new `SplitVec<T, Linear>` as the underlying storage; see [`PinnedConcurrentCol::with_linear_growth`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `fragments_capacity == 0`.
+    pub fn with_linear_growth(
+        self,
+        constant_fragment_capacity_exponent: usize,
+        fragments_capacity: usize,
+    ) -> PinnedConcurrentColBuilder<T, S, SplitVec<T, Linear>> {
+        self.with_pinned_vec(SplitVec::with_linear_growth_and_fragments_capacity(
+            constant_fragment_capacity_exponent,
+            fragments_capacity,
+        ))
+    }
+
+    /// Uses a new `FixedVec<T>` as the underlying storage; see [`PinnedConcurrentCol::with_fixed_capacity`].
+    pub fn with_fixed_capacity(
+        self,
+        fixed_capacity: usize,
+    ) -> PinnedConcurrentColBuilder<T, S, FixedVec<T>> {
+        self.with_pinned_vec(FixedVec::new(fixed_capacity))
+    }
+
+    /// Sets the capacity to be allocated up front, so that the first writers do not race into growing the collection.
+    pub fn initial_capacity(mut self, initial_capacity: usize) -> Self {
+        self.initial_capacity = initial_capacity;
+        self
+    }
+
+    /// Sets the maximum capacity that the collection must be able to concurrently grow to.
+    ///
+    /// The maximum capacity of a dynamic storage is increased by [`PinnedConcurrentCol::reserve_maximum_capacity`] whenever required;
+    /// building fails if the storage cannot reach the required maximum capacity, such as a `FixedVec` with a smaller fixed capacity.
+    pub fn maximum_capacity(mut self, maximum_capacity: usize) -> Self {
+        self.maximum_capacity = Some(maximum_capacity);
+        self
+    }
+
+    /// Sets the memory fill that new allocations of the underlying storage are filled with;
+    /// overwriting the memory fill defined by the concurrent state, [`ConcurrentState::fill_memory_with`].
+    pub fn memory_fill(mut self, memory_fill: MemoryFill<T>) -> Self {
+        self.fill_memory_with = Some(Some(memory_fill));
+        self
+    }
+
+    /// Fills new allocations of the underlying storage with `fill_with()`; see [`MemoryFill::from_closure`].
+    pub fn fill_memory_with<F>(self, fill_with: F) -> Self
+    where
+        F: Fn() -> T + Send + Sync + 'static,
+    {
+        self.memory_fill(MemoryFill::from_closure(fill_with))
+    }
+
+    /// Fills new allocations of the underlying storage with clones of the `value`; see [`MemoryFill::from_value`].
+    pub fn fill_memory_with_value(self, value: T) -> Self
+    where
+        T: Clone + Send + Sync + 'static,
+    {
+        self.memory_fill(MemoryFill::from_value(value))
+    }
+
+    /// Fills new allocations of the underlying storage with `T::default()`; see [`MemoryFill::from_default`].
+    pub fn fill_memory_with_default(self) -> Self
+    where
+        T: Default,
+    {
+        self.memory_fill(MemoryFill::from_default())
+    }
+
+    /// Disables filling new allocations of the underlying storage.
+    ///
+    /// Note that building fails if the concurrent state requires the memory to be filled.
+    pub fn without_memory_fill(mut self) -> Self {
+        self.fill_memory_with = Some(None);
+        self
+    }
+
+    /// Sets the strategy that threads follow while waiting on a [`WritePermit::Spin`](crate::WritePermit::Spin);
+    /// overwriting the strategy defined by the concurrent state, [`ConcurrentState::spin_strategy`].
+    pub fn spin_strategy(mut self, spin_strategy: SpinStrategy) -> Self {
+        self.spin_strategy = Some(spin_strategy);
+        self
+    }
+
+    /// Sets the policy determining when the collection allocates new positions;
+    /// overwriting the policy defined by the concurrent state, [`ConcurrentState::growth_policy`].
+    pub fn growth_policy(mut self, growth_policy: GrowthPolicy) -> Self {
+        self.growth_policy = Some(growth_policy);
+        self
+    }
+
+    /// Validates the configuration and builds the collection.
+    ///
+    /// # Errors
+    ///
+    /// * [`PinnedConcurrentColError::MissingMemoryFill`] if the memory fill is disabled while the concurrent state requires it,
+    /// * [`PinnedConcurrentColError::InvalidGrowthPolicy`] if the growth policy is not valid, see [`GrowthPolicy::is_valid`],
+    /// * [`PinnedConcurrentColError::OutOfMaximumCapacity`] if the storage cannot reach the required maximum capacity or initial capacity,
+    /// * [`PinnedConcurrentColError::FailedToGrow`] if the storage fails to allocate the initial capacity.
+    pub fn build(
+        self,
+    ) -> Result<PinnedConcurrentCol<T, V::ConPinnedVec, S>, PinnedConcurrentColError> {
+        let len = self.pinned_vec.len();
+        let is_fixed_capacity = matches!(
+            self.pinned_vec.capacity_state(),
+            CapacityState::FixedCapacity(_)
+        );
+        let state = S::new_for_pinned_vec(&self.pinned_vec);
+
+        let fill_memory_with = match self.fill_memory_with {
+            Some(fill_memory_with) => fill_memory_with,
+            None => state.fill_memory_with(),
+        };
+        if fill_memory_with.is_none() && state.fill_memory_with().is_some() {
+            return Err(PinnedConcurrentColError::MissingMemoryFill);
+        }
+
+        if let Some(growth_policy) = self.growth_policy
+            && !growth_policy.is_valid()
+        {
+            return Err(PinnedConcurrentColError::InvalidGrowthPolicy);
+        }
+
+        let mut col = PinnedConcurrentCol::new_from_pinned_and_state(
+            self.pinned_vec,
+            state,
+            fill_memory_with,
+        );
+
+        if let Some(spin_strategy) = self.spin_strategy {
+            col.set_spin_strategy(spin_strategy);
+        }
+
+        if let Some(growth_policy) = self.growth_policy {
+            col.set_growth_policy(growth_policy);
+        }
+
+        if let Some(maximum_capacity) = self.maximum_capacity
+            && maximum_capacity > col.maximum_capacity()
+        {
+            let reserved = match is_fixed_capacity {
+                true => col.maximum_capacity(),
+                // SAFETY: the collection is gap-free since the elements of the pinned vector are the only elements
+                false => unsafe { col.reserve_maximum_capacity(len, maximum_capacity) },
+            };
+            if reserved < maximum_capacity {
+                return Err(PinnedConcurrentColError::OutOfMaximumCapacity {
+                    required_capacity: maximum_capacity,
+                    maximum_capacity: reserved,
+                });
+            }
+        }
+
+        col.with_allocated_capacity(len, self.initial_capacity)
+    }
+}
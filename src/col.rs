@@ -1,10 +1,35 @@
 use crate::{
-    errors::*, mem_state::VecDropState, state::ConcurrentState, write_permit::WritePermit,
+    committed::CommittedLen,
+    errors::{PinnedConcurrentColError, WriteError, expect_ok},
+    growth_policy::GrowthPolicy,
+    local_writer::LocalWriter,
+    mem_state::VecDropState,
+    memory_fill::MemoryFill,
+    reservation::Reservation,
+    rollback::WriteRollback,
+    slice_producer::SliceProducer,
+    spin::SpinStrategy,
+    state::{ConcurrentState, PublishedState},
+    sync::{AtomicUsize, Ordering},
+    write_permit::WritePermit,
+    written::WrittenPositions,
+};
+use alloc::vec::Vec;
+use core::{
+    marker::PhantomData,
+    ops::{Range, RangeBounds},
 };
-use core::{marker::PhantomData, ops::RangeBounds};
 use orx_pinned_vec::{ConcurrentPinnedVec, IntoConcurrentPinnedVec};
 use orx_pseudo_default::PseudoDefault;
 
+#[cfg(feature = "metrics")]
+use crate::metrics::{ColStats, FillTimer, Metrics, Timer};
+
+#[cfg(feature = "debug_checks")]
+use crate::debug_checks::DebugChecks;
+#[cfg(feature = "debug_checks")]
+use orx_pinned_vec::utils::slice::vec_range_limits;
+
 /// A core data structure with a focus to enable high performance, possibly lock-free, concurrent collections using a [`PinnedVec`](https://crates.io/crates/orx-pinned-vec) as the underlying storage.
 ///
 /// Pinned vectors grow while keeping the already pushed elements pinned to their memory locations. This allows the following concurrency model.
@@ -12,6 +37,8 @@ use orx_pseudo_default::PseudoDefault;
 /// * Writing to the collection does not block. Multiple writes can happen concurrently.
 ///   * However, `PinnedConcurrentCol` itself does not provide guarantees for race-free writing; and hence, the write methods are marked `unsafe`.
 ///   * It is the responsibility of the wrapper to make sure that multiple writes or reading during write to the same position do not happen concurrently.
+///   * With the `debug_checks` feature, the collection keeps a shadow state of the positions and panics on repeated writes to the same position,
+///     on reads of never-written positions and on taking out the vector with never-written positions, which helps validating wrappers in tests.
 /// * Only one growth (capacity expansion) can happen at a given time.
 ///   * If the underlying collection reaches its capacity and needs to grow, one and only one thread takes the responsibility to expand the vector.
 /// * Growth does not block.
@@ -28,6 +55,16 @@ where
     con_pinned_vec: P,
     state: S,
     vec_drop_state: VecDropState,
+    spin_strategy: SpinStrategy,
+    growth_policy: GrowthPolicy,
+    latest_allocation_begin: AtomicUsize,
+    fill_memory_with: Option<MemoryFill<T>>,
+    written: Option<WrittenPositions>,
+    committed: Option<CommittedLen>,
+    #[cfg(feature = "metrics")]
+    metrics: Metrics,
+    #[cfg(feature = "debug_checks")]
+    debug_checks: DebugChecks,
 }
 
 impl<T, P, S> Drop for PinnedConcurrentCol<T, P, S>
@@ -38,19 +75,24 @@ where
     fn drop(&mut self) {
         match self.vec_drop_state {
             VecDropState::ToBeDropped => {
-                let len = match self.state().fill_memory_with().is_some() {
+                let len = match self.fill_memory_with.is_some() {
                     true => self.con_pinned_vec.capacity(),
+                    false if self.written.is_some() => {
+                        self.drop_written_in(0..self.con_pinned_vec.capacity());
+                        0
+                    }
                     false => {
                         let capacity = self.con_pinned_vec.capacity();
                         let no_gap_len = self.state.try_get_no_gap_len().unwrap_or(capacity);
-                        [no_gap_len].into_iter().fold(capacity, usize::min)
+                        let len = [no_gap_len].into_iter().fold(capacity, usize::min);
+                        self.drop_after_consumed(len)
                     }
                 };
                 self.vec_drop_state = VecDropState::TakenOut;
                 unsafe { self.con_pinned_vec.set_pinned_vec_len(len) };
             }
             VecDropState::TakenOut => {
-                let len = match self.state().fill_memory_with().is_some() {
+                let len = match self.fill_memory_with.is_some() {
                     true => self.con_pinned_vec.capacity(),
                     false => 0,
                 };
@@ -72,17 +114,50 @@ where
         Q: IntoConcurrentPinnedVec<T, ConPinnedVec = P>,
     {
         let state = S::new_for_pinned_vec(&pinned_vec);
+        let fill_memory_with = state.fill_memory_with();
+        Self::new_from_pinned_and_state(pinned_vec, state, fill_memory_with)
+    }
 
-        let con_pinned_vec = match state.fill_memory_with() {
+    /// Wraps the `pinned_vec` and converts it into a pinned concurrent collection with the given `state`,
+    /// where new allocations are filled by `fill_memory_with` rather than the function defined by the state.
+    pub(crate) fn new_from_pinned_and_state<Q>(
+        pinned_vec: Q,
+        state: S,
+        fill_memory_with: Option<MemoryFill<T>>,
+    ) -> Self
+    where
+        Q: IntoConcurrentPinnedVec<T, ConPinnedVec = P>,
+    {
+        let spin_strategy = state.spin_strategy();
+        let growth_policy = state.growth_policy();
+        let written_len = pinned_vec.len();
+
+        let con_pinned_vec = match &fill_memory_with {
             None => pinned_vec.into_concurrent(),
-            Some(f) => pinned_vec.into_concurrent_filled_with(f),
+            Some(f) => pinned_vec.into_concurrent_filled_with(|| f.fill()),
         };
 
+        state.update_after_growth(con_pinned_vec.capacity());
+        let written = Self::new_written_positions(&state, &con_pinned_vec, written_len);
+        let committed = Self::new_committed_len(&state, &con_pinned_vec, written_len);
+        #[cfg(feature = "debug_checks")]
+        let debug_checks = DebugChecks::new(con_pinned_vec.capacity(), written_len);
+
         Self {
             phantom: Default::default(),
             state,
             con_pinned_vec,
             vec_drop_state: VecDropState::ToBeDropped,
+            spin_strategy,
+            growth_policy,
+            latest_allocation_begin: AtomicUsize::new(0),
+            fill_memory_with,
+            written,
+            committed,
+            #[cfg(feature = "metrics")]
+            metrics: Metrics::default(),
+            #[cfg(feature = "debug_checks")]
+            debug_checks,
         }
     }
 
@@ -113,16 +188,29 @@ where
     /// - Concurrent bag and vector do not allow leaving gaps, and only push to the back of the collection.
     /// - Furthermore, they keep track of the number of pushes.
     /// - Therefore, they can safely extract the pinned vector out with the length that it correctly knows.
+    ///
+    /// When the collection keeps track of its written positions (see [`ConcurrentState::track_written_positions`]),
+    /// the written elements beyond `pinned_vec_len` are dropped before the vector is taken out.
+    /// Further, [`PinnedConcurrentCol::try_into_inner`] can be used to verify that the prefix is gap-free.
     pub unsafe fn into_inner(mut self, pinned_vec_len: usize) -> P::P
     where
         P::P: IntoConcurrentPinnedVec<T, ConPinnedVec = P>,
     {
+        #[cfg(feature = "debug_checks")]
+        if self.fill_memory_with.is_none() {
+            self.debug_checks.check_len(pinned_vec_len);
+        }
+
+        if self.fill_memory_with.is_none() {
+            self.drop_written_in(pinned_vec_len..self.con_pinned_vec.capacity());
+        }
+
         self.vec_drop_state = VecDropState::TakenOut;
 
         let mut inner = <P::P as PseudoDefault>::pseudo_default().into_concurrent();
-        match self.state.fill_memory_with() {
+        match &self.fill_memory_with {
             Some(fill_with) => {
-                inner.fill_with(0..inner.capacity(), fill_with);
+                inner.fill_with(0..inner.capacity(), || fill_with.fill());
                 unsafe { inner.set_pinned_vec_len(inner.capacity()) };
             }
             None => unsafe { inner.set_pinned_vec_len(0) },
@@ -133,6 +221,37 @@ where
         unsafe { inner.into_inner(pinned_vec_len) }
     }
 
+    /// Sets the length of the underlying pinned vector to the given `pinned_vec_len` and returns the vector
+    /// provided that all positions in `0..pinned_vec_len` are known to be written.
+    ///
+    /// The positions are known to be written if any of the following holds:
+    /// * the memory is filled (see [`PinnedConcurrentCol::fill_memory_with`]), in which case all positions within capacity are valid;
+    /// * the collection keeps track of its written positions (see [`ConcurrentState::track_written_positions`]) and all positions are marked as written;
+    /// * otherwise, `pinned_vec_len` does not exceed the no-gap length reported by [`ConcurrentState::try_get_no_gap_len`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PinnedConcurrentColError::UnwrittenPosition`] with the first position which is not known to be written.
+    /// In this case, the collection is dropped, dropping only the elements known to be written.
+    pub fn try_into_inner(self, pinned_vec_len: usize) -> Result<P::P, PinnedConcurrentColError>
+    where
+        P::P: IntoConcurrentPinnedVec<T, ConPinnedVec = P>,
+    {
+        let no_gap_len = match (&self.written, &self.fill_memory_with) {
+            (_, Some(_)) => self.con_pinned_vec.capacity(),
+            (Some(written), None) => written.first_unmarked(0..pinned_vec_len),
+            (None, None) if self.num_consumed() > 0 => 0,
+            (None, None) => self.state.try_get_no_gap_len().unwrap_or(0),
+        };
+        let no_gap_len = no_gap_len.min(self.con_pinned_vec.capacity());
+
+        match pinned_vec_len <= no_gap_len {
+            // SAFETY: all positions in 0..pinned_vec_len are known to be written
+            true => Ok(unsafe { self.into_inner(pinned_vec_len) }),
+            false => Err(PinnedConcurrentColError::UnwrittenPosition { idx: no_gap_len }),
+        }
+    }
+
     /// Clones the underlying pinned vector, sets its length to the given `pinned_vec_len` and returns the vector.
     ///
     /// # Safety
@@ -162,18 +281,35 @@ where
     where
         T: Clone,
     {
+        #[cfg(feature = "debug_checks")]
+        self.debug_check_read(0..pinned_vec_len);
+
         let con_pinned_vec = unsafe { self.con_pinned_vec.clone_with_len(pinned_vec_len) };
-        if let Some(fill_with) = self.state.fill_memory_with() {
+        if let Some(fill_with) = &self.fill_memory_with {
             let range_to_fill = pinned_vec_len..con_pinned_vec.capacity();
-            con_pinned_vec.fill_with(range_to_fill, fill_with);
+            con_pinned_vec.fill_with(range_to_fill, || fill_with.fill());
         }
 
         let state = S::new_for_con_pinned_vec(&con_pinned_vec, pinned_vec_len);
+        let written = Self::new_written_positions(&state, &con_pinned_vec, pinned_vec_len);
+        let committed = Self::new_committed_len(&state, &con_pinned_vec, pinned_vec_len);
+        #[cfg(feature = "debug_checks")]
+        let debug_checks = DebugChecks::new(con_pinned_vec.capacity(), pinned_vec_len);
         Self {
             phantom: Default::default(),
             state,
             con_pinned_vec,
             vec_drop_state: VecDropState::ToBeDropped,
+            spin_strategy: self.spin_strategy,
+            growth_policy: self.growth_policy,
+            latest_allocation_begin: AtomicUsize::new(0),
+            fill_memory_with: self.fill_memory_with.clone(),
+            written,
+            committed,
+            #[cfg(feature = "metrics")]
+            metrics: Metrics::default(),
+            #[cfg(feature = "debug_checks")]
+            debug_checks,
         }
     }
 
@@ -197,6 +333,124 @@ where
         self.con_pinned_vec.max_capacity()
     }
 
+    /// Returns the memory fill that new allocations of the underlying pinned vector are filled with; None if the memory is not filled.
+    ///
+    /// Initially, this is the function defined by the concurrent state, [`ConcurrentState::fill_memory_with`],
+    /// unless it is overwritten while building the collection with [`PinnedConcurrentColBuilder::fill_memory_with`](crate::PinnedConcurrentColBuilder::fill_memory_with).
+    pub fn fill_memory_with(&self) -> Option<&MemoryFill<T>> {
+        self.fill_memory_with.as_ref()
+    }
+
+    /// Returns the strategy that threads follow while waiting on a [`WritePermit::Spin`].
+    ///
+    /// Initially, this is the strategy defined by the concurrent state, [`ConcurrentState::spin_strategy`].
+    pub fn spin_strategy(&self) -> SpinStrategy {
+        self.spin_strategy
+    }
+
+    /// Returns a snapshot of the growth and contention counters of the collection.
+    #[cfg(feature = "metrics")]
+    pub fn stats(&self) -> ColStats {
+        self.metrics.stats()
+    }
+
+    /// Returns the policy determining when the collection allocates new positions.
+    ///
+    /// Initially, this is the policy defined by the concurrent state, [`ConcurrentState::growth_policy`].
+    pub fn growth_policy(&self) -> GrowthPolicy {
+        self.growth_policy
+    }
+
+    /// Returns the range of positions allocated by the latest growth of the collection, which ends at the current capacity.
+    ///
+    /// Before the first concurrent growth, the range covers the entire capacity.
+    /// The [`GrowthPolicy::GrowAhead`] threshold is measured within this range; see [`GrowthPolicy::grows_ahead`].
+    pub fn latest_allocation(&self) -> Range<usize> {
+        let begin = self.latest_allocation_begin.load(Ordering::Acquire);
+        let capacity = self.con_pinned_vec.capacity();
+        begin.min(capacity)..capacity
+    }
+
+    /// Returns whether or not the collection keeps track of its written positions; see [`ConcurrentState::track_written_positions`].
+    pub fn tracks_written_positions(&self) -> bool {
+        self.written.is_some()
+    }
+
+    /// Returns whether or not the `idx`-th position is written, provided that the collection keeps track of its written positions;
+    /// returns None otherwise.
+    ///
+    /// Note that positions reserved by the buffer methods such as [`PinnedConcurrentCol::n_items_buffer_as_mut_slices`] are marked as written
+    /// at the time of the reservation, since the caller is responsible for writing all reserved positions.
+    pub fn is_written(&self, idx: usize) -> Option<bool> {
+        self.written.as_ref().map(|x| x.is_marked(idx))
+    }
+
+    /// Returns the committed length, the length of the longest prefix which is completely written,
+    /// provided that the collection maintains it (see [`ConcurrentState::track_committed_len`]); returns None otherwise.
+    ///
+    /// The committed length is loaded with `Acquire` ordering; hence, the writes of all positions below the returned length happen-before this call.
+    /// It never decreases through a shared reference.
+    pub fn committed_len(&self) -> Option<usize> {
+        self.committed.as_ref().map(|x| x.len())
+    }
+
+    /// Returns the number of committed elements which are moved out of the collection from the front by [`PinnedConcurrentCol::drain_committed`],
+    /// provided that the collection maintains the committed length; returns None otherwise.
+    pub fn consumed_len(&self) -> Option<usize> {
+        self.committed.as_ref().map(|x| x.consumed())
+    }
+
+    /// Returns a reference to the element at the `idx`-th position if it is within the committed prefix and not yet consumed;
+    /// returns None otherwise, including the case where the collection does not maintain the committed length.
+    ///
+    /// This method is safe since committed positions are completely written and must never be written again through a shared reference.
+    pub fn get_committed(&self, idx: usize) -> Option<&T> {
+        let committed = self.committed.as_ref()?;
+        match committed.consumed() <= idx && idx < committed.len() {
+            // SAFETY: committed positions are written and never written again
+            true => unsafe { self.con_pinned_vec.get(idx) },
+            false => None,
+        }
+    }
+
+    /// Returns an iterator over the elements of the committed prefix, excluding the consumed elements, at the time of the call;
+    /// the iterator is empty if the collection does not maintain the committed length.
+    ///
+    /// This method is safe since committed positions are completely written and must never be written again through a shared reference.
+    pub fn iter_committed(&self) -> impl Iterator<Item = &T> {
+        let range = self.committed_range();
+        // SAFETY: committed positions are written and never written again
+        unsafe { self.con_pinned_vec.iter_over_range(range) }
+    }
+
+    /// Returns an iterator over the contiguous slices which together cover the committed prefix, excluding the consumed elements, at the time of the call;
+    /// the iterator is empty if the collection does not maintain the committed length.
+    ///
+    /// Unlike [`PinnedConcurrentCol::iter_committed`], the slices allow processing the elements of each fragment in bulk.
+    ///
+    /// This method is safe since committed positions are completely written and must never be written again through a shared reference.
+    pub fn committed_slices(&self) -> P::SliceIter<'_> {
+        self.con_pinned_vec.slices(self.committed_range())
+    }
+
+    /// Returns a splittable [`SliceProducer`] over the committed prefix, excluding the consumed elements, at the time of the call;
+    /// the producer is empty if the collection does not maintain the committed length.
+    ///
+    /// The producer can be split and sent to different threads in order to process the committed elements in parallel;
+    /// with the `rayon` feature, it can directly be converted into a parallel iterator.
+    ///
+    /// This method is safe since committed positions are completely written and must never be written again through a shared reference.
+    pub fn committed_slice_producer(&self) -> SliceProducer<'_, T> {
+        SliceProducer::new(self.committed_slices())
+    }
+
+    fn committed_range(&self) -> Range<usize> {
+        match &self.committed {
+            Some(committed) => committed.consumed()..committed.len(),
+            None => 0..0,
+        }
+    }
+
     // unsafe getters
 
     /// Returns an iterator to the elements of the underlying pinned vector starting from the first element and taking `len` elements.
@@ -222,6 +476,9 @@ where
     /// - Furthermore, it uses a pinned vector of `Option<T>` to represent a collection of `T`s. It has a valid zero value, `Option::None`.
     /// - The iter wrapper simply skips `None`s which correspond to uninitialized values.
     pub unsafe fn iter(&self, len: usize) -> impl Iterator<Item = &T> {
+        #[cfg(feature = "debug_checks")]
+        self.debug_check_read(0..len);
+
         unsafe { self.con_pinned_vec.iter(len) }
     }
 
@@ -251,9 +508,47 @@ where
         &self,
         range: R,
     ) -> impl Iterator<Item = &T> {
+        #[cfg(feature = "debug_checks")]
+        {
+            let [begin, end] = vec_range_limits(&range, Some(self.capacity()));
+            self.debug_check_read(begin..end);
+        }
+
         unsafe { self.con_pinned_vec.iter_over_range(range) }
     }
 
+    /// Returns an iterator over the contiguous slices of the underlying pinned vector which together cover the given `range`.
+    ///
+    /// Unlike [`PinnedConcurrentCol::iter_over_range`], which yields the elements one by one,
+    /// the slices allow processing the elements of each fragment in bulk.
+    ///
+    /// # Safety
+    ///
+    /// The safety requirements are identical to those of [`PinnedConcurrentCol::iter_over_range`]:
+    /// all values in `range` must be written, and no position in `range` may be written while the slices are being read.
+    pub unsafe fn slices<R: RangeBounds<usize>>(&self, range: R) -> P::SliceIter<'_> {
+        #[cfg(feature = "debug_checks")]
+        {
+            let [begin, end] = vec_range_limits(&range, Some(self.capacity()));
+            self.debug_check_read(begin..end);
+        }
+
+        self.con_pinned_vec.slices(range)
+    }
+
+    /// Returns a splittable [`SliceProducer`] over the elements of the underlying pinned vector in the given `range`.
+    ///
+    /// The producer can be split and sent to different threads in order to process the range in parallel;
+    /// with the `rayon` feature, it can directly be converted into a parallel iterator.
+    ///
+    /// # Safety
+    ///
+    /// The safety requirements are identical to those of [`PinnedConcurrentCol::iter_over_range`]:
+    /// all values in `range` must be written, and no position in `range` may be written while the producer or any of its parts is in use.
+    pub unsafe fn slice_producer<R: RangeBounds<usize>>(&self, range: R) -> SliceProducer<'_, T> {
+        SliceProducer::new(unsafe { self.slices(range) })
+    }
+
     /// Returns a mutable iterator to the elements of the underlying pinned vector starting from the first element and taking `len` elements.
     ///
     /// # Safety
@@ -274,6 +569,9 @@ where
     /// - Furthermore, it uses a pinned vector of `Option<T>` to represent a collection of `T`s. It has a valid zero value, `Option::None`.
     /// - The iter wrapper simply skips `None`s which correspond to uninitialized values.
     pub unsafe fn iter_mut(&mut self, len: usize) -> impl Iterator<Item = &mut T> {
+        #[cfg(feature = "debug_checks")]
+        self.debug_check_read(0..len);
+
         unsafe { self.con_pinned_vec.iter_mut(len) }
     }
 
@@ -300,6 +598,9 @@ where
     /// - Furthermore, it uses a pinned vector of `Option<T>` to represent a collection of `T`s. It has a valid zero value, `Option::None`.
     /// - The get method wrapper simply the value, which will be `None` for uninitialized values.
     pub unsafe fn get(&self, index: usize) -> Option<&T> {
+        #[cfg(feature = "debug_checks")]
+        self.debug_check_read(index..index + 1);
+
         unsafe { self.con_pinned_vec.get(index) }
     }
 
@@ -323,11 +624,35 @@ where
     /// - Furthermore, it uses a pinned vector of `Option<T>` to represent a collection of `T`s. It has a valid zero value, `Option::None`.
     /// - The get_mut method wrapper will return `None` for uninitialized values.
     pub unsafe fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        #[cfg(feature = "debug_checks")]
+        self.debug_check_read(index..index + 1);
+
         unsafe { self.con_pinned_vec.get_mut(index) }
     }
 
     // mutations
 
+    /// Sets the strategy that threads follow while waiting on a [`WritePermit::Spin`] for this collection;
+    /// overwriting the strategy defined by the concurrent state, [`ConcurrentState::spin_strategy`].
+    pub fn set_spin_strategy(&mut self, spin_strategy: SpinStrategy) {
+        self.spin_strategy = spin_strategy;
+    }
+
+    /// Sets the policy determining when the collection allocates new positions;
+    /// overwriting the policy defined by the concurrent state, [`ConcurrentState::growth_policy`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `growth_policy` is not valid; see [`GrowthPolicy::is_valid`].
+    pub fn set_growth_policy(&mut self, growth_policy: GrowthPolicy) {
+        assert!(
+            growth_policy.is_valid(),
+            "{}",
+            PinnedConcurrentColError::InvalidGrowthPolicy
+        );
+        self.growth_policy = growth_policy;
+    }
+
     /// Note that [`PinnedConcurrentCol::maximum_capacity`] returns the maximum possible number of elements that the underlying pinned vector can grow to without reserving maximum capacity.
     ///
     /// In other words, the pinned vector can automatically grow up to the [`PinnedConcurrentCol::maximum_capacity`] with `write` and `write_n_items` methods, using only a shared reference.
@@ -350,24 +675,49 @@ where
         current_len: usize,
         maximum_capacity: usize,
     ) -> usize {
-        match self.state.fill_memory_with() {
+        #[cfg(feature = "metrics")]
+        let fill_timer = FillTimer::default();
+        let fill = |fill_with: &MemoryFill<T>| {
+            #[cfg(feature = "metrics")]
+            return fill_timer.measure(|| fill_with.fill());
+            #[cfg(not(feature = "metrics"))]
+            return fill_with.fill();
+        };
+
+        let new_maximum_capacity = match &self.fill_memory_with {
             Some(fill_with) => unsafe {
                 self.con_pinned_vec
                     .reserve_maximum_concurrent_capacity_fill_with(
                         current_len,
                         maximum_capacity,
-                        fill_with,
+                        || fill(fill_with),
                     )
             },
             None => unsafe {
                 self.con_pinned_vec
                     .reserve_maximum_concurrent_capacity(current_len, maximum_capacity)
             },
-        }
+        };
+
+        self.grow_side_structures_to(self.con_pinned_vec.capacity());
+
+        #[cfg(feature = "metrics")]
+        self.metrics.record_fill(fill_timer);
+
+        new_maximum_capacity
     }
 
     /// Writes the `value` to the `idx`-th position.
     ///
+    /// See [`PinnedConcurrentCol::try_write`] for the fallible version of the method.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the write request fails; i.e., if [`PinnedConcurrentCol::try_write`] returns an error:
+    /// * `idx` is out of the maximum capacity of the underlying pinned vector,
+    /// * the underlying pinned vector fails to grow, or
+    /// * the concurrent state rejects the request.
+    ///
     /// # Safety
     ///
     /// This method makes sure that the value is written to a position owned by the underlying pinned vector.
@@ -377,33 +727,47 @@ where
     /// Multiple threads can try to write to the same `idx` at the same time.
     /// The wrapper is responsible for preventing this.
     ///
-    /// This method can safely be used provided that the caller provides the following guarantee:
+    /// This method can safely be used provided that the caller provides the following guarantees:
     /// * **multiple `write` or `write_n_items` calls which writes to the same `idx` must not happen concurrently.**
+    /// * **positions below the [`PinnedConcurrentCol::committed_len`] must not be written again**, since they might be read concurrently.
     pub unsafe fn write(&self, idx: usize, value: T) {
-        self.assert_has_capacity_for(idx);
-        loop {
-            let write_permit = self.state.write_permit(self, idx);
-            match write_permit {
-                WritePermit::JustWrite => {
-                    self.write_at(idx, value);
-                    self.state.update_after_write(idx, idx + 1);
-                    break;
-                }
-                WritePermit::GrowThenWrite => {
-                    self.grow_to(idx + 1);
-                    self.write_at(idx, value);
-                    self.state.update_after_write(idx, idx + 1);
-                    break;
-                }
-                WritePermit::Spin => {}
-            }
+        expect_ok(unsafe { self.try_write(idx, value) })
+    }
+
+    /// Tries to write the `value` to the `idx`-th position; returns an error rather than panicking if the write request fails.
+    ///
+    /// When the write request fails, the `value` is given back to the caller within the [`WriteError`];
+    /// and hence, the caller can retry the request or fall back to another destination.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`WriteError`] with the cause of the failure:
+    /// * [`PinnedConcurrentColError::OutOfMaximumCapacity`] if `idx` is out of the maximum capacity of the underlying pinned vector,
+    /// * [`PinnedConcurrentColError::FailedToGrow`] if the underlying pinned vector fails to grow,
+    /// * [`PinnedConcurrentColError::RejectedByState`] if the concurrent state rejects the request with [`WritePermit::Reject`].
+    ///
+    /// # Safety
+    ///
+    /// Safety requirements are identical to those of [`PinnedConcurrentCol::write`].
+    pub unsafe fn try_write(&self, idx: usize, value: T) -> Result<(), WriteError<T>> {
+        if let Err(error) = self.acquire_write_permit(idx, 1) {
+            return Err(WriteError::new(error, value));
         }
+        self.write_at(idx, value);
+        self.commit_after_write(idx, idx + 1);
+        Ok(())
     }
 
     /// Reserves and returns a reference for one position at the `idx`-th position.
     ///
     /// The caller is responsible for writing to the position.
     ///
+    /// See [`PinnedConcurrentCol::try_single_item_as_ref`] for the fallible version of the method.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the request fails; i.e., if [`PinnedConcurrentCol::try_single_item_as_ref`] returns an error.
+    ///
     /// # Safety
     ///
     /// This method makes sure that the values are written to positions owned by the underlying pinned vector.
@@ -418,40 +782,48 @@ where
     /// Note that although both methods are unsafe, it is much easier to achieve required safety guarantees with `write_n_items`;
     /// hence, it must be preferred unless there is a good reason to acquire mutable slices.
     /// One such example case is to copy results directly into the output's slices, which could be more performant in a very critical scenario.
-    #[allow(clippy::missing_panics_doc)]
     pub unsafe fn single_item_as_ref(&self, idx: usize) -> &T {
-        self.assert_has_capacity_for(idx);
-        loop {
-            let write_permit = self.state.write_permit(self, idx);
-            match write_permit {
-                WritePermit::JustWrite => {
-                    let x = unsafe {
-                        self.con_pinned_vec
-                            .get(idx)
-                            .expect("should succeed since has capacity for idx")
-                    };
-                    self.state.update_after_write(idx, idx + 1);
-                    return x;
-                }
-                WritePermit::GrowThenWrite => {
-                    self.grow_to(idx + 1);
-                    self.state.update_after_write(idx, idx + 1);
-                    return unsafe {
-                        self.con_pinned_vec
-                            .get(idx)
-                            .expect("should succeed since has capacity for idx")
-                    };
-                }
-                WritePermit::Spin => {}
-            }
-        }
+        expect_ok(unsafe { self.try_single_item_as_ref(idx) })
+    }
+
+    /// Tries to reserve and return a reference for one position at the `idx`-th position; returns an error rather than panicking if the request fails.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`PinnedConcurrentCol::try_write`].
+    ///
+    /// # Safety
+    ///
+    /// Safety requirements are identical to those of [`PinnedConcurrentCol::single_item_as_ref`].
+    #[allow(clippy::missing_panics_doc)]
+    pub unsafe fn try_single_item_as_ref(
+        &self,
+        idx: usize,
+    ) -> Result<&T, PinnedConcurrentColError> {
+        self.acquire_write_permit(idx, 1)?;
+        let x = unsafe {
+            self.con_pinned_vec
+                .get(idx)
+                .expect("should succeed since has capacity for idx")
+        };
+        self.update_after_write(idx, idx + 1);
+        Ok(x)
     }
 
     /// Writes the `num_items` `values` to sequential positions starting from the `begin_idx`-th position.
     ///
     /// * If the `values` iterator has more than `num_items` elements, the excess values will be ignored.
-    /// * The method will not complain; however, `values` iterator yielding less than `num_items` elements might lead to safety issues (see below).
+    /// * The write is all-or-nothing. If the `values` iterator panics or yields less than `num_items` elements,
+    ///   the elements already written to the range are dropped and the state is not updated.
+    ///
+    /// See [`PinnedConcurrentCol::write_exact_n_items`] to write as many elements as an [`ExactSizeIterator`] yields and keep the written ones.
+    ///
+    /// See [`PinnedConcurrentCol::try_write_n_items`] for the fallible version of the method.
     ///
+    /// # Panics
+    ///
+    /// Panics if the write request fails; i.e., if [`PinnedConcurrentCol::try_write_n_items`] returns an error,
+    /// which includes the case where the `values` iterator yields less than `num_items` elements.
     ///
     /// # Safety
     ///
@@ -464,7 +836,9 @@ where
     ///
     /// This method can safely be used provided that the caller provides the following guarantees:
     /// * **multiple `write` or `write_n_items` calls which writes to the same `idx` must not happen concurrently.**
-    /// * **values** iterator yielding less than `num_items` elements might lead to gaps in the bag, which would lead to gaps in the vector if not handled properly.
+    /// * **positions below the [`PinnedConcurrentCol::committed_len`] must not be written again**, since they might be read concurrently.
+    /// * **values** iterator panicking or yielding less than `num_items` elements leaves the range unwritten;
+    ///   this might lead to gaps in the bag, which would lead to gaps in the vector if not handled properly.
     pub unsafe fn write_n_items<IntoIter>(
         &self,
         begin_idx: usize,
@@ -472,35 +846,199 @@ where
         values: IntoIter,
     ) where
         IntoIter: IntoIterator<Item = T>,
+    {
+        expect_ok(unsafe { self.try_write_n_items(begin_idx, num_items, values) })
+    }
+
+    /// Tries to write the `num_items` `values` to sequential positions starting from the `begin_idx`-th position;
+    /// returns an error rather than panicking if the write request fails.
+    ///
+    /// None of the `values` are written when the write request fails.
+    ///
+    /// # Errors
+    ///
+    /// * Returns the same errors as [`PinnedConcurrentCol::try_write`] where the requested position is the last position `begin_idx + num_items - 1`.
+    /// * Returns [`PinnedConcurrentColError::IteratorTooShort`] if the `values` iterator yields less than `num_items` elements;
+    ///   in this case, the already written elements are dropped.
+    ///
+    /// # Safety
+    ///
+    /// Safety requirements are identical to those of [`PinnedConcurrentCol::write_n_items`].
+    pub unsafe fn try_write_n_items<IntoIter>(
+        &self,
+        begin_idx: usize,
+        num_items: usize,
+        values: IntoIter,
+    ) -> Result<(), PinnedConcurrentColError>
+    where
+        IntoIter: IntoIterator<Item = T>,
     {
         if num_items > 0 {
             let end_idx = begin_idx + num_items;
-            let last_idx = end_idx - 1;
-            self.assert_has_capacity_for(last_idx);
-
-            loop {
-                match self.state.write_permit_n_items(self, begin_idx, num_items) {
-                    WritePermit::JustWrite => {
-                        self.write_n_items_at(begin_idx, num_items, values);
-                        self.state.update_after_write(begin_idx, end_idx);
-                        break;
-                    }
-                    WritePermit::GrowThenWrite => {
-                        self.grow_to(end_idx);
-                        self.write_n_items_at(begin_idx, num_items, values);
-                        self.state.update_after_write(begin_idx, end_idx);
-                        break;
-                    }
-                    WritePermit::Spin => {}
-                }
+            self.acquire_write_permit(begin_idx, num_items)?;
+            self.write_n_items_at(begin_idx, num_items, values)?;
+            self.commit_after_write(begin_idx, end_idx);
+        }
+        Ok(())
+    }
+
+    /// Writes all elements of the `values` iterator to sequential positions starting from the `begin_idx`-th position, and returns the number of written elements.
+    ///
+    /// Positions are reserved with respect to the length of the iterator, and the written elements are committed to the state even when the
+    /// iterator yields less elements than its length or panics.
+    /// In other words, unlike [`PinnedConcurrentCol::write_n_items`], the method never discards the written elements; instead, it reports the actual count written.
+    /// Excess elements beyond the length of the iterator are ignored.
+    ///
+    /// See [`PinnedConcurrentCol::try_write_exact_n_items`] for the fallible version of the method.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the write request fails; i.e., if [`PinnedConcurrentCol::try_write_exact_n_items`] returns an error.
+    ///
+    /// # Safety
+    ///
+    /// Safety requirements are identical to those of [`PinnedConcurrentCol::write_n_items`] where `num_items` is the length of the iterator.
+    /// Note that the returned count being less than the length of the iterator leaves a gap which must be handled by the wrapper.
+    pub unsafe fn write_exact_n_items<IntoIter>(&self, begin_idx: usize, values: IntoIter) -> usize
+    where
+        IntoIter: IntoIterator<Item = T>,
+        IntoIter::IntoIter: ExactSizeIterator,
+    {
+        expect_ok(unsafe { self.try_write_exact_n_items(begin_idx, values) })
+    }
+
+    /// Tries to write all elements of the `values` iterator to sequential positions starting from the `begin_idx`-th position,
+    /// and returns the number of written elements; returns an error rather than panicking if the write request fails.
+    ///
+    /// None of the `values` are written when the write request fails.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`PinnedConcurrentCol::try_write`] where the requested position is the last position `begin_idx + values.len() - 1`.
+    ///
+    /// # Safety
+    ///
+    /// Safety requirements are identical to those of [`PinnedConcurrentCol::write_exact_n_items`].
+    pub unsafe fn try_write_exact_n_items<IntoIter>(
+        &self,
+        begin_idx: usize,
+        values: IntoIter,
+    ) -> Result<usize, PinnedConcurrentColError>
+    where
+        IntoIter: IntoIterator<Item = T>,
+        IntoIter::IntoIter: ExactSizeIterator,
+    {
+        let values = values.into_iter();
+        let mut reservation = unsafe { self.try_reserve_n_items(begin_idx, values.len()) }?;
+        for value in values {
+            if reservation.push(value).is_err() {
+                break;
             }
         }
+        Ok(reservation.num_initialized())
+    }
+
+    /// Copies the `values` to sequential positions starting from the `begin_idx`-th position.
+    ///
+    /// Unlike [`PinnedConcurrentCol::write_n_items`] which writes the elements one by one, the values are copied in bulk
+    /// into each destination fragment of the underlying pinned vector.
+    ///
+    /// See [`PinnedConcurrentCol::try_write_slice`] for the fallible version of the method.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the write request fails; i.e., if [`PinnedConcurrentCol::try_write_slice`] returns an error.
+    ///
+    /// # Safety
+    ///
+    /// Safety requirements are identical to those of [`PinnedConcurrentCol::write_n_items`] where `num_items` is the length of the `values`.
+    pub unsafe fn write_slice(&self, begin_idx: usize, values: &[T])
+    where
+        T: Copy,
+    {
+        expect_ok(unsafe { self.try_write_slice(begin_idx, values) })
+    }
+
+    /// Tries to copy the `values` to sequential positions starting from the `begin_idx`-th position;
+    /// returns an error rather than panicking if the write request fails.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`PinnedConcurrentCol::try_write`] where the requested position is the last position `begin_idx + values.len() - 1`.
+    ///
+    /// # Safety
+    ///
+    /// Safety requirements are identical to those of [`PinnedConcurrentCol::write_slice`].
+    pub unsafe fn try_write_slice(
+        &self,
+        begin_idx: usize,
+        values: &[T],
+    ) -> Result<(), PinnedConcurrentColError>
+    where
+        T: Copy,
+    {
+        unsafe { self.try_write_from_slices(begin_idx, &[values]) }
+    }
+
+    /// Copies the elements of the `slices`, one after the other, to sequential positions starting from the `begin_idx`-th position.
+    ///
+    /// The values are copied in bulk, handling the boundaries of both the source slices and the destination fragments of the underlying pinned vector.
+    ///
+    /// See [`PinnedConcurrentCol::try_write_from_slices`] for the fallible version of the method.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the write request fails; i.e., if [`PinnedConcurrentCol::try_write_from_slices`] returns an error.
+    ///
+    /// # Safety
+    ///
+    /// Safety requirements are identical to those of [`PinnedConcurrentCol::write_n_items`] where `num_items` is the total length of the `slices`.
+    pub unsafe fn write_from_slices(&self, begin_idx: usize, slices: &[&[T]])
+    where
+        T: Copy,
+    {
+        expect_ok(unsafe { self.try_write_from_slices(begin_idx, slices) })
+    }
+
+    /// Tries to copy the elements of the `slices`, one after the other, to sequential positions starting from the `begin_idx`-th position;
+    /// returns an error rather than panicking if the write request fails.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`PinnedConcurrentCol::try_write`] where the requested position is the last position `begin_idx + num_items - 1`,
+    /// `num_items` being the total length of the `slices`.
+    ///
+    /// # Safety
+    ///
+    /// Safety requirements are identical to those of [`PinnedConcurrentCol::write_from_slices`].
+    pub unsafe fn try_write_from_slices(
+        &self,
+        begin_idx: usize,
+        slices: &[&[T]],
+    ) -> Result<(), PinnedConcurrentColError>
+    where
+        T: Copy,
+    {
+        let num_items = slices.iter().map(|x| x.len()).sum();
+        if num_items > 0 {
+            let end_idx = begin_idx + num_items;
+            self.acquire_write_permit(begin_idx, num_items)?;
+            self.copy_from_slices_at(begin_idx, num_items, slices);
+            self.commit_after_write(begin_idx, end_idx);
+        }
+        Ok(())
     }
 
     /// Reserves and returns an iterator of mutable slices for `num_items` positions starting from the `begin_idx`-th position.
     ///
     /// The caller is responsible for filling all `num_items` positions in the returned iterator of slices with values to avoid gaps.
     ///
+    /// See [`PinnedConcurrentCol::try_n_items_buffer_as_slices`] for the fallible version of the method.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the request fails; i.e., if [`PinnedConcurrentCol::try_n_items_buffer_as_slices`] returns an error.
+    ///
     /// # Safety
     ///
     /// This method makes sure that the values are written to positions owned by the underlying pinned vector.
@@ -520,29 +1058,32 @@ where
         begin_idx: usize,
         num_items: usize,
     ) -> P::SliceIter<'_> {
+        expect_ok(unsafe { self.try_n_items_buffer_as_slices(begin_idx, num_items) })
+    }
+
+    /// Tries to reserve and return an iterator of slices for `num_items` positions starting from the `begin_idx`-th position;
+    /// returns an error rather than panicking if the request fails.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`PinnedConcurrentCol::try_write`] where the requested position is the last position `begin_idx + num_items - 1`.
+    ///
+    /// # Safety
+    ///
+    /// Safety requirements are identical to those of [`PinnedConcurrentCol::n_items_buffer_as_slices`].
+    pub unsafe fn try_n_items_buffer_as_slices(
+        &self,
+        begin_idx: usize,
+        num_items: usize,
+    ) -> Result<P::SliceIter<'_>, PinnedConcurrentColError> {
         match num_items {
-            0 => P::SliceIter::default(),
+            0 => Ok(P::SliceIter::default()),
             _ => {
                 let end_idx = begin_idx + num_items;
-                let last_idx = end_idx - 1;
-                self.assert_has_capacity_for(last_idx);
-
-                loop {
-                    match self.state.write_permit_n_items(self, begin_idx, num_items) {
-                        WritePermit::JustWrite => {
-                            let slices = self.slices_for_n_items_at(begin_idx, num_items);
-                            self.state.update_after_write(begin_idx, end_idx);
-                            return slices;
-                        }
-                        WritePermit::GrowThenWrite => {
-                            self.grow_to(end_idx);
-                            let slices = self.slices_for_n_items_at(begin_idx, num_items);
-                            self.state.update_after_write(begin_idx, end_idx);
-                            return slices;
-                        }
-                        WritePermit::Spin => {}
-                    }
-                }
+                self.acquire_write_permit(begin_idx, num_items)?;
+                let slices = self.slices_for_n_items_at(begin_idx, num_items);
+                self.update_after_write(begin_idx, end_idx);
+                Ok(slices)
             }
         }
     }
@@ -551,6 +1092,12 @@ where
     ///
     /// The caller is responsible for filling all `num_items` positions in the returned iterator of slices with values to avoid gaps.
     ///
+    /// See [`PinnedConcurrentCol::try_n_items_buffer_as_mut_slices`] for the fallible version of the method.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the request fails; i.e., if [`PinnedConcurrentCol::try_n_items_buffer_as_mut_slices`] returns an error.
+    ///
     /// # Safety
     ///
     /// This method makes sure that the values are written to positions owned by the underlying pinned vector.
@@ -565,36 +1112,200 @@ where
     /// Note that although both methods are unsafe, it is much easier to achieve required safety guarantees with `write_n_items`;
     /// hence, it must be preferred unless there is a good reason to acquire mutable slices.
     /// One such example case is to copy results directly into the output's slices, which could be more performant in a very critical scenario.
+    ///
+    /// Note that the positions are neither committed nor published before the caller calls [`PinnedConcurrentCol::commit_range`] after writing them.
+    /// [`PinnedConcurrentCol::reserve_n_items`] provides a panic-safe alternative which commits only the initialized positions.
     pub unsafe fn n_items_buffer_as_mut_slices(
         &self,
         begin_idx: usize,
         num_items: usize,
     ) -> P::SliceMutIter<'_> {
+        expect_ok(unsafe { self.try_n_items_buffer_as_mut_slices(begin_idx, num_items) })
+    }
+
+    /// Tries to reserve and return an iterator of mutable slices for `num_items` positions starting from the `begin_idx`-th position;
+    /// returns an error rather than panicking if the request fails.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`PinnedConcurrentCol::try_write`] where the requested position is the last position `begin_idx + num_items - 1`.
+    ///
+    /// # Safety
+    ///
+    /// Safety requirements are identical to those of [`PinnedConcurrentCol::n_items_buffer_as_mut_slices`].
+    pub unsafe fn try_n_items_buffer_as_mut_slices(
+        &self,
+        begin_idx: usize,
+        num_items: usize,
+    ) -> Result<P::SliceMutIter<'_>, PinnedConcurrentColError> {
         match num_items {
-            0 => P::SliceMutIter::default(),
+            0 => Ok(P::SliceMutIter::default()),
             _ => {
                 let end_idx = begin_idx + num_items;
-                let last_idx = end_idx - 1;
-                self.assert_has_capacity_for(last_idx);
-
-                loop {
-                    match self.state.write_permit_n_items(self, begin_idx, num_items) {
-                        WritePermit::JustWrite => {
-                            let slices = self.slices_mut_for_n_items_at(begin_idx, num_items);
-                            self.state.update_after_write(begin_idx, end_idx);
-                            return slices;
-                        }
-                        WritePermit::GrowThenWrite => {
-                            self.grow_to(end_idx);
-                            let slices = self.slices_mut_for_n_items_at(begin_idx, num_items);
-                            self.state.update_after_write(begin_idx, end_idx);
-                            return slices;
-                        }
-                        WritePermit::Spin => {}
-                    }
-                }
+                self.acquire_write_permit(begin_idx, num_items)?;
+                let slices = self.slices_mut_for_n_items_at(begin_idx, num_items);
+                self.update_after_write(begin_idx, end_idx);
+                Ok(slices)
+            }
+        }
+    }
+
+    /// Commits the `range` which is written through the buffer methods, such as [`PinnedConcurrentCol::n_items_buffer_as_mut_slices`],
+    /// advancing the committed length whenever the range closes the gap at its end.
+    ///
+    /// The range is further published to the state by [`ConcurrentState::publish_after_write`], since the buffer methods do not publish the positions.
+    /// Otherwise, the call has no effect if the collection does not maintain the committed length (see [`ConcurrentState::track_committed_len`]).
+    ///
+    /// # Safety
+    ///
+    /// The caller must guarantee that all positions in the `range` are completely written before the call,
+    /// and that they are never written again through a shared reference of the collection.
+    pub unsafe fn commit_range(&self, range: Range<usize>) {
+        self.state
+            .publish_after_write(range.start, range.end, self.spin_strategy);
+        if let Some(committed) = &self.committed {
+            committed.commit(range, self.con_pinned_vec.capacity(), self.spin_strategy);
+        }
+    }
+
+    /// Moves the committed elements which are not yet consumed out of the collection, and returns them in the order of their positions.
+    ///
+    /// Writers may continue writing to the positions beyond the committed length concurrently;
+    /// and the elements committed later can be drained by subsequent calls.
+    /// Returns an empty vector if the collection does not maintain the committed length (see [`ConcurrentState::track_committed_len`]).
+    ///
+    /// See [`PinnedConcurrentCol::drain_committed_with`] to pass the elements to a callback rather than collecting them.
+    ///
+    /// # Safety
+    ///
+    /// Safety requirements are identical to those of [`PinnedConcurrentCol::drain_committed_with`].
+    pub unsafe fn drain_committed(&self) -> Vec<T> {
+        let mut drained = Vec::new();
+        unsafe { self.drain_committed_with(|x| drained.push(x)) };
+        drained
+    }
+
+    /// Moves the committed elements which are not yet consumed out of the collection, passes them to `consume` in the order of their positions,
+    /// and returns the number of drained elements.
+    ///
+    /// Each element is drained exactly once, even when multiple threads drain concurrently.
+    /// The drained positions are marked as taken so that they are never dropped again by the collection:
+    /// * if the memory is filled, the positions are refilled by the [`PinnedConcurrentCol::fill_memory_with`];
+    /// * if the collection keeps track of its written positions, the positions are marked as not written;
+    /// * otherwise, the collection skips the consumed prefix when dropping or clearing its elements.
+    ///
+    /// Returns zero if the collection does not maintain the committed length (see [`ConcurrentState::track_committed_len`]).
+    ///
+    /// # Safety
+    ///
+    /// Since the elements are moved out through a shared reference, the caller must guarantee that:
+    /// * **no reference to a committed element is alive during the call**; such as references obtained by
+    ///   [`PinnedConcurrentCol::get_committed`] or [`PinnedConcurrentCol::iter_committed`] on another thread.
+    ///
+    /// Further, the consumed positions are not written anymore, unless the memory is filled.
+    /// Therefore, the unsafe methods requiring written positions, such as [`PinnedConcurrentCol::into_inner`], must not cover the consumed prefix;
+    /// and [`PinnedConcurrentCol::try_into_inner`] fails for any non-zero length.
+    pub unsafe fn drain_committed_with<F>(&self, mut consume: F) -> usize
+    where
+        F: FnMut(T),
+    {
+        let Some(committed) = &self.committed else {
+            return 0;
+        };
+
+        let range = committed.claim_unconsumed();
+        for idx in range.clone() {
+            let ptr = self.ptr_mut_at(idx);
+            // SAFETY: the position is committed, and it is claimed by this call only
+            let value = unsafe { ptr.read() };
+            if let Some(f) = &self.fill_memory_with {
+                unsafe { ptr.write(f.fill()) };
             }
+            if let Some(written) = &self.written {
+                written.unmark(idx..(idx + 1));
+            }
+            consume(value);
         }
+        range.len()
+    }
+
+    /// Reserves `num_items` positions starting from the `begin_idx`-th position and returns a [`Reservation`] guard owning these positions.
+    ///
+    /// The collection grows whenever necessary so that all reserved positions are allocated once the method returns.
+    /// However, unlike [`PinnedConcurrentCol::n_items_buffer_as_mut_slices`], the positions are not committed to the state immediately.
+    /// The reservation keeps track of the initialized positions and commits only the initialized prefix on finish or on drop.
+    ///
+    /// See [`PinnedConcurrentCol::try_reserve_n_items`] for the fallible version of the method.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the request fails; i.e., if [`PinnedConcurrentCol::try_reserve_n_items`] returns an error.
+    ///
+    /// # Safety
+    ///
+    /// This method makes sure that the reserved positions are owned by the underlying pinned vector.
+    /// Furthermore, it makes sure that the growth of the vector happens thread-safely whenever necessary.
+    ///
+    /// On the other hand, it is unsafe due to the possibility of a race condition.
+    /// Multiple threads can try to write to the same position at the same time.
+    /// The wrapper is responsible for preventing this.
+    ///
+    /// This method can safely be used provided that the caller provides the following guarantee:
+    /// * **multiple `write`, `write_n_items` or `reserve_n_items` calls which writes to the same `idx` must not happen concurrently.**
+    pub unsafe fn reserve_n_items(
+        &self,
+        begin_idx: usize,
+        num_items: usize,
+    ) -> Reservation<'_, T, P, S> {
+        expect_ok(unsafe { self.try_reserve_n_items(begin_idx, num_items) })
+    }
+
+    /// Tries to reserve `num_items` positions starting from the `begin_idx`-th position and return a [`Reservation`] guard owning these positions;
+    /// returns an error rather than panicking if the request fails.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`PinnedConcurrentCol::try_write`] where the requested position is the last position `begin_idx + num_items - 1`.
+    ///
+    /// # Safety
+    ///
+    /// Safety requirements are identical to those of [`PinnedConcurrentCol::reserve_n_items`].
+    pub unsafe fn try_reserve_n_items(
+        &self,
+        begin_idx: usize,
+        num_items: usize,
+    ) -> Result<Reservation<'_, T, P, S>, PinnedConcurrentColError> {
+        if num_items > 0 {
+            self.acquire_write_permit(begin_idx, num_items)?;
+        }
+        Ok(Reservation::new(self, begin_idx, num_items))
+    }
+
+    /// Creates a [`LocalWriter`] which reserves `chunk_size` positions at once and writes to them locally.
+    ///
+    /// Each time the writer needs a new chunk, it calls `reserve(chunk_size)` which must claim `chunk_size` positions
+    /// for the writer and return the first position of the claimed range.
+    /// The range is then reserved by [`PinnedConcurrentCol::try_reserve_n_items`], which evaluates the [`ConcurrentState::write_permit_n_items`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is zero.
+    ///
+    /// # Safety
+    ///
+    /// The caller must guarantee that the ranges claimed by `reserve` are exclusively owned by the writer;
+    /// i.e., **no other `write`, `write_n_items`, `reserve_n_items` call or local writer writes to the claimed positions**.
+    ///
+    /// Further, when the memory is not filled, the unused positions of the last chunk are left as holes; see [`LocalWriter::flush`].
+    pub unsafe fn local_writer<R>(
+        &self,
+        chunk_size: usize,
+        reserve: R,
+    ) -> LocalWriter<'_, T, P, S, R>
+    where
+        R: FnMut(usize) -> usize,
+    {
+        LocalWriter::new(self, chunk_size, reserve)
     }
 
     /// Clears the collection.
@@ -603,9 +1314,71 @@ where
     /// This method is unsafe since the concurrent pinned vector might contain gaps.
     ///
     /// This method can safely be called if entries in all positions 0..len are written
+    ///
+    /// When the collection keeps track of its written positions, `prior_len` is ignored and only the written positions are dropped;
+    /// hence, the method is safe to call regardless of the gaps.
     pub unsafe fn clear(&mut self, prior_len: usize) {
+        #[cfg(feature = "debug_checks")]
+        if self.written.is_none() && self.fill_memory_with.is_none() {
+            self.debug_checks.check_len(prior_len);
+        }
+
+        let prior_len = match (self.written.is_some(), self.fill_memory_with.is_some()) {
+            (true, false) => {
+                self.drop_written_in(0..self.con_pinned_vec.capacity());
+                0
+            }
+            (false, false) => self.drop_after_consumed(prior_len),
+            (_, true) => prior_len,
+        };
+
         unsafe { self.con_pinned_vec.clear(prior_len) };
         self.state = S::new_for_con_pinned_vec(&self.con_pinned_vec, 0);
+        self.latest_allocation_begin.store(0, Ordering::Relaxed);
+
+        if let Some(written) = &mut self.written {
+            written.clear();
+            written.grow_to(self.con_pinned_vec.capacity());
+        }
+        if let Some(committed) = &mut self.committed {
+            committed.clear(self.con_pinned_vec.capacity());
+        }
+
+        #[cfg(feature = "debug_checks")]
+        self.debug_checks.clear(self.con_pinned_vec.capacity());
+    }
+}
+
+impl<T, P, S> PinnedConcurrentCol<T, P, S>
+where
+    P: ConcurrentPinnedVec<T>,
+    S: PublishedState<T>,
+{
+    /// Returns a reference to the element at the `idx`-th position if it is published by the state; returns None otherwise.
+    ///
+    /// This method is safe since the [`PublishedState`] guarantees that published positions are completely written and never written again.
+    pub fn try_get_published(&self, idx: usize) -> Option<&T> {
+        match self.state.is_published(idx) {
+            // SAFETY: published positions are written and never written again
+            true => unsafe { self.con_pinned_vec.get(idx) },
+            false => None,
+        }
+    }
+
+    /// Waits until the `idx`-th position is published by the state and returns a reference to the element.
+    ///
+    /// The calling thread follows the [`PinnedConcurrentCol::spin_strategy`] while waiting.
+    ///
+    /// Note that the method never returns if the position is never written;
+    /// [`PinnedConcurrentCol::try_get_published`] can be used for a non-blocking alternative.
+    pub fn wait_get(&self, idx: usize) -> &T {
+        let mut backoff = self.spin_strategy.backoff();
+        loop {
+            match self.try_get_published(idx) {
+                Some(value) => return value,
+                None => backoff.spin(),
+            }
+        }
     }
 }
 
@@ -616,41 +1389,276 @@ where
     P: ConcurrentPinnedVec<T>,
     S: ConcurrentState<T>,
 {
+    /// Allocates at least `initial_capacity` positions up front, where the collection currently contains `len` gap-free elements.
+    ///
+    /// The state is recreated for the allocated capacity, and hence, this method must only be called during the construction.
+    pub(crate) fn with_allocated_capacity(
+        mut self,
+        len: usize,
+        initial_capacity: usize,
+    ) -> Result<Self, PinnedConcurrentColError> {
+        if initial_capacity > self.capacity() {
+            self.check_has_capacity_for(initial_capacity - 1)?;
+            let result = match &self.fill_memory_with {
+                None => self.con_pinned_vec.grow_to(initial_capacity),
+                Some(f) => self
+                    .con_pinned_vec
+                    .grow_to_and_fill_with(initial_capacity, || f.fill()),
+            };
+            let _ = result.map_err(PinnedConcurrentColError::FailedToGrow)?;
+
+            self.state = S::new_for_con_pinned_vec(&self.con_pinned_vec, len);
+            self.grow_side_structures_to(self.con_pinned_vec.capacity());
+        }
+        Ok(self)
+    }
+
+    /// Releases the claims of the positions in the `range` whose write is rolled back.
+    #[cfg(feature = "debug_checks")]
+    pub(crate) fn debug_release(&self, range: Range<usize>) {
+        self.debug_checks.release(range);
+    }
+
+    /// Checks that the positions in the `range` within capacity are written, unless the memory is filled.
+    #[cfg(feature = "debug_checks")]
+    fn debug_check_read(&self, range: Range<usize>) {
+        if self.fill_memory_with.is_none() {
+            let end = range.end.min(self.con_pinned_vec.capacity());
+            self.debug_checks.check_read(range.start.min(end)..end);
+        }
+    }
+
+    fn new_written_positions(
+        state: &S,
+        con_pinned_vec: &P,
+        written_len: usize,
+    ) -> Option<WrittenPositions> {
+        state
+            .track_written_positions()
+            .then(|| WrittenPositions::new(con_pinned_vec.capacity(), written_len))
+    }
+
+    fn new_committed_len(
+        state: &S,
+        con_pinned_vec: &P,
+        written_len: usize,
+    ) -> Option<CommittedLen> {
+        state
+            .track_committed_len()
+            .then(|| CommittedLen::new(con_pinned_vec.capacity(), written_len))
+    }
+
+    /// Updates the state after the values are written to the range, publishes the range to the state,
+    /// and then commits the range if the committed length is maintained.
     #[inline]
-    fn assert_has_capacity_for(&self, idx: usize) {
-        assert!(
-            idx < self.con_pinned_vec.max_capacity(),
-            "{}",
-            ERR_REACHED_MAX_CAPACITY
-        );
+    pub(crate) fn commit_after_write(&self, begin_idx: usize, end_idx: usize) {
+        self.update_after_write(begin_idx, end_idx);
+        self.state
+            .publish_after_write(begin_idx, end_idx, self.spin_strategy);
+        if let Some(committed) = &self.committed {
+            committed.commit(
+                begin_idx..end_idx,
+                self.con_pinned_vec.capacity(),
+                self.spin_strategy,
+            );
+        }
+    }
+
+    /// Marks the range as written, if the positions are tracked, and then updates the state.
+    #[inline]
+    pub(crate) fn update_after_write(&self, begin_idx: usize, end_idx: usize) {
+        if let Some(written) = &self.written {
+            written.mark(begin_idx..end_idx, self.spin_strategy);
+        }
+        self.state
+            .update_after_write(begin_idx, end_idx, self.spin_strategy);
+    }
+
+    /// Drops the elements at the positions within the `range` which are marked as written, and unmarks them.
+    fn drop_written_in(&mut self, range: Range<usize>) {
+        if let Some(written) = &self.written {
+            for idx in written.marked(range.clone()) {
+                // SAFETY: the position is marked as written and it is dropped only once since it is unmarked below
+                unsafe { self.con_pinned_vec.get_ptr_mut(idx).drop_in_place() };
+            }
+            written.unmark(range);
+        }
+    }
+
+    fn num_consumed(&self) -> usize {
+        self.committed.as_ref().map(|x| x.consumed()).unwrap_or(0)
+    }
+
+    /// Drops the elements in `consumed..len` in place if the front of the collection is consumed,
+    /// and returns the length that the underlying pinned vector must be dropped or cleared with.
+    fn drop_after_consumed(&mut self, len: usize) -> usize {
+        let consumed = self.num_consumed();
+        match consumed {
+            0 => len,
+            _ => {
+                for idx in consumed..len {
+                    // SAFETY: positions below the length are written and the consumed positions are already moved out
+                    unsafe { self.con_pinned_vec.get_ptr_mut(idx).drop_in_place() };
+                }
+                0
+            }
+        }
+    }
+
+    #[inline]
+    fn check_has_capacity_for(&self, idx: usize) -> Result<(), PinnedConcurrentColError> {
+        let maximum_capacity = self.con_pinned_vec.max_capacity();
+        match idx < maximum_capacity {
+            true => Ok(()),
+            false => Err(PinnedConcurrentColError::OutOfMaximumCapacity {
+                required_capacity: idx + 1,
+                maximum_capacity,
+            }),
+        }
+    }
+
+    /// Evaluates the write permit for `num_items` positions starting from `begin_idx` until the caller is allowed to write;
+    /// the collection grows whenever the caller is assigned the responsibility.
+    fn acquire_write_permit(
+        &self,
+        begin_idx: usize,
+        num_items: usize,
+    ) -> Result<(), PinnedConcurrentColError> {
+        let result = self.evaluate_write_permit(begin_idx, num_items);
+
+        #[cfg(feature = "debug_checks")]
+        if result.is_ok() {
+            self.debug_checks
+                .claim(begin_idx..begin_idx + num_items, self.spin_strategy);
+        }
+
+        result
+    }
+
+    /// Makes sure that the `idx`-th position is allocated by evaluating the write permit protocol of the state,
+    /// without claiming the position for writing.
+    pub(crate) fn try_allocate(&self, idx: usize) -> Result<(), PinnedConcurrentColError> {
+        match idx < self.con_pinned_vec.capacity() {
+            true => Ok(()),
+            false => self.evaluate_write_permit(idx, 1),
+        }
+    }
+
+    fn evaluate_write_permit(
+        &self,
+        begin_idx: usize,
+        num_items: usize,
+    ) -> Result<(), PinnedConcurrentColError> {
+        let end_idx = begin_idx + num_items;
+        self.check_has_capacity_for(end_idx - 1)?;
+
+        let mut backoff = self.spin_strategy.backoff();
+        let result = loop {
+            let write_permit = match num_items {
+                1 => self.state.write_permit(self, begin_idx),
+                _ => self.state.write_permit_n_items(self, begin_idx, num_items),
+            };
+            match write_permit {
+                WritePermit::JustWrite => break Ok(()),
+                WritePermit::GrowThenWrite => break self.grow_to(end_idx),
+                WritePermit::WriteAndGrowAhead => {
+                    self.grow_ahead();
+                    break Ok(());
+                }
+                WritePermit::Spin => backoff.spin(),
+                WritePermit::Reject => break Err(PinnedConcurrentColError::RejectedByState),
+            }
+        };
+
+        #[cfg(feature = "metrics")]
+        self.metrics.record_spins(num_items, backoff.num_steps());
+
+        result
     }
 
     #[inline]
-    fn write_at(&self, idx: usize, value: T) {
-        let ptr = unsafe { self.con_pinned_vec.get_ptr_mut(idx) };
+    pub(crate) fn write_at(&self, idx: usize, value: T) {
+        let ptr = self.ptr_mut_at(idx);
         unsafe { ptr.write(value) };
     }
 
-    fn write_n_items_at<I>(&self, begin_idx: usize, num_items: usize, values: I)
+    #[inline]
+    pub(crate) fn ptr_mut_at(&self, idx: usize) -> *mut T {
+        unsafe { self.con_pinned_vec.get_ptr_mut(idx) }
+    }
+
+    /// Writes `num_items` values to the range starting at `begin_idx`; rolls back the written elements if the iterator panics or is short.
+    fn write_n_items_at<I>(
+        &self,
+        begin_idx: usize,
+        num_items: usize,
+        values: I,
+    ) -> Result<(), PinnedConcurrentColError>
     where
         I: IntoIterator<Item = T>,
     {
-        const ERR_SHORT_ITER: &str = "iterator is shorter than expected num_items";
-
         let mut values = values.into_iter();
+        let mut rollback = WriteRollback::new(self, begin_idx, num_items);
 
         let slices = self.slices_mut_for_n_items_at(begin_idx, num_items);
         for slice in slices {
             let ptr = slice.as_mut_ptr();
             let len = slice.len();
             for i in 0..len {
-                unsafe { ptr.add(i).write(values.next().expect(ERR_SHORT_ITER)) };
+                match values.next() {
+                    Some(value) => {
+                        unsafe { ptr.add(i).write(value) };
+                        rollback.increment();
+                    }
+                    None => {
+                        return Err(PinnedConcurrentColError::IteratorTooShort {
+                            num_items,
+                            num_yielded: rollback.num_written(),
+                        });
+                    }
+                }
+            }
+        }
+
+        rollback.disarm();
+        Ok(())
+    }
+
+    /// Copies the elements of the `sources` to the range of `num_items` positions starting at `begin_idx`,
+    /// where `num_items` is the total length of the sources.
+    fn copy_from_slices_at(&self, begin_idx: usize, num_items: usize, sources: &[&[T]])
+    where
+        T: Copy,
+    {
+        let mut sources = sources.iter();
+        let mut source: &[T] = &[];
+
+        for slice in self.slices_mut_for_n_items_at(begin_idx, num_items) {
+            let (ptr, len) = (slice.as_mut_ptr(), slice.len());
+            let mut num_copied = 0;
+            while num_copied < len {
+                if source.is_empty() {
+                    match sources.next() {
+                        Some(next) => source = next,
+                        None => return,
+                    }
+                    continue;
+                }
+                let n = (len - num_copied).min(source.len());
+                // SAFETY: the destination is within the range owned by the write request, and it cannot overlap with a shared source slice
+                unsafe { core::ptr::copy_nonoverlapping(source.as_ptr(), ptr.add(num_copied), n) };
+                num_copied += n;
+                source = &source[n..];
             }
         }
     }
 
     #[inline]
-    fn slices_mut_for_n_items_at(&self, begin_idx: usize, num_items: usize) -> P::SliceMutIter<'_> {
+    pub(crate) fn slices_mut_for_n_items_at(
+        &self,
+        begin_idx: usize,
+        num_items: usize,
+    ) -> P::SliceMutIter<'_> {
         let end_idx = begin_idx + num_items;
         unsafe { self.con_pinned_vec.slices_mut(begin_idx..end_idx) }
     }
@@ -661,23 +1669,85 @@ where
         self.con_pinned_vec.slices(begin_idx..end_idx)
     }
 
-    fn grow_to(&self, new_capacity: usize) {
-        match self.state.fill_memory_with() {
-            None => {
-                let _new_capacity = self
-                    .con_pinned_vec
-                    .grow_to(new_capacity)
-                    .expect(ERR_FAILED_TO_GROW);
+    /// Allocates the next fragment ahead of the write frontier, provided that the maximum capacity allows.
+    ///
+    /// The positions requested by the caller are already allocated; hence, a failure to grow is not an error of the write request.
+    fn grow_ahead(&self) {
+        let capacity = self.con_pinned_vec.capacity();
+        match capacity < self.con_pinned_vec.max_capacity() {
+            true => {
+                let _ = self.grow_to(capacity + 1);
             }
-            Some(f) => {
-                let _new_capacity = self
-                    .con_pinned_vec
-                    .grow_to_and_fill_with(new_capacity, f)
-                    .expect(ERR_FAILED_TO_GROW);
+            false => self.state.release_growth_handle(),
+        }
+    }
+
+    /// Grows the structures maintained alongside the pinned vector, the bitmaps, the state and the debug shadow state, so that they cover the given `capacity`.
+    ///
+    /// The states without a growth handle allow a writer to grow the collection as soon as the prior growth publishes its capacity;
+    /// hence, the growths might run concurrently and so might this method.
+    fn grow_side_structures_to(&self, capacity: usize) {
+        if let Some(written) = &self.written {
+            written.grow_to(capacity);
+        }
+        if let Some(committed) = &self.committed {
+            committed.grow_to(capacity);
+        }
+        self.state.update_after_growth(capacity);
+
+        #[cfg(feature = "debug_checks")]
+        self.debug_checks.grow_to(capacity);
+    }
+
+    fn grow_to(&self, new_capacity: usize) -> Result<(), PinnedConcurrentColError> {
+        let prior_capacity = self.con_pinned_vec.capacity();
+        #[cfg(feature = "metrics")]
+        let timer = Timer::start();
+
+        #[cfg(feature = "metrics")]
+        let fill_timer = FillTimer::default();
+        let fill = |fill_with: &MemoryFill<T>| {
+            #[cfg(feature = "metrics")]
+            return fill_timer.measure(|| fill_with.fill());
+            #[cfg(not(feature = "metrics"))]
+            return fill_with.fill();
+        };
+
+        // the requested positions are covered before the pinned vector publishes its new capacity,
+        // which might immediately let another writer grow the collection further
+        self.grow_side_structures_to(new_capacity);
+
+        let result = match &self.fill_memory_with {
+            None => self.con_pinned_vec.grow_to(new_capacity),
+            Some(f) => self
+                .con_pinned_vec
+                .grow_to_and_fill_with(new_capacity, || fill(f)),
+        };
+
+        if let Ok(capacity) = &result {
+            if *capacity > prior_capacity {
+                // a concurrent growth of a handle-less state might complete earlier while publishing a larger capacity
+                self.latest_allocation_begin
+                    .fetch_max(prior_capacity, Ordering::AcqRel);
             }
+            // the positions allocated beyond the requested capacity
+            self.grow_side_structures_to(*capacity);
         }
 
+        #[cfg(feature = "metrics")]
+        self.metrics.record_growth(
+            result.as_ref().ok().map(|capacity| {
+                capacity.saturating_sub(prior_capacity) * core::mem::size_of::<T>()
+            }),
+            timer,
+            fill_timer,
+        );
+
         self.state.release_growth_handle();
+
+        result
+            .map(|_new_capacity| ())
+            .map_err(PinnedConcurrentColError::FailedToGrow)
     }
 }
 
@@ -716,7 +1786,7 @@ mod tests {
     }
 
     impl<T> ConcurrentState<T> for MyConState<T> {
-        fn fill_memory_with(&self) -> Option<fn() -> T> {
+        fn fill_memory_with(&self) -> Option<MemoryFill<T>> {
             None
         }
 
@@ -765,7 +1835,7 @@ mod tests {
 
         fn release_growth_handle(&self) {}
 
-        fn update_after_write(&self, _: usize, _: usize) {}
+        fn update_after_write(&self, _: usize, _: usize, _: SpinStrategy) {}
 
         fn try_get_no_gap_len(&self) -> Option<usize> {
             Some(self.len())
@@ -0,0 +1,110 @@
+use crate::{
+    spin::SpinStrategy,
+    sync::{AtomicUsize, Ordering, atomic},
+    written::WrittenPositions,
+};
+use core::ops::Range;
+
+/// Watermark of the longest prefix of a pinned concurrent collection whose positions are completely written.
+///
+/// Writes might complete in any order. Each completed range is marked in a bitmap,
+/// and the watermark is advanced over the marked positions by the thread completing the range.
+///
+/// Further, the committed positions can be consumed, i.e., moved out of the collection, from the front;
+/// the consumed positions are below a second watermark which never exceeds the committed length.
+pub(crate) struct CommittedLen {
+    len: AtomicUsize,
+    consumed: AtomicUsize,
+    completed: WrittenPositions,
+}
+
+impl CommittedLen {
+    /// Creates the watermark for a collection with the given `capacity` where positions `0..len` are already written.
+    pub(crate) fn new(capacity: usize, len: usize) -> Self {
+        Self {
+            len: len.into(),
+            consumed: 0.into(),
+            completed: WrittenPositions::new(capacity, len),
+        }
+    }
+
+    /// Makes sure that the bitmap of completed positions covers all positions of a collection with the given `capacity`.
+    pub(crate) fn grow_to(&self, capacity: usize) {
+        self.completed.grow_to(capacity);
+    }
+
+    /// Returns the committed length; all positions below it are written and the writes happen-before this call.
+    #[inline(always)]
+    pub(crate) fn len(&self) -> usize {
+        self.len.load(Ordering::Acquire)
+    }
+
+    /// Returns the number of positions from the front which are consumed.
+    #[inline(always)]
+    pub(crate) fn consumed(&self) -> usize {
+        self.consumed.load(Ordering::Acquire)
+    }
+
+    /// Claims the committed positions which are not yet consumed, and returns the claimed range which might be empty.
+    ///
+    /// Each position is claimed at most once, even when multiple threads consume concurrently.
+    pub(crate) fn claim_unconsumed(&self) -> Range<usize> {
+        let mut begin_idx = self.consumed();
+        loop {
+            let end_idx = self.len();
+            if end_idx <= begin_idx {
+                return begin_idx..begin_idx;
+            }
+            match self.consumed.compare_exchange(
+                begin_idx,
+                end_idx,
+                Ordering::AcqRel,
+                Ordering::Acquire,
+            ) {
+                Ok(_) => return begin_idx..end_idx,
+                Err(consumed) => begin_idx = consumed,
+            }
+        }
+    }
+
+    /// Marks the `range` as completely written and advances the watermark as far as the completed positions allow.
+    ///
+    /// The thread follows the `spin_strategy` if it waits for the bitmap to grow together with the collection.
+    pub(crate) fn commit(&self, range: Range<usize>, capacity: usize, spin_strategy: SpinStrategy) {
+        self.completed.mark(range, spin_strategy);
+
+        // a thread completing a range and then loading the watermark must not miss the range completed concurrently by another thread,
+        // which in turn might have loaded the watermark before this range is marked
+        atomic::fence(Ordering::SeqCst);
+
+        let mut current = self.len();
+        loop {
+            let next = self.completed.first_unmarked(current..capacity);
+            if next == current {
+                break;
+            }
+            current =
+                match self
+                    .len
+                    .compare_exchange(current, next, Ordering::AcqRel, Ordering::Acquire)
+                {
+                    Ok(_) => next,
+                    Err(current) => current,
+                };
+        }
+    }
+
+    /// Returns an iterator over the positions within `len()..end_idx` which are completely written but not committed;
+    /// i.e., the positions beyond a gap which is never going to be written.
+    pub(crate) fn completed_beyond(&self, end_idx: usize) -> impl Iterator<Item = usize> + '_ {
+        self.completed.marked(self.len()..end_idx)
+    }
+
+    /// Resets the watermarks to zero and marks all positions as not completed.
+    pub(crate) fn clear(&mut self, capacity: usize) {
+        self.len.store(0, Ordering::Relaxed);
+        self.consumed.store(0, Ordering::Relaxed);
+        self.completed.clear();
+        self.completed.grow_to(capacity);
+    }
+}
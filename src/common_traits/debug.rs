@@ -1,4 +1,4 @@
-use crate::{ConcurrentState, PinnedConcurrentCol};
+use crate::{ConcurrentLog, ConcurrentState, PinnedConcurrentCol};
 use core::fmt::Debug;
 use orx_pinned_vec::ConcurrentPinnedVec;
 
@@ -8,9 +8,26 @@ where
     S: ConcurrentState<T> + Debug,
 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        f.debug_struct("PinnedConcurrentCol")
+        let mut debug = f.debug_struct("PinnedConcurrentCol");
+        debug
             .field("state", &self.state())
             .field("capacity", &self.capacity())
+            .field("maximum_capacity", &self.maximum_capacity());
+        #[cfg(feature = "metrics")]
+        debug.field("stats", &self.stats());
+        debug.finish()
+    }
+}
+
+impl<T, P> Debug for ConcurrentLog<T, P>
+where
+    P: ConcurrentPinnedVec<T>,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ConcurrentLog")
+            .field("committed_len", &self.committed_len())
+            .field("reserved_len", &self.reserved_len())
+            .field("capacity", &self.capacity())
             .field("maximum_capacity", &self.maximum_capacity())
             .finish()
     }
@@ -0,0 +1,71 @@
+use crate::{spin::SpinStrategy, written::WrittenPositions};
+use core::ops::Range;
+
+/// Shadow state of the positions of a pinned concurrent collection which is maintained when the `debug_checks` feature is enabled.
+///
+/// A position is claimed when a write permit is acquired for it, and released when the write is rolled back.
+/// The checks panic with a descriptive message on:
+/// * claiming an already claimed position; i.e., on concurrent or repeated writes to the same position,
+/// * reading a position which is never claimed, unless the memory is filled,
+/// * taking out the pinned vector with positions below its length which are never claimed, unless the memory is filled.
+///
+/// The checks are meant to validate the implementations of concurrent states and wrappers in tests,
+/// and are not intended for production use.
+pub(crate) struct DebugChecks {
+    claimed: WrittenPositions,
+}
+
+impl DebugChecks {
+    /// Creates the shadow state for a collection with the given `capacity` where positions `0..written_len` are already written.
+    pub(crate) fn new(capacity: usize, written_len: usize) -> Self {
+        Self {
+            claimed: WrittenPositions::new(capacity, written_len),
+        }
+    }
+
+    pub(crate) fn grow_to(&self, capacity: usize) {
+        self.claimed.grow_to(capacity);
+    }
+
+    pub(crate) fn clear(&mut self, capacity: usize) {
+        self.claimed.clear();
+        self.claimed.grow_to(capacity);
+    }
+
+    /// Claims the positions in the `range` for a write, following the `spin_strategy` if the shadow state is yet to grow.
+    pub(crate) fn claim(&self, range: Range<usize>, spin_strategy: SpinStrategy) {
+        let result = self.claimed.try_mark(range.clone(), spin_strategy);
+        assert!(
+            result.is_ok(),
+            "debug_checks: position {} of the write request {:?} is written more than once; concurrent or repeated writes to the same position are undefined behavior",
+            result.err().unwrap_or_default(),
+            range,
+        );
+    }
+
+    /// Releases the positions in the `range` whose write is rolled back.
+    pub(crate) fn release(&self, range: Range<usize>) {
+        self.claimed.unmark(range);
+    }
+
+    /// Checks that all positions in the `range` are written before they are read.
+    pub(crate) fn check_read(&self, range: Range<usize>) {
+        let first_unwritten = self.claimed.first_unmarked(range.clone());
+        assert!(
+            first_unwritten == range.end,
+            "debug_checks: position {} is read before it is written",
+            first_unwritten,
+        );
+    }
+
+    /// Checks that all positions below `len` are written before the pinned vector is taken out with the length `len`.
+    pub(crate) fn check_len(&self, len: usize) {
+        let first_unwritten = self.claimed.first_unmarked(0..len);
+        assert!(
+            first_unwritten == len,
+            "debug_checks: position {} below the length {} is never written",
+            first_unwritten,
+            len,
+        );
+    }
+}
@@ -1,4 +1,129 @@
-pub(crate) const ERR_FAILED_TO_GROW: &str =
-    "The underlying pinned vector reached its capacity and failed to grow";
+use core::fmt::{Debug, Display, Formatter};
+use orx_pinned_vec::PinnedVecGrowthError;
 
-pub(crate) const ERR_REACHED_MAX_CAPACITY: &str = "Out of capacity. Underlying pinned vector cannot grow any further while being concurrently safe.";
+/// Error observed during a fallible request to a [`PinnedConcurrentCol`](crate::PinnedConcurrentCol), or while building one.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PinnedConcurrentColError {
+    /// The requested position is out of the maximum capacity of the underlying pinned vector.
+    /// The vector cannot grow any further while being concurrently safe.
+    OutOfMaximumCapacity {
+        /// Capacity required to complete the write request.
+        required_capacity: usize,
+        /// Maximum capacity that the underlying pinned vector can concurrently grow to.
+        maximum_capacity: usize,
+    },
+    /// The underlying pinned vector reached its capacity and failed to grow.
+    FailedToGrow(PinnedVecGrowthError),
+    /// The concurrent state rejected the write request by returning [`WritePermit::Reject`](crate::WritePermit::Reject).
+    RejectedByState,
+    /// The iterator provided to a write request yielded less elements than the number of positions to write.
+    /// The elements which are already written are dropped.
+    IteratorTooShort {
+        /// Number of positions to write.
+        num_items: usize,
+        /// Number of elements yielded by the iterator.
+        num_yielded: usize,
+    },
+    /// The concurrent state requires the memory to be filled on allocation; however, memory fill is disabled while building the collection.
+    MissingMemoryFill,
+    /// The growth policy is not valid; see [`GrowthPolicy::is_valid`](crate::GrowthPolicy::is_valid).
+    InvalidGrowthPolicy,
+    /// The position is not known to be written; and hence, the pinned vector cannot be safely taken out with the requested length.
+    UnwrittenPosition {
+        /// The first position which is not known to be written.
+        idx: usize,
+    },
+}
+
+impl Display for PinnedConcurrentColError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::OutOfMaximumCapacity {
+                required_capacity,
+                maximum_capacity,
+            } => write!(
+                f,
+                "Out of capacity. Underlying pinned vector cannot grow any further while being concurrently safe. Required capacity is {} while maximum capacity is {}.",
+                required_capacity, maximum_capacity
+            ),
+            Self::FailedToGrow(error) => write!(
+                f,
+                "The underlying pinned vector reached its capacity and failed to grow: {:?}.",
+                error
+            ),
+            Self::RejectedByState => write!(f, "The concurrent state rejected the write request."),
+            Self::IteratorTooShort {
+                num_items,
+                num_yielded,
+            } => write!(
+                f,
+                "The iterator is shorter than expected num_items; it yielded {} elements while {} elements are expected, and the written elements are dropped.",
+                num_yielded, num_items
+            ),
+            Self::MissingMemoryFill => write!(
+                f,
+                "The concurrent state requires the memory to be filled on allocation; however, memory fill is disabled."
+            ),
+            Self::InvalidGrowthPolicy => write!(
+                f,
+                "The growth policy is not valid; the threshold of the grow-ahead growth policy must be within 1..=100."
+            ),
+            Self::UnwrittenPosition { idx } => write!(
+                f,
+                "The position {} is not known to be written; the collection cannot be taken out without a gap.",
+                idx
+            ),
+        }
+    }
+}
+
+impl core::error::Error for PinnedConcurrentColError {}
+
+/// Error of a fallible request writing a single value, which gives the value back to the caller together with the cause of the failure.
+///
+/// The value is not written to the collection; hence, the caller can retry the request or fall back to another destination.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct WriteError<T> {
+    /// Cause of the failure.
+    pub error: PinnedConcurrentColError,
+    /// The value which failed to be written.
+    pub value: T,
+}
+
+impl<T> WriteError<T> {
+    pub(crate) fn new(error: PinnedConcurrentColError, value: T) -> Self {
+        Self { error, value }
+    }
+
+    /// Takes out the value which failed to be written.
+    pub fn into_value(self) -> T {
+        self.value
+    }
+}
+
+impl<T> From<WriteError<T>> for PinnedConcurrentColError {
+    fn from(error: WriteError<T>) -> Self {
+        error.error
+    }
+}
+
+impl<T> Display for WriteError<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(&self.error, f)
+    }
+}
+
+impl<T: Debug> core::error::Error for WriteError<T> {}
+
+/// Unwraps the result of a fallible request within its infallible counterpart; panics with the description of the error.
+///
+/// The descriptions of the capacity and short iterator failures begin with the panic messages of the infallible methods
+/// preceding the fallible API, so that the panics remain recognizable by the callers.
+#[track_caller]
+#[allow(clippy::panic)]
+pub(crate) fn expect_ok<R, E: Display>(result: Result<R, E>) -> R {
+    match result {
+        Ok(x) => x,
+        Err(error) => panic!("{}", error),
+    }
+}
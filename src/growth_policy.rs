@@ -0,0 +1,61 @@
+use core::ops::Range;
+
+/// Policy determining when the collection allocates new positions.
+///
+/// The policy can be defined per concurrent state by [`ConcurrentState::growth_policy`](crate::ConcurrentState::growth_policy),
+/// and overwritten per collection by [`PinnedConcurrentCol::set_growth_policy`](crate::PinnedConcurrentCol::set_growth_policy).
+///
+/// Note that the policy is applied by the concurrent state while evaluating the write permits;
+/// among the provided states, it is respected by the states which guard the growth by a growth handle,
+/// [`OrderedState`](crate::states::OrderedState) and [`FilledState`](crate::states::FilledState).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum GrowthPolicy {
+    /// The collection grows only when a write request exceeds the capacity.
+    ///
+    /// The thread receiving the [`WritePermit::GrowThenWrite`](crate::WritePermit::GrowThenWrite) pays the allocation cost while
+    /// the writers to positions beyond the capacity wait.
+    #[default]
+    OnDemand,
+    /// The collection grows ahead of the write frontier: once a write request reaches `threshold_percent` percent of the capacity,
+    /// the thread receiving the [`WritePermit::WriteAndGrowAhead`](crate::WritePermit::WriteAndGrowAhead) allocates the next fragment
+    /// before the frontier reaches the end of the capacity.
+    ///
+    /// The threshold is measured within the latest allocation, so that the collection grows ahead at most once per capacity;
+    /// the collection is never more than one growth ahead of the frontier.
+    ///
+    /// Smaller thresholds allocate more eagerly; a threshold of 100 is equivalent to [`GrowthPolicy::OnDemand`].
+    /// The threshold must be within `1..=100`; see [`GrowthPolicy::is_valid`].
+    GrowAhead {
+        /// Percentage of the latest allocation that a write request must reach to trigger the allocation of the next fragment.
+        threshold_percent: u8,
+    },
+}
+
+impl GrowthPolicy {
+    /// Returns whether or not the policy is valid; i.e., the threshold of [`GrowthPolicy::GrowAhead`] is within `1..=100`.
+    pub fn is_valid(self) -> bool {
+        match self {
+            Self::OnDemand => true,
+            Self::GrowAhead { threshold_percent } => (1..=100).contains(&threshold_percent),
+        }
+    }
+
+    /// Returns whether or not a write request whose last position is `last_idx` must trigger a growth ahead of the frontier,
+    /// given the `latest_allocation` of the collection, the range of positions allocated by its latest growth which ends at the capacity;
+    /// see [`PinnedConcurrentCol::latest_allocation`](crate::PinnedConcurrentCol::latest_allocation).
+    ///
+    /// Always returns false for positions beyond the capacity, which require a [`WritePermit::GrowThenWrite`](crate::WritePermit::GrowThenWrite),
+    /// and for positions before the latest allocation, which has already been allocated ahead of them.
+    pub fn grows_ahead(self, last_idx: usize, latest_allocation: Range<usize>) -> bool {
+        match self {
+            Self::OnDemand => false,
+            Self::GrowAhead { threshold_percent } => {
+                let threshold_percent = threshold_percent.clamp(1, 100) as u128;
+                let Range { start, end } = latest_allocation;
+                let len = end.saturating_sub(start) as u128;
+                let threshold = start + (len * threshold_percent).div_ceil(100) as usize;
+                threshold <= last_idx && last_idx < end
+            }
+        }
+    }
+}
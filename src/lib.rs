@@ -13,18 +13,61 @@
 #![no_std]
 
 extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
+mod atomic_col;
+mod builder;
 mod col;
+mod committed;
 mod common_traits;
+#[cfg(feature = "debug_checks")]
+mod debug_checks;
 mod errors;
+mod growth_policy;
+mod local_writer;
+mod log;
 mod mem_state;
+mod memory_fill;
+#[cfg(feature = "metrics")]
+mod metrics;
 mod new;
+mod once;
+#[cfg(feature = "rayon")]
+mod par_iter;
+mod reservation;
+mod ring;
+mod rollback;
+mod slab;
+mod slice_producer;
+mod spin;
 mod state;
+mod sync;
 mod write_permit;
+mod written;
 
 /// Common relevant traits, structs, enums.
 pub mod prelude;
+/// Ready-to-use implementations of the [`ConcurrentState`] trait.
+pub mod states;
 
+pub use atomic_col::{AtomicInteger, ConcurrentAtomicCol};
+pub use builder::PinnedConcurrentColBuilder;
 pub use col::PinnedConcurrentCol;
-pub use state::ConcurrentState;
+pub use errors::{PinnedConcurrentColError, WriteError};
+pub use growth_policy::GrowthPolicy;
+pub use local_writer::LocalWriter;
+pub use log::ConcurrentLog;
+pub use memory_fill::MemoryFill;
+#[cfg(feature = "metrics")]
+pub use metrics::ColStats;
+pub use once::{ConcurrentOnceCol, OnceSlot};
+#[cfg(feature = "rayon")]
+pub use par_iter::ParSliceIter;
+pub use reservation::{Reservation, ReservationOutcome};
+pub use ring::ConcurrentRing;
+pub use slab::{ConcurrentSlab, SlabKey, SlabRef, SlabSlot};
+pub use slice_producer::{SliceProducer, SliceProducerIter};
+pub use spin::{Backoff, SpinStrategy};
+pub use state::{ConcurrentState, PublishedState};
 pub use write_permit::WritePermit;
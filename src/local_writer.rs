@@ -0,0 +1,165 @@
+use crate::{
+    PinnedConcurrentCol,
+    errors::{WriteError, expect_ok},
+    reservation::{Reservation, ReservationOutcome},
+    state::ConcurrentState,
+};
+use core::ops::Range;
+use orx_pinned_vec::ConcurrentPinnedVec;
+
+/// A per-thread writer which reserves chunks of positions at once and writes to them locally,
+/// so that the shared counters of the wrapper are updated once per chunk rather than once per element.
+///
+/// * A local writer is created by [`PinnedConcurrentCol::local_writer`] with a `reserve` function
+///   which claims `chunk_size` positions of the collection and returns the first position of the claimed range;
+///   such as `|n| col.state().fetch_increment_len(n)` for the [`AppendOnlyState`](crate::states::AppendOnlyState).
+/// * Each chunk is a [`Reservation`]; and hence, the collection grows whenever necessary while reserving the chunk,
+///   and only the written positions of a chunk are committed to the concurrent state.
+/// * Unused positions of the last chunk are flushed by [`LocalWriter::flush`] or on drop:
+///   * if the memory is filled, the unused positions hold valid fill values and they are committed together with the written positions;
+///   * otherwise, the unused positions are left as holes, which the wrapper is responsible for handling.
+///
+/// # Examples
+///
+/// ```
+/// use orx_pinned_concurrent_col::prelude::*;
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+///
+/// let col: PinnedConcurrentCol<Option<u64>, _, FilledState<_>> = PinnedConcurrentCol::with_doubling_growth();
+/// let len = AtomicUsize::new(0);
+///
+/// let mut writer = unsafe { col.local_writer(8, |n| len.fetch_add(n, Ordering::Relaxed)) };
+/// for i in 0..10 {
+///     assert_eq!(writer.push(Some(i)), i as usize);
+/// }
+/// assert_eq!(writer.flush(), None); // unused positions 10..16 are filled with None
+/// drop(writer);
+///
+/// let vec = unsafe { col.into_inner(16) };
+/// assert_eq!(vec.get(9), Some(&Some(9)));
+/// assert_eq!(vec.get(12), Some(&None));
+/// ```
+pub struct LocalWriter<'a, T, P, S, R>
+where
+    P: ConcurrentPinnedVec<T>,
+    S: ConcurrentState<T>,
+    R: FnMut(usize) -> usize,
+{
+    col: &'a PinnedConcurrentCol<T, P, S>,
+    reserve: R,
+    chunk_size: usize,
+    chunk: Option<Reservation<'a, T, P, S>>,
+}
+
+impl<T, P, S, R> Drop for LocalWriter<'_, T, P, S, R>
+where
+    P: ConcurrentPinnedVec<T>,
+    S: ConcurrentState<T>,
+    R: FnMut(usize) -> usize,
+{
+    fn drop(&mut self) {
+        let _ = self.flush();
+    }
+}
+
+impl<'a, T, P, S, R> LocalWriter<'a, T, P, S, R>
+where
+    P: ConcurrentPinnedVec<T>,
+    S: ConcurrentState<T>,
+    R: FnMut(usize) -> usize,
+{
+    pub(crate) fn new(
+        col: &'a PinnedConcurrentCol<T, P, S>,
+        chunk_size: usize,
+        reserve: R,
+    ) -> Self {
+        assert!(
+            chunk_size > 0,
+            "chunk size of a local writer must be positive"
+        );
+        Self {
+            col,
+            reserve,
+            chunk_size,
+            chunk: None,
+        }
+    }
+
+    /// Returns the number of positions reserved at once.
+    pub fn chunk_size(&self) -> usize {
+        self.chunk_size
+    }
+
+    /// Returns the range of the unused positions of the current chunk.
+    pub fn unused_range(&self) -> Range<usize> {
+        match &self.chunk {
+            Some(chunk) => (chunk.range().end - chunk.num_remaining())..chunk.range().end,
+            None => 0..0,
+        }
+    }
+
+    /// Writes the `value` to the next position of the current chunk, reserving a new chunk whenever necessary,
+    /// and returns the position that the value is written to.
+    ///
+    /// See [`LocalWriter::try_push`] for the fallible version of the method.
+    ///
+    /// # Panics
+    ///
+    /// Panics if reserving a new chunk fails; i.e., if [`LocalWriter::try_push`] returns an error.
+    pub fn push(&mut self, value: T) -> usize {
+        expect_ok(self.try_push(value))
+    }
+
+    /// Writes the `value` to the next position of the current chunk, reserving a new chunk whenever necessary,
+    /// and returns the position that the value is written to; returns an error rather than panicking if the request fails.
+    ///
+    /// When the request fails, the `value` is given back to the caller within the [`WriteError`];
+    /// while the positions claimed by the `reserve` function are left as holes.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`WriteError`] with the same causes as the errors of [`PinnedConcurrentCol::try_reserve_n_items`].
+    pub fn try_push(&mut self, value: T) -> Result<usize, WriteError<T>> {
+        if let Some(chunk) = &mut self.chunk
+            && !chunk.is_complete()
+        {
+            return Ok(push_to(chunk, value));
+        }
+
+        let _ = self.flush();
+        let begin_idx = (self.reserve)(self.chunk_size);
+        // SAFETY: the positions claimed by the reserve function are owned by this writer, see PinnedConcurrentCol::local_writer
+        match unsafe { self.col.try_reserve_n_items(begin_idx, self.chunk_size) } {
+            Ok(chunk) => Ok(push_to(self.chunk.insert(chunk), value)),
+            Err(error) => Err(WriteError::new(error, value)),
+        }
+    }
+
+    /// Commits the written positions of the current chunk to the concurrent state, and releases the chunk.
+    ///
+    /// If the memory is filled, the unused positions of the chunk are committed as well, since they hold valid fill values;
+    /// and the method returns None.
+    /// Otherwise, the method returns the range of the unused positions which are left as holes, if any.
+    pub fn flush(&mut self) -> Option<Range<usize>> {
+        let unused = self.unused_range();
+        let mut chunk = self.chunk.take()?;
+        if self.col.fill_memory_with().is_some() {
+            // SAFETY: the unused positions are filled on allocation and not written by any other thread
+            unsafe { chunk.assume_init(chunk.num_remaining()) };
+        }
+        match chunk.finish() {
+            ReservationOutcome::Complete => None,
+            ReservationOutcome::Partial { .. } => Some(unused),
+        }
+    }
+}
+
+fn push_to<T, P, S>(chunk: &mut Reservation<'_, T, P, S>, value: T) -> usize
+where
+    P: ConcurrentPinnedVec<T>,
+    S: ConcurrentState<T>,
+{
+    let idx = chunk.range().start + chunk.num_initialized();
+    let _ = chunk.push(value);
+    idx
+}
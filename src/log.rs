@@ -0,0 +1,316 @@
+use crate::{
+    PinnedConcurrentCol,
+    errors::{PinnedConcurrentColError, WriteError, expect_ok},
+    states::LogState,
+};
+use alloc::vec::Vec;
+use core::{mem::ManuallyDrop, ops::Range};
+use orx_fixed_vec::ConcurrentFixedVec;
+use orx_pinned_vec::{ConcurrentPinnedVec, IntoConcurrentPinnedVec};
+use orx_split_vec::{ConcurrentSplitVec, Doubling, Linear};
+
+/// A safe, append-only concurrent log built directly on top of a [`PinnedConcurrentCol`].
+///
+/// * Values are appended concurrently by [`ConcurrentLog::push`] and [`ConcurrentLog::extend`] which return the positions assigned to the values.
+/// * The log maintains a committed length, which is the length of its prefix that is completely written.
+///   Readers can safely access elements within `0..committed_len()` while other threads keep appending.
+/// * The underlying pinned vector can be taken out by [`ConcurrentLog::into_inner`].
+///
+/// The log serves as the reference safe wrapper of the pinned concurrent collection.
+/// It satisfies the safety requirements of the core as follows:
+/// * each position is reserved by exactly one writer through the [`LogState`];
+/// * readers only access the committed prefix, which is never written again;
+/// * the committed prefix is gap-free, and hence, it is the length to drop or to take out.
+///
+/// Writes complete in any order and never wait for each other: a write request returns as soon as its values are written,
+/// and its values are committed, i.e., become visible to the readers, once all prior positions are written as well.
+///
+/// Values are collected before their positions are reserved; hence, a panicking iterator never leaves a reserved range unwritten.
+/// If a reserved range still fails to be written, such as when the log is out of its maximum capacity, the log is poisoned:
+/// the committed length stops at the failed range, all further writes fail with [`PinnedConcurrentColError::RejectedByState`],
+/// and the values already written after the failed range are never committed; they are dropped together with the log.
+///
+/// # Examples
+///
+/// ```
+/// use orx_pinned_concurrent_col::prelude::*;
+///
+/// let log = ConcurrentLog::new();
+///
+/// std::thread::scope(|s| {
+///     for t in 0..4 {
+///         let log = &log;
+///         s.spawn(move || {
+///             for i in 0..100 {
+///                 log.push(t * 1000 + i);
+///             }
+///         });
+///     }
+/// });
+///
+/// // all writers are joined; hence, all positions are committed
+/// assert_eq!(log.committed_len(), 400);
+///
+/// let range = log.extend([7, 8, 9]);
+/// assert_eq!(range, 400..403);
+/// assert_eq!(log.get(401), Some(&8));
+///
+/// let vec = log.into_inner();
+/// assert_eq!(vec.len(), 403);
+/// ```
+pub struct ConcurrentLog<T, P = ConcurrentSplitVec<T, Doubling>>
+where
+    P: ConcurrentPinnedVec<T>,
+{
+    col: PinnedConcurrentCol<T, P, LogState<T>>,
+}
+
+impl<T, P> Drop for ConcurrentLog<T, P>
+where
+    P: ConcurrentPinnedVec<T>,
+{
+    fn drop(&mut self) {
+        self.drop_uncommitted();
+    }
+}
+
+unsafe impl<T: Send, P: ConcurrentPinnedVec<T>> Send for ConcurrentLog<T, P> {}
+
+unsafe impl<T: Send + Sync, P: ConcurrentPinnedVec<T>> Sync for ConcurrentLog<T, P> {}
+
+impl<T> ConcurrentLog<T, ConcurrentSplitVec<T, Doubling>> {
+    /// Creates a new empty log using a `SplitVec<T, Doubling>` as the underlying storage.
+    pub fn new() -> Self {
+        Self::with_doubling_growth()
+    }
+
+    /// Creates a new empty log using a `SplitVec<T, Doubling>` as the underlying storage.
+    pub fn with_doubling_growth() -> Self {
+        Self {
+            col: PinnedConcurrentCol::with_doubling_growth(),
+        }
+    }
+}
+
+impl<T> Default for ConcurrentLog<T, ConcurrentSplitVec<T, Doubling>> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> ConcurrentLog<T, ConcurrentSplitVec<T, Linear>> {
+    /// Creates a new empty log using a `SplitVec<T, Linear>` as the underlying storage.
+    ///
+    /// See [`PinnedConcurrentCol::with_linear_growth`] for details of the arguments.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `fragments_capacity == 0`.
+    pub fn with_linear_growth(
+        constant_fragment_capacity_exponent: usize,
+        fragments_capacity: usize,
+    ) -> Self {
+        Self {
+            col: PinnedConcurrentCol::with_linear_growth(
+                constant_fragment_capacity_exponent,
+                fragments_capacity,
+            ),
+        }
+    }
+}
+
+impl<T> ConcurrentLog<T, ConcurrentFixedVec<T>> {
+    /// Creates a new empty log using a `FixedVec<T>` as the underlying storage.
+    ///
+    /// Note that the log cannot grow beyond the `fixed_capacity`.
+    pub fn with_fixed_capacity(fixed_capacity: usize) -> Self {
+        Self {
+            col: PinnedConcurrentCol::with_fixed_capacity(fixed_capacity),
+        }
+    }
+}
+
+impl<T, P> ConcurrentLog<T, P>
+where
+    P: ConcurrentPinnedVec<T>,
+{
+    /// Wraps the `pinned_vec` and converts it into a concurrent log.
+    ///
+    /// Elements already in the `pinned_vec` are considered to be committed.
+    pub fn new_from_pinned<Q>(pinned_vec: Q) -> Self
+    where
+        Q: IntoConcurrentPinnedVec<T, ConPinnedVec = P>,
+    {
+        Self {
+            col: PinnedConcurrentCol::new_from_pinned(pinned_vec),
+        }
+    }
+
+    /// Returns the length of the prefix of the log which is completely written and can be safely read.
+    #[inline(always)]
+    pub fn committed_len(&self) -> usize {
+        self.col.state().committed_len()
+    }
+
+    /// Returns the number of positions reserved by the writers so far; some of which might not yet be committed.
+    #[inline(always)]
+    pub fn reserved_len(&self) -> usize {
+        self.col.state().len()
+    }
+
+    /// Returns whether or not the log is poisoned since a reserved range failed to be written;
+    /// all writes to a poisoned log fail.
+    #[inline(always)]
+    pub fn is_poisoned(&self) -> bool {
+        self.col.state().is_poisoned()
+    }
+
+    /// Returns whether or not the log has any committed element.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.committed_len() == 0
+    }
+
+    /// Returns the current allocated capacity of the log.
+    pub fn capacity(&self) -> usize {
+        self.col.capacity()
+    }
+
+    /// Returns maximum possible capacity that the log can concurrently grow to.
+    pub fn maximum_capacity(&self) -> usize {
+        self.col.maximum_capacity()
+    }
+
+    /// Appends the `value` to the log and returns its position.
+    ///
+    /// The method returns once the value is written, without waiting for the prior positions;
+    /// the value becomes visible to the readers once all prior positions are written as well.
+    ///
+    /// See [`ConcurrentLog::try_push`] for the fallible version of the method.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the log is out of its maximum capacity, or if the log is poisoned.
+    pub fn push(&self, value: T) -> usize {
+        expect_ok(self.try_push(value))
+    }
+
+    /// Appends the `value` to the log and returns its position; returns an error rather than panicking if the write fails.
+    ///
+    /// The method returns once the value is written, without waiting for the prior positions;
+    /// the value becomes visible to the readers once all prior positions are written as well.
+    ///
+    /// # Errors
+    ///
+    /// * Returns the errors of [`PinnedConcurrentCol::try_write`], such as when the log is out of its maximum capacity,
+    ///   in which case the log is poisoned.
+    /// * Returns [`PinnedConcurrentColError::RejectedByState`] if the log is poisoned.
+    ///
+    /// In either case, the `value` is given back to the caller within the [`WriteError`].
+    pub fn try_push(&self, value: T) -> Result<usize, WriteError<T>> {
+        let idx = self.col.state().fetch_increment_len(1);
+        // SAFETY: idx is reserved only for this writer
+        let result = unsafe { self.col.try_write(idx, value) };
+        self.complete(idx..(idx + 1), result)?;
+        Ok(idx)
+    }
+
+    /// Appends all `values` to sequential positions of the log and returns the range of these positions.
+    ///
+    /// The method returns once the values are written, without waiting for the prior positions;
+    /// the values become visible to the readers once all prior positions are written as well.
+    ///
+    /// See [`ConcurrentLog::try_extend`] for the fallible version of the method.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the log is out of its maximum capacity, or if the log is poisoned.
+    /// A panic of the `values` iterator is propagated before any position is reserved.
+    pub fn extend<I>(&self, values: I) -> Range<usize>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        expect_ok(self.try_extend(values))
+    }
+
+    /// Appends all `values` to sequential positions of the log and returns the range of these positions;
+    /// returns an error rather than panicking if the write fails.
+    ///
+    /// The values are collected before the positions are reserved; hence, a panic of the `values` iterator
+    /// is propagated before any position is reserved, and the log remains intact.
+    ///
+    /// # Errors
+    ///
+    /// * Returns the errors of [`PinnedConcurrentCol::try_write_n_items`], such as when the log is out of its maximum capacity,
+    ///   in which case the log is poisoned.
+    /// * Returns [`PinnedConcurrentColError::RejectedByState`] if the log is poisoned, in which case the `values` are dropped.
+    pub fn try_extend<I>(&self, values: I) -> Result<Range<usize>, PinnedConcurrentColError>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let values: Vec<_> = values.into_iter().collect();
+        let num_items = values.len();
+        let begin_idx = self.col.state().fetch_increment_len(num_items);
+        let range = begin_idx..(begin_idx + num_items);
+        // SAFETY: range is reserved only for this writer
+        let result = unsafe { self.col.try_write_n_items(begin_idx, num_items, values) };
+        self.complete(range.clone(), result)?;
+        Ok(range)
+    }
+
+    /// Returns a reference to the element at the `idx`-th position if it is committed; returns None otherwise.
+    pub fn get(&self, idx: usize) -> Option<&T> {
+        self.col.try_get_published(idx)
+    }
+
+    /// Waits until the element at the `idx`-th position is committed and returns a reference to it.
+    ///
+    /// This allows consumers to follow the producers without an additional channel.
+    /// Note that the method never returns if the position is never pushed.
+    pub fn wait_get(&self, idx: usize) -> &T {
+        self.col.wait_get(idx)
+    }
+
+    /// Returns an iterator over the elements which are committed at the time of the call.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        let len = self.committed_len();
+        // SAFETY: committed positions are written and never written again
+        unsafe { self.col.iter(len) }
+    }
+
+    /// Converts the log into the underlying pinned vector containing the committed elements.
+    pub fn into_inner(self) -> P::P
+    where
+        P::P: IntoConcurrentPinnedVec<T, ConPinnedVec = P>,
+    {
+        let mut log = ManuallyDrop::new(self);
+        log.drop_uncommitted();
+        // SAFETY: the log is never used nor dropped after the collection is moved out
+        let col = unsafe { core::ptr::read(&log.col) };
+        let len = col.state().committed_len();
+        // SAFETY: committed prefix is gap-free
+        unsafe { col.into_inner(len) }
+    }
+
+    /// Completes the write of the reserved `range` with the `result` of the write request.
+    ///
+    /// A failed write poisons the log since the range will never be committed.
+    /// A written range is already marked by the state and it is committed as soon as the prior positions are written; hence, the writer does not wait.
+    fn complete<E>(&self, range: Range<usize>, result: Result<(), E>) -> Result<(), E> {
+        if result.is_err() {
+            self.col.state().poison(range.start);
+        }
+        result
+    }
+
+    /// Drops the elements which are written after a range which failed to be written, and hence, which are never committed.
+    ///
+    /// These elements are beyond the committed length; and hence, they are neither dropped nor taken out by the collection.
+    fn drop_uncommitted(&mut self) {
+        let end_idx = self.reserved_len().min(self.col.capacity());
+        for idx in self.col.state().written_uncommitted(end_idx) {
+            // SAFETY: the position is written, it is never committed, and the log is exclusively borrowed
+            unsafe { self.col.ptr_mut_at(idx).drop_in_place() };
+        }
+    }
+}
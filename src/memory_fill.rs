@@ -0,0 +1,109 @@
+use alloc::sync::Arc;
+use core::fmt::Debug;
+
+/// Defines the values that new allocations of the underlying pinned vector are filled with.
+///
+/// A memory fill can be created from:
+/// * a function pointer, [`MemoryFill::from_fn`],
+/// * the default value of the type, [`MemoryFill::from_default`],
+/// * a template value which is cloned into each position, [`MemoryFill::from_value`], or
+/// * a closure which might capture state computed at runtime, [`MemoryFill::from_closure`].
+///
+/// # Examples
+///
+/// ```
+/// use orx_pinned_concurrent_col::*;
+///
+/// let fill = MemoryFill::from_fn(|| 42);
+/// assert_eq!(fill.fill(), 42);
+///
+/// let fill = MemoryFill::<String>::from_default();
+/// assert_eq!(fill.fill(), String::new());
+///
+/// let fill = MemoryFill::from_value(vec!['e', 'm', 'p', 't', 'y']);
+/// assert_eq!(fill.fill(), vec!['e', 'm', 'p', 't', 'y']);
+///
+/// let sentinel = u64::MAX - 7;
+/// let fill = MemoryFill::from_closure(move || sentinel);
+/// assert_eq!(fill.fill(), sentinel);
+/// ```
+pub struct MemoryFill<T> {
+    fill: Fill<T>,
+}
+
+enum Fill<T> {
+    Fn(fn() -> T),
+    Closure(Arc<dyn Fn() -> T + Send + Sync>),
+}
+
+impl<T> MemoryFill<T> {
+    /// Creates a memory fill where each position is filled with `fill_with()`.
+    pub fn from_fn(fill_with: fn() -> T) -> Self {
+        Self {
+            fill: Fill::Fn(fill_with),
+        }
+    }
+
+    /// Creates a memory fill where each position is filled with `T::default()`.
+    pub fn from_default() -> Self
+    where
+        T: Default,
+    {
+        Self::from_fn(T::default)
+    }
+
+    /// Creates a memory fill where each position is filled with a clone of the `value`.
+    pub fn from_value(value: T) -> Self
+    where
+        T: Clone + Send + Sync + 'static,
+    {
+        Self::from_closure(move || value.clone())
+    }
+
+    /// Creates a memory fill where each position is filled with `fill_with()`.
+    ///
+    /// Since the closure might be called concurrently by the thread growing the collection, it is required to be `Send + Sync`.
+    pub fn from_closure<F>(fill_with: F) -> Self
+    where
+        F: Fn() -> T + Send + Sync + 'static,
+    {
+        Self {
+            fill: Fill::Closure(Arc::new(fill_with)),
+        }
+    }
+
+    /// Creates the value that a new position is filled with.
+    #[inline(always)]
+    pub fn fill(&self) -> T {
+        match &self.fill {
+            Fill::Fn(f) => f(),
+            Fill::Closure(f) => f(),
+        }
+    }
+}
+
+impl<T> Clone for MemoryFill<T> {
+    fn clone(&self) -> Self {
+        let fill = match &self.fill {
+            Fill::Fn(f) => Fill::Fn(*f),
+            Fill::Closure(f) => Fill::Closure(f.clone()),
+        };
+        Self { fill }
+    }
+}
+
+impl<T> Debug for MemoryFill<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let kind = match &self.fill {
+            Fill::Fn(_) => "Fn",
+            Fill::Closure(_) => "Closure",
+        };
+        f.debug_struct("MemoryFill").field("kind", &kind).finish()
+    }
+}
+
+impl<T> From<fn() -> T> for MemoryFill<T> {
+    fn from(fill_with: fn() -> T) -> Self {
+        Self::from_fn(fill_with)
+    }
+}
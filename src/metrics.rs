@@ -0,0 +1,155 @@
+#[cfg(target_has_atomic = "64")]
+use crate::sync::AtomicU64;
+use crate::sync::{AtomicUsize, Ordering};
+use core::{cell::Cell, time::Duration};
+
+/// Counter of the nanoseconds, which is a 64-bit word whenever the target supports 64-bit atomics; a `usize` otherwise,
+/// in which case the durations wrap around after `2^usize::BITS` nanoseconds.
+#[cfg(target_has_atomic = "64")]
+type NanosCounter = AtomicU64;
+#[cfg(not(target_has_atomic = "64"))]
+type NanosCounter = AtomicUsize;
+
+/// Snapshot of the growth and contention counters of a [`PinnedConcurrentCol`](crate::PinnedConcurrentCol),
+/// obtained by [`PinnedConcurrentCol::stats`](crate::PinnedConcurrentCol::stats).
+///
+/// The counters are collected with relaxed atomics; hence, a snapshot taken while writers are active
+/// is not necessarily consistent among its fields.
+///
+/// Durations are measured only when the `std` feature is enabled; they are zero otherwise.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ColStats {
+    /// Number of successful growths of the underlying pinned vector, including the growths ahead of the write frontier.
+    pub num_grows: usize,
+    /// Number of growth attempts of the underlying pinned vector which failed.
+    pub num_failed_grows: usize,
+    /// Number of spin iterations of the single item write requests, such as [`write`](crate::PinnedConcurrentCol::write).
+    pub num_spins_write: usize,
+    /// Number of spin iterations of the multiple items write requests, such as [`write_n_items`](crate::PinnedConcurrentCol::write_n_items)
+    /// and [`reserve_n_items`](crate::PinnedConcurrentCol::reserve_n_items).
+    pub num_spins_write_n_items: usize,
+    /// Number of bytes allocated by the growths.
+    pub bytes_allocated: usize,
+    /// Total duration that the growth handle is held while allocating, including filling the new allocation
+    /// when the state requires a [`MemoryFill`](crate::MemoryFill).
+    pub growth_duration: Duration,
+    /// Total duration of filling the new allocations with the [`MemoryFill`](crate::MemoryFill);
+    /// by the growths, where it is a part of the `growth_duration`, and by [`reserve_maximum_capacity`](crate::PinnedConcurrentCol::reserve_maximum_capacity).
+    ///
+    /// The fill of an allocation is timed once, from its first fill call until the allocation completes;
+    /// hence, it excludes the allocation of the first new fragment, while it includes the allocations of the further fragments, if any.
+    pub fill_duration: Duration,
+}
+
+#[derive(Default)]
+pub(crate) struct Metrics {
+    num_grows: AtomicUsize,
+    num_failed_grows: AtomicUsize,
+    num_spins_write: AtomicUsize,
+    num_spins_write_n_items: AtomicUsize,
+    bytes_allocated: AtomicUsize,
+    growth_nanos: NanosCounter,
+    fill_nanos: NanosCounter,
+}
+
+impl Metrics {
+    pub(crate) fn record_spins(&self, num_items: usize, num_spins: u32) {
+        if num_spins > 0 {
+            let counter = match num_items {
+                1 => &self.num_spins_write,
+                _ => &self.num_spins_write_n_items,
+            };
+            counter.fetch_add(num_spins as usize, Ordering::Relaxed);
+        }
+    }
+
+    pub(crate) fn record_growth(
+        &self,
+        num_bytes: Option<usize>,
+        timer: Timer,
+        fill_timer: FillTimer,
+    ) {
+        match num_bytes {
+            Some(num_bytes) => {
+                self.num_grows.fetch_add(1, Ordering::Relaxed);
+                self.bytes_allocated.fetch_add(num_bytes, Ordering::Relaxed);
+            }
+            None => {
+                self.num_failed_grows.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+
+        self.growth_nanos
+            .fetch_add(timer.elapsed_nanos() as _, Ordering::Relaxed);
+        self.record_fill(fill_timer);
+    }
+
+    pub(crate) fn record_fill(&self, fill_timer: FillTimer) {
+        self.fill_nanos
+            .fetch_add(fill_timer.elapsed_nanos() as _, Ordering::Relaxed);
+    }
+
+    pub(crate) fn stats(&self) -> ColStats {
+        ColStats {
+            num_grows: self.num_grows.load(Ordering::Relaxed),
+            num_failed_grows: self.num_failed_grows.load(Ordering::Relaxed),
+            num_spins_write: self.num_spins_write.load(Ordering::Relaxed),
+            num_spins_write_n_items: self.num_spins_write_n_items.load(Ordering::Relaxed),
+            bytes_allocated: self.bytes_allocated.load(Ordering::Relaxed),
+            growth_duration: duration(&self.growth_nanos),
+            fill_duration: duration(&self.fill_nanos),
+        }
+    }
+}
+
+#[allow(clippy::unnecessary_cast)]
+fn duration(nanos: &NanosCounter) -> Duration {
+    Duration::from_nanos(nanos.load(Ordering::Relaxed) as u64)
+}
+
+/// Measures the elapsed time when the `std` feature is enabled; a no-op otherwise.
+#[derive(Clone, Copy)]
+pub(crate) struct Timer {
+    #[cfg(feature = "std")]
+    begin: std::time::Instant,
+}
+
+impl Timer {
+    pub(crate) fn start() -> Self {
+        Self {
+            #[cfg(feature = "std")]
+            begin: std::time::Instant::now(),
+        }
+    }
+
+    fn elapsed_nanos(&self) -> u64 {
+        #[cfg(feature = "std")]
+        return self.begin.elapsed().as_nanos() as u64;
+        #[cfg(not(feature = "std"))]
+        return 0;
+    }
+}
+
+/// Measures the memory fill of a single allocation, whose fill calls are interleaved with the allocations of the fragments by the underlying pinned vector.
+///
+/// The clock is read once when the first fill call begins and once when the measurement is recorded, rather than per filled element.
+#[derive(Default)]
+pub(crate) struct FillTimer {
+    begin: Cell<Option<Timer>>,
+}
+
+impl FillTimer {
+    pub(crate) fn measure<T>(&self, fill: impl FnOnce() -> T) -> T {
+        if self.begin.get().is_none() {
+            self.begin.set(Some(Timer::start()));
+        }
+        fill()
+    }
+
+    fn elapsed_nanos(&self) -> u64 {
+        match self.begin.get() {
+            Some(timer) => timer.elapsed_nanos(),
+            None => 0,
+        }
+    }
+}
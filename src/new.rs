@@ -2,6 +2,8 @@ use crate::{col::PinnedConcurrentCol, state::ConcurrentState};
 use orx_fixed_vec::{ConcurrentFixedVec, FixedVec};
 use orx_split_vec::{ConcurrentSplitVec, Doubling, Linear, SplitVec};
 
+const ERR_INITIAL_CAPACITY: &str = "initial capacity must not exceed the maximum capacity";
+
 impl<T, S> PinnedConcurrentCol<T, ConcurrentSplitVec<T, Doubling>, S>
 where
     S: ConcurrentState<T>,
@@ -10,6 +12,20 @@ where
     pub fn with_doubling_growth() -> Self {
         Self::new_from_pinned(SplitVec::with_doubling_growth_and_max_concurrent_capacity())
     }
+
+    /// Creates a new concurrent bag by creating and wrapping up a new `SplitVec<T, Doubling>` as the underlying storage,
+    /// where at least `initial_capacity` positions are allocated up front.
+    ///
+    /// Pre-allocating the expected capacity avoids the first writers to race into growing the collection.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `initial_capacity` exceeds the maximum capacity of the doubling split vector.
+    pub fn with_doubling_growth_and_capacity(initial_capacity: usize) -> Self {
+        Self::with_doubling_growth()
+            .with_allocated_capacity(0, initial_capacity)
+            .expect(ERR_INITIAL_CAPACITY)
+    }
 }
 
 impl<T, S> PinnedConcurrentCol<T, ConcurrentSplitVec<T, Linear>, S>
@@ -23,6 +39,14 @@ where
     /// `fragments_capacity` determines the initial `maximum_capacity` of the vector as follows: `maximum_capacity * 2 ^ constant_fragment_capacity_exponent`,
     /// which can be increased by `reserve_maximum_capacity` when necessary.
     ///
+    /// Note that the collection does not raise the maximum capacity automatically while writers hold only a shared reference.
+    /// This is not supported by the underlying storage: `ConcurrentSplitVec` keeps its fragment pointers in a `Vec` of fixed length,
+    /// which would have to be reallocated while the writers access it; and accordingly,
+    /// `ConcurrentPinnedVec::reserve_maximum_concurrent_capacity` requires an exclusive reference.
+    /// When the total size cannot be predicted up front:
+    /// * writers can use the fallible [`PinnedConcurrentCol::try_write`] methods which return [`PinnedConcurrentColError::OutOfMaximumCapacity`](crate::PinnedConcurrentColError::OutOfMaximumCapacity) rather than panicking, and
+    /// * the maximum capacity can be increased by [`PinnedConcurrentCol::reserve_maximum_capacity`] once exclusive access is regained, after which writers can continue.
+    ///
     /// # Panics
     ///
     /// Panics if `fragments_capacity == 0`.
@@ -35,6 +59,25 @@ where
             fragments_capacity,
         ))
     }
+
+    /// Creates a new concurrent bag by creating and wrapping up a new `SplitVec<T, Linear>` as the underlying storage,
+    /// where at least `initial_capacity` positions are allocated up front.
+    ///
+    /// See [`PinnedConcurrentCol::with_linear_growth`] for details of the `constant_fragment_capacity_exponent` and `fragments_capacity` arguments.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `fragments_capacity == 0`, or
+    /// if `initial_capacity` exceeds the maximum capacity `fragments_capacity * 2 ^ constant_fragment_capacity_exponent`.
+    pub fn with_linear_growth_and_capacity(
+        constant_fragment_capacity_exponent: usize,
+        fragments_capacity: usize,
+        initial_capacity: usize,
+    ) -> Self {
+        Self::with_linear_growth(constant_fragment_capacity_exponent, fragments_capacity)
+            .with_allocated_capacity(0, initial_capacity)
+            .expect(ERR_INITIAL_CAPACITY)
+    }
 }
 
 impl<T, S> PinnedConcurrentCol<T, ConcurrentFixedVec<T>, S>
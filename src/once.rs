@@ -0,0 +1,261 @@
+use crate::{
+    PinnedConcurrentCol,
+    errors::{PinnedConcurrentColError, expect_ok},
+    states::FilledState,
+    sync::{AtomicUsize, atomic::Ordering},
+};
+use core::{cell::UnsafeCell, mem::MaybeUninit};
+use orx_fixed_vec::ConcurrentFixedVec;
+use orx_pinned_vec::ConcurrentPinnedVec;
+use orx_split_vec::{ConcurrentSplitVec, Doubling, Linear};
+
+const EMPTY: usize = 0;
+const INITIALIZING: usize = 1;
+const READY: usize = 2;
+
+/// A slot of a [`ConcurrentOnceCol`], which is the element type of its underlying pinned vector.
+///
+/// A slot is either empty, being initialized by exactly one thread, or ready holding its value.
+pub struct OnceSlot<T> {
+    state: AtomicUsize,
+    value: UnsafeCell<MaybeUninit<T>>,
+}
+
+impl<T> Default for OnceSlot<T> {
+    fn default() -> Self {
+        Self {
+            state: AtomicUsize::new(EMPTY),
+            value: UnsafeCell::new(MaybeUninit::uninit()),
+        }
+    }
+}
+
+impl<T> Drop for OnceSlot<T> {
+    fn drop(&mut self) {
+        if self.state.load(Ordering::Acquire) == READY {
+            // SAFETY: the value of a ready slot is initialized
+            unsafe { self.value.get_mut().assume_init_drop() };
+        }
+    }
+}
+
+impl<T> OnceSlot<T> {
+    fn get(&self) -> Option<&T> {
+        match self.state.load(Ordering::Acquire) == READY {
+            // SAFETY: the value of a ready slot is initialized and never written again
+            true => Some(unsafe { (*self.value.get()).assume_init_ref() }),
+            false => None,
+        }
+    }
+}
+
+/// Resets the slot to empty if the initializer panics, so that another thread can initialize it.
+struct ResetOnUnwind<'a>(&'a AtomicUsize);
+
+impl Drop for ResetOnUnwind<'_> {
+    fn drop(&mut self) {
+        self.0.store(EMPTY, Ordering::Release);
+    }
+}
+
+/// A safe concurrent collection built on top of a [`PinnedConcurrentCol`], each position of which is initialized at most once.
+///
+/// * [`ConcurrentOnceCol::get_or_init`] lets exactly one thread compute the value of a position;
+///   the other threads requesting the same position wait for the value following the spin strategy of the collection.
+/// * Values can be initialized at arbitrary positions in any order; the collection grows whenever necessary
+///   following the write permit protocol of the [`FilledState`].
+/// * Once a position is initialized, its value is never written again; hence, references to the values remain valid while other positions are initialized.
+///
+/// If the initializer panics, the position is reset to empty; and another call can initialize it.
+///
+/// The collection satisfies the safety requirements of the core as follows:
+/// * the memory is filled with empty slots on allocation, and hence, every allocated position holds a valid slot;
+/// * the value of a slot is written only by the thread which moves it from empty to initializing state;
+/// * readers access the value of a slot only once it is ready.
+///
+/// # Examples
+///
+/// ```
+/// use orx_pinned_concurrent_col::prelude::*;
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+///
+/// let memo = ConcurrentOnceCol::new();
+/// let num_computed = AtomicUsize::new(0);
+/// let square = |i: usize| {
+///     num_computed.fetch_add(1, Ordering::Relaxed);
+///     i * i
+/// };
+///
+/// std::thread::scope(|s| {
+///     for _ in 0..4 {
+///         s.spawn(|| {
+///             for i in [3, 100, 7, 3] {
+///                 assert_eq!(memo.get_or_init(i, || square(i)), &(i * i));
+///             }
+///         });
+///     }
+/// });
+///
+/// assert_eq!(num_computed.load(Ordering::Relaxed), 3);
+/// assert_eq!(memo.get(100), Some(&10000));
+/// assert_eq!(memo.get(4), None);
+/// ```
+pub struct ConcurrentOnceCol<T, P = ConcurrentSplitVec<OnceSlot<T>, Doubling>>
+where
+    P: ConcurrentPinnedVec<OnceSlot<T>>,
+{
+    col: PinnedConcurrentCol<OnceSlot<T>, P, FilledState<OnceSlot<T>>>,
+}
+
+unsafe impl<T: Send, P: ConcurrentPinnedVec<OnceSlot<T>>> Send for ConcurrentOnceCol<T, P> {}
+
+unsafe impl<T: Send + Sync, P: ConcurrentPinnedVec<OnceSlot<T>>> Sync for ConcurrentOnceCol<T, P> {}
+
+impl<T> ConcurrentOnceCol<T, ConcurrentSplitVec<OnceSlot<T>, Doubling>> {
+    /// Creates a new empty collection using a `SplitVec<OnceSlot<T>, Doubling>` as the underlying storage.
+    pub fn new() -> Self {
+        Self::with_doubling_growth()
+    }
+
+    /// Creates a new empty collection using a `SplitVec<OnceSlot<T>, Doubling>` as the underlying storage.
+    pub fn with_doubling_growth() -> Self {
+        Self {
+            col: PinnedConcurrentCol::with_doubling_growth(),
+        }
+    }
+}
+
+impl<T> Default for ConcurrentOnceCol<T, ConcurrentSplitVec<OnceSlot<T>, Doubling>> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> ConcurrentOnceCol<T, ConcurrentSplitVec<OnceSlot<T>, Linear>> {
+    /// Creates a new empty collection using a `SplitVec<OnceSlot<T>, Linear>` as the underlying storage.
+    ///
+    /// See [`PinnedConcurrentCol::with_linear_growth`] for details of the arguments.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `fragments_capacity == 0`.
+    pub fn with_linear_growth(
+        constant_fragment_capacity_exponent: usize,
+        fragments_capacity: usize,
+    ) -> Self {
+        Self {
+            col: PinnedConcurrentCol::with_linear_growth(
+                constant_fragment_capacity_exponent,
+                fragments_capacity,
+            ),
+        }
+    }
+}
+
+impl<T> ConcurrentOnceCol<T, ConcurrentFixedVec<OnceSlot<T>>> {
+    /// Creates a new empty collection using a `FixedVec<OnceSlot<T>>` as the underlying storage.
+    ///
+    /// Note that positions at or beyond the `fixed_capacity` cannot be initialized.
+    pub fn with_fixed_capacity(fixed_capacity: usize) -> Self {
+        Self {
+            col: PinnedConcurrentCol::with_fixed_capacity(fixed_capacity),
+        }
+    }
+}
+
+impl<T, P> ConcurrentOnceCol<T, P>
+where
+    P: ConcurrentPinnedVec<OnceSlot<T>>,
+{
+    /// Returns the current allocated capacity of the collection.
+    pub fn capacity(&self) -> usize {
+        self.col.capacity()
+    }
+
+    /// Returns maximum possible capacity that the collection can concurrently grow to.
+    pub fn maximum_capacity(&self) -> usize {
+        self.col.maximum_capacity()
+    }
+
+    /// Returns a reference to the value at the `idx`-th position if it is initialized; returns None otherwise.
+    pub fn get(&self, idx: usize) -> Option<&T> {
+        // SAFETY: all allocated positions are filled with slots
+        unsafe { self.col.get(idx) }.and_then(|slot| slot.get())
+    }
+
+    /// Returns a mutable reference to the value at the `idx`-th position if it is initialized; returns None otherwise.
+    pub fn get_mut(&mut self, idx: usize) -> Option<&mut T> {
+        // SAFETY: all allocated positions are filled with slots
+        let slot = unsafe { self.col.get_mut(idx) }?;
+        match slot.state.load(Ordering::Acquire) == READY {
+            // SAFETY: the value of a ready slot is initialized and the collection is exclusively borrowed
+            true => Some(unsafe { slot.value.get_mut().assume_init_mut() }),
+            false => None,
+        }
+    }
+
+    /// Returns whether or not the value at the `idx`-th position is initialized.
+    pub fn is_initialized(&self, idx: usize) -> bool {
+        self.get(idx).is_some()
+    }
+
+    /// Returns an iterator over the positions and values which are initialized at the time each position is visited.
+    pub fn iter(&self) -> impl Iterator<Item = (usize, &T)> {
+        (0..self.capacity()).filter_map(|idx| self.get(idx).map(|x| (idx, x)))
+    }
+
+    /// Returns a reference to the value at the `idx`-th position, initializing it with `init` if it is empty.
+    ///
+    /// Exactly one thread runs its `init` for a position; other threads requesting the same position wait until the value is ready.
+    ///
+    /// See [`ConcurrentOnceCol::try_get_or_init`] for the fallible version of the method.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `idx`-th position cannot be allocated; i.e., if [`ConcurrentOnceCol::try_get_or_init`] returns an error.
+    ///
+    /// Panics if `init` panics, in which case the position is left empty.
+    pub fn get_or_init<F>(&self, idx: usize, init: F) -> &T
+    where
+        F: FnOnce() -> T,
+    {
+        expect_ok(self.try_get_or_init(idx, init))
+    }
+
+    /// Returns a reference to the value at the `idx`-th position, initializing it with `init` if it is empty;
+    /// returns an error rather than panicking if the position cannot be allocated.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`PinnedConcurrentCol::try_write`] for the `idx`-th position.
+    pub fn try_get_or_init<F>(&self, idx: usize, init: F) -> Result<&T, PinnedConcurrentColError>
+    where
+        F: FnOnce() -> T,
+    {
+        self.col.try_allocate(idx)?;
+        // SAFETY: the position is allocated, and all allocated positions are filled with slots
+        let slot = unsafe { &*self.col.ptr_mut_at(idx) };
+
+        let mut backoff = self.col.spin_strategy().backoff();
+        loop {
+            match slot.state.compare_exchange(
+                EMPTY,
+                INITIALIZING,
+                Ordering::Acquire,
+                Ordering::Acquire,
+            ) {
+                Ok(_) => break,
+                // SAFETY: the value of a ready slot is initialized and never written again
+                Err(READY) => return Ok(unsafe { (*slot.value.get()).assume_init_ref() }),
+                Err(_) => backoff.spin(),
+            }
+        }
+
+        let reset = ResetOnUnwind(&slot.state);
+        // SAFETY: the slot is exclusively owned by this thread while initializing
+        let value = unsafe { (*slot.value.get()).write(init()) };
+        core::mem::forget(reset);
+        slot.state.store(READY, Ordering::Release);
+        Ok(value)
+    }
+}
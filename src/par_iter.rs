@@ -0,0 +1,81 @@
+use crate::slice_producer::{SliceProducer, SliceProducerIter};
+use rayon::iter::{
+    IndexedParallelIterator, IntoParallelIterator, ParallelIterator,
+    plumbing::{Consumer, Producer, ProducerCallback, UnindexedConsumer, bridge},
+};
+
+/// Indexed parallel iterator over the elements of a [`SliceProducer`], created by its `into_par_iter` method.
+///
+/// The elements are split among the rayon threads at arbitrary positions, possibly within a fragment,
+/// while each thread iterates over its part slice by slice.
+///
+/// # Examples
+///
+/// ```
+/// use orx_pinned_concurrent_col::prelude::*;
+/// use rayon::prelude::*;
+///
+/// let col: PinnedConcurrentCol<u64, _, OrderedState<_>> = PinnedConcurrentCol::with_doubling_growth();
+/// unsafe { col.write_n_items(0, 1000, 0..1000) };
+///
+/// let sum: u64 = col.committed_slice_producer().into_par_iter().sum();
+/// assert_eq!(sum, (0..1000).sum());
+///
+/// let squares: Vec<_> = col.committed_slice_producer().into_par_iter().map(|x| x * x).collect();
+/// assert_eq!(squares, (0..1000).map(|x| x * x).collect::<Vec<_>>());
+/// ```
+#[derive(Debug, Clone)]
+pub struct ParSliceIter<'a, T> {
+    producer: SliceProducer<'a, T>,
+}
+
+impl<'a, T: Sync> IntoParallelIterator for SliceProducer<'a, T> {
+    type Iter = ParSliceIter<'a, T>;
+    type Item = &'a T;
+
+    fn into_par_iter(self) -> Self::Iter {
+        ParSliceIter { producer: self }
+    }
+}
+
+impl<'a, T: Sync> ParallelIterator for ParSliceIter<'a, T> {
+    type Item = &'a T;
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where
+        C: UnindexedConsumer<Self::Item>,
+    {
+        bridge(self, consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.producer.len())
+    }
+}
+
+impl<T: Sync> IndexedParallelIterator for ParSliceIter<'_, T> {
+    fn len(&self) -> usize {
+        self.producer.len()
+    }
+
+    fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+
+    fn with_producer<CB: ProducerCallback<Self::Item>>(self, callback: CB) -> CB::Output {
+        callback.callback(self.producer)
+    }
+}
+
+impl<'a, T: Sync> Producer for SliceProducer<'a, T> {
+    type Item = &'a T;
+    type IntoIter = SliceProducerIter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIterator::into_iter(self)
+    }
+
+    fn split_at(self, index: usize) -> (Self, Self) {
+        SliceProducer::split_at(self, index)
+    }
+}
@@ -1,5 +1,23 @@
+pub use crate::atomic_col::{AtomicInteger, ConcurrentAtomicCol};
+pub use crate::builder::PinnedConcurrentColBuilder;
 pub use crate::col::PinnedConcurrentCol;
-pub use crate::state::ConcurrentState;
+pub use crate::errors::{PinnedConcurrentColError, WriteError};
+pub use crate::growth_policy::GrowthPolicy;
+pub use crate::local_writer::LocalWriter;
+pub use crate::log::ConcurrentLog;
+pub use crate::memory_fill::MemoryFill;
+#[cfg(feature = "metrics")]
+pub use crate::metrics::ColStats;
+pub use crate::once::{ConcurrentOnceCol, OnceSlot};
+#[cfg(feature = "rayon")]
+pub use crate::par_iter::ParSliceIter;
+pub use crate::reservation::{Reservation, ReservationOutcome};
+pub use crate::ring::ConcurrentRing;
+pub use crate::slab::{ConcurrentSlab, SlabKey, SlabRef, SlabSlot};
+pub use crate::slice_producer::{SliceProducer, SliceProducerIter};
+pub use crate::spin::{Backoff, SpinStrategy};
+pub use crate::state::{ConcurrentState, PublishedState};
+pub use crate::states::{AppendOnlyState, FilledState, LogState, OrderedState};
 pub use crate::write_permit::WritePermit;
 
 pub use orx_fixed_vec::FixedVec;
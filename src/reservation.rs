@@ -0,0 +1,198 @@
+use crate::{PinnedConcurrentCol, state::ConcurrentState};
+use core::{mem::MaybeUninit, ops::Range};
+use orx_pinned_vec::ConcurrentPinnedVec;
+
+/// Outcome of a [`Reservation`] once it is finished or dropped.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReservationOutcome {
+    /// All reserved positions are initialized and committed to the concurrent state.
+    Complete,
+    /// Only the first `num_initialized` reserved positions are initialized and committed to the concurrent state.
+    ///
+    /// The remaining positions are not written; it is the responsibility of the wrapper to handle the gap.
+    Partial {
+        /// Number of initialized positions at the beginning of the reserved range.
+        num_initialized: usize,
+    },
+}
+
+/// A guard owning a reserved range of positions of a [`PinnedConcurrentCol`], which allows zero-copy bulk writes.
+///
+/// * A reservation is created by [`PinnedConcurrentCol::reserve_n_items`] or [`PinnedConcurrentCol::try_reserve_n_items`].
+///   The collection grows whenever necessary during the creation, so that all reserved positions are allocated.
+/// * Positions are initialized sequentially, either one by one with [`Reservation::push`] or in bulk by writing to the
+///   [`Reservation::uninit_slices_mut`] and then calling [`Reservation::assume_init`].
+/// * The reservation keeps track of the number of initialized positions.
+///   Only the initialized prefix of the range is committed to the concurrent state on [`Reservation::finish`] or on drop.
+///
+/// Since the commit happens on drop as well, a panic in the producer never leaves uninitialized positions committed as written.
+///
+/// # Examples
+///
+/// ```
+/// use orx_pinned_concurrent_col::prelude::*;
+///
+/// let col: PinnedConcurrentCol<u64, _, AppendOnlyState<_>> = PinnedConcurrentCol::with_doubling_growth();
+///
+/// let begin_idx = col.state().fetch_increment_len(4);
+/// let mut reservation = unsafe { col.reserve_n_items(begin_idx, 4) };
+///
+/// let mut value = 0;
+/// for slice in reservation.uninit_slices_mut() {
+///     for x in slice.iter_mut() {
+///         x.write(value);
+///         value += 1;
+///     }
+/// }
+/// unsafe { reservation.assume_init(4) };
+///
+/// assert_eq!(reservation.finish(), ReservationOutcome::Complete);
+///
+/// let vec = unsafe { col.into_inner(4) };
+/// assert_eq!(vec, &[0, 1, 2, 3]);
+/// ```
+pub struct Reservation<'a, T, P, S>
+where
+    P: ConcurrentPinnedVec<T>,
+    S: ConcurrentState<T>,
+{
+    col: &'a PinnedConcurrentCol<T, P, S>,
+    begin_idx: usize,
+    num_items: usize,
+    num_initialized: usize,
+    is_committed: bool,
+}
+
+impl<T, P, S> Drop for Reservation<'_, T, P, S>
+where
+    P: ConcurrentPinnedVec<T>,
+    S: ConcurrentState<T>,
+{
+    fn drop(&mut self) {
+        self.commit();
+    }
+}
+
+impl<'a, T, P, S> Reservation<'a, T, P, S>
+where
+    P: ConcurrentPinnedVec<T>,
+    S: ConcurrentState<T>,
+{
+    pub(crate) fn new(
+        col: &'a PinnedConcurrentCol<T, P, S>,
+        begin_idx: usize,
+        num_items: usize,
+    ) -> Self {
+        Self {
+            col,
+            begin_idx,
+            num_items,
+            num_initialized: 0,
+            is_committed: false,
+        }
+    }
+
+    /// Returns the range of the positions owned by the reservation.
+    pub fn range(&self) -> Range<usize> {
+        self.begin_idx..(self.begin_idx + self.num_items)
+    }
+
+    /// Returns the number of reserved positions.
+    pub fn len(&self) -> usize {
+        self.num_items
+    }
+
+    /// Returns whether or not the reservation is empty.
+    pub fn is_empty(&self) -> bool {
+        self.num_items == 0
+    }
+
+    /// Returns the number of initialized positions at the beginning of the reserved range.
+    pub fn num_initialized(&self) -> usize {
+        self.num_initialized
+    }
+
+    /// Returns the number of reserved positions which are not yet initialized.
+    pub fn num_remaining(&self) -> usize {
+        self.num_items - self.num_initialized
+    }
+
+    /// Returns whether or not all reserved positions are initialized.
+    pub fn is_complete(&self) -> bool {
+        self.num_initialized == self.num_items
+    }
+
+    /// Writes the `value` to the next uninitialized position of the reservation.
+    ///
+    /// # Errors
+    ///
+    /// Returns back the `value` if all reserved positions are already initialized.
+    pub fn push(&mut self, value: T) -> Result<(), T> {
+        match self.is_complete() {
+            true => Err(value),
+            false => {
+                self.col
+                    .write_at(self.begin_idx + self.num_initialized, value);
+                self.num_initialized += 1;
+                Ok(())
+            }
+        }
+    }
+
+    /// Returns an iterator of mutable slices over the reserved positions which are not yet initialized.
+    ///
+    /// Writing to the slices does not change the number of initialized positions;
+    /// the caller must call [`Reservation::assume_init`] afterwards.
+    pub fn uninit_slices_mut(&mut self) -> impl Iterator<Item = &mut [MaybeUninit<T>]> {
+        let begin_idx = self.begin_idx + self.num_initialized;
+        self.col
+            .slices_mut_for_n_items_at(begin_idx, self.num_remaining())
+            .into_iter()
+            .map(|slice| {
+                let (ptr, len) = (slice.as_mut_ptr() as *mut MaybeUninit<T>, slice.len());
+                // SAFETY: MaybeUninit<T> has the same layout as T, and the positions are owned by the reservation
+                unsafe { core::slice::from_raw_parts_mut(ptr, len) }
+            })
+    }
+
+    /// Marks the next `num_items` uninitialized positions of the reservation as initialized.
+    ///
+    /// # Safety
+    ///
+    /// The caller must have written the next `num_items` positions, i.e., the first `num_items` elements of the
+    /// [`Reservation::uninit_slices_mut`], before calling this method.
+    ///
+    /// Further, `num_items` must not exceed [`Reservation::num_remaining`].
+    pub unsafe fn assume_init(&mut self, num_items: usize) {
+        debug_assert!(num_items <= self.num_remaining());
+        self.num_initialized += num_items;
+    }
+
+    /// Commits the initialized positions to the concurrent state and returns the outcome of the reservation.
+    ///
+    /// Dropping the reservation without calling this method commits the initialized positions in the same way.
+    pub fn finish(mut self) -> ReservationOutcome {
+        self.commit();
+        match self.is_complete() {
+            true => ReservationOutcome::Complete,
+            false => ReservationOutcome::Partial {
+                num_initialized: self.num_initialized,
+            },
+        }
+    }
+
+    fn commit(&mut self) {
+        if !self.is_committed {
+            self.is_committed = true;
+            if self.num_initialized > 0 {
+                let end_idx = self.begin_idx + self.num_initialized;
+                self.col.commit_after_write(self.begin_idx, end_idx);
+            }
+
+            #[cfg(feature = "debug_checks")]
+            self.col.debug_release(
+                (self.begin_idx + self.num_initialized)..(self.begin_idx + self.num_items),
+            );
+        }
+    }
+}
@@ -0,0 +1,329 @@
+use crate::{
+    PinnedConcurrentCol,
+    spin::SpinStrategy,
+    states::FilledState,
+    sync::{
+        AtomicUsize,
+        atomic::{self, Ordering},
+        spin_loop,
+    },
+};
+use core::{cell::UnsafeCell, mem::MaybeUninit};
+use orx_fixed_vec::ConcurrentFixedVec;
+
+/// A slot of the ring; its stamp tells the position that the slot is ready for.
+///
+/// Positions are encoded as stamps with a lap and an index, `lap * one_lap + index`, where the index is the physical position of the slot
+/// and `one_lap` is the smallest power of two exceeding the capacity; so that the stamps wrap around without breaking the indices.
+///
+/// * `stamp == pos` means that the slot is free for the producer of the position `pos`;
+/// * `stamp == pos + 1` means that the slot holds the value of the position `pos` for its consumer.
+///
+/// Since the index of a position is less than the capacity, `pos + 1` never reaches the next lap and the two states are distinct.
+struct RingSlot<T> {
+    stamp: AtomicUsize,
+    value: UnsafeCell<MaybeUninit<T>>,
+}
+
+impl<T> Default for RingSlot<T> {
+    fn default() -> Self {
+        Self {
+            stamp: AtomicUsize::new(0),
+            value: UnsafeCell::new(MaybeUninit::uninit()),
+        }
+    }
+}
+
+/// A bounded multi-producer multi-consumer ring buffer built on top of a [`PinnedConcurrentCol`] with a fixed capacity.
+///
+/// * Values are pushed to and popped from increasing positions, which visit the slots in a round robin order, one lap after the other.
+/// * Once a value is popped, its slot is released and reused by the producer of the position one lap ahead.
+/// * When the ring is full, [`ConcurrentRing::try_push`] returns the value back,
+///   while [`ConcurrentRing::push`] waits for the consumers following the spin strategy of the collection,
+///   as a [`WritePermit::Spin`](crate::WritePermit::Spin) would; see [`SpinStrategy::LONG_WAIT`].
+///
+/// Note that the pushes are not evaluated by the write permits of a [`ConcurrentState`](crate::ConcurrentState).
+/// The permit protocol hands out each position once, while the ring reuses its positions once they are released by the consumers;
+/// hence, each slot carries its own stamp, and the collection serves as the memory which is allocated and filled up front.
+///
+/// The ring never allocates after its creation; and it only depends on `core` and `alloc`.
+///
+/// The ring satisfies the safety requirements of the core as follows:
+/// * the memory is allocated and filled with free slots on creation, and the underlying vector never grows;
+/// * the value of a slot is written only by the producer which claimed its position, after the slot is released by the prior consumer;
+/// * the value of a slot is taken out only by the consumer which claimed its position, after the producer completed the write.
+///
+/// # Examples
+///
+/// ```
+/// use orx_pinned_concurrent_col::prelude::*;
+///
+/// let ring = ConcurrentRing::new(4);
+///
+/// for i in 0..4 {
+///     assert_eq!(ring.try_push(i), Ok(()));
+/// }
+/// assert!(ring.is_full());
+/// assert_eq!(ring.try_push(4), Err(4));
+///
+/// assert_eq!(ring.try_pop(), Some(0));
+/// assert_eq!(ring.try_push(4), Ok(()));
+///
+/// let sum = std::thread::scope(|s| {
+///     let ring = &ring;
+///     s.spawn(move || (5..100).for_each(|i| ring.push(i)));
+///     s.spawn(move || (1..100).map(|_| ring.pop()).sum::<i32>()).join().unwrap()
+/// });
+/// assert_eq!(sum, (1..100).sum());
+/// assert!(ring.is_empty());
+/// ```
+pub struct ConcurrentRing<T> {
+    col:
+        PinnedConcurrentCol<RingSlot<T>, ConcurrentFixedVec<RingSlot<T>>, FilledState<RingSlot<T>>>,
+    capacity: usize,
+    one_lap: usize,
+    push_pos: AtomicUsize,
+    pop_pos: AtomicUsize,
+}
+
+unsafe impl<T: Send> Send for ConcurrentRing<T> {}
+
+unsafe impl<T: Send> Sync for ConcurrentRing<T> {}
+
+impl<T> Drop for ConcurrentRing<T> {
+    fn drop(&mut self) {
+        while self.try_pop().is_some() {}
+    }
+}
+
+impl<T> ConcurrentRing<T> {
+    /// Creates a new empty ring with the given fixed `capacity`, which is allocated up front.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is zero.
+    pub fn new(capacity: usize) -> Self {
+        Self::with_first_lap(capacity, 0)
+    }
+
+    /// Creates a new empty ring with the given fixed `capacity` whose positions begin at the given `first_lap` stamp,
+    /// which must be a multiple of the lap of the ring.
+    fn with_first_lap(capacity: usize, first_lap: usize) -> Self {
+        assert!(capacity > 0, "capacity of a ring must be positive");
+        let one_lap = (capacity + 1).next_power_of_two();
+        let mut col: PinnedConcurrentCol<RingSlot<T>, _, FilledState<_>> =
+            PinnedConcurrentCol::with_fixed_capacity(capacity);
+        // producers and consumers might wait for each other for long
+        col.set_spin_strategy(SpinStrategy::LONG_WAIT);
+
+        // SAFETY: all positions are filled with slots on allocation
+        for (idx, slot) in unsafe { col.iter(capacity) }.enumerate() {
+            slot.stamp.store(first_lap + idx, Ordering::Relaxed);
+        }
+        Self {
+            col,
+            capacity,
+            one_lap,
+            push_pos: AtomicUsize::new(first_lap),
+            pop_pos: AtomicUsize::new(first_lap),
+        }
+    }
+
+    /// Returns the fixed capacity of the ring.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of values in the ring.
+    ///
+    /// Note that the value is only a snapshot while other threads concurrently push or pop.
+    pub fn len(&self) -> usize {
+        loop {
+            let push_pos = self.push_pos.load(Ordering::SeqCst);
+            let pop_pos = self.pop_pos.load(Ordering::SeqCst);
+
+            // the positions are consistent only if the push position did not change while the pop position is loaded
+            if self.push_pos.load(Ordering::SeqCst) == push_pos {
+                let push_idx = self.index(push_pos);
+                let pop_idx = self.index(pop_pos);
+                return match pop_idx.cmp(&push_idx) {
+                    core::cmp::Ordering::Less => push_idx - pop_idx,
+                    core::cmp::Ordering::Greater => self.capacity - pop_idx + push_idx,
+                    core::cmp::Ordering::Equal if push_pos == pop_pos => 0,
+                    core::cmp::Ordering::Equal => self.capacity,
+                };
+            }
+        }
+    }
+
+    /// Returns whether or not the ring is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns whether or not the ring is full.
+    pub fn is_full(&self) -> bool {
+        self.len() == self.capacity
+    }
+
+    /// Pushes the `value` to the ring, waiting following the spin strategy of the collection while the ring is full.
+    ///
+    /// Note that the method never returns if the ring remains full.
+    pub fn push(&self, value: T) {
+        let mut value = value;
+        let mut backoff = self.col.spin_strategy().backoff();
+        while let Err(rejected) = self.try_push(value) {
+            value = rejected;
+            backoff.spin();
+        }
+    }
+
+    /// Tries to push the `value` to the ring; returns the value back as the error if the ring is full.
+    pub fn try_push(&self, value: T) -> Result<(), T> {
+        let mut pos = self.push_pos.load(Ordering::Relaxed);
+        loop {
+            let slot = self.slot(pos);
+            let stamp = slot.stamp.load(Ordering::Acquire);
+            if stamp == pos {
+                match self.push_pos.compare_exchange_weak(
+                    pos,
+                    self.next(pos),
+                    Ordering::SeqCst,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => {
+                        // SAFETY: the slot is released by its prior consumer and the position is claimed only by this producer
+                        unsafe { (*slot.value.get()).write(value) };
+                        slot.stamp.store(pos + 1, Ordering::Release);
+                        return Ok(());
+                    }
+                    Err(current) => pos = current,
+                }
+            } else if stamp.wrapping_add(self.one_lap) == pos + 1 {
+                // the slot holds the value of the position one lap behind; the ring is full unless it is being popped
+                atomic::fence(Ordering::SeqCst);
+                let pop_pos = self.pop_pos.load(Ordering::Relaxed);
+                if pop_pos.wrapping_add(self.one_lap) == pos {
+                    return Err(value);
+                }
+                spin_loop();
+                pos = self.push_pos.load(Ordering::Relaxed);
+            } else {
+                // another thread claimed the position and is yet to complete its write or read
+                spin_loop();
+                pos = self.push_pos.load(Ordering::Relaxed);
+            }
+        }
+    }
+
+    /// Pops the next value from the ring, waiting following the spin strategy of the collection while the ring is empty.
+    ///
+    /// Note that the method never returns if nothing is pushed to the ring.
+    pub fn pop(&self) -> T {
+        let mut backoff = self.col.spin_strategy().backoff();
+        loop {
+            match self.try_pop() {
+                Some(value) => return value,
+                None => backoff.spin(),
+            }
+        }
+    }
+
+    /// Tries to pop the next value from the ring; returns None if the ring is empty.
+    pub fn try_pop(&self) -> Option<T> {
+        let mut pos = self.pop_pos.load(Ordering::Relaxed);
+        loop {
+            let slot = self.slot(pos);
+            let stamp = slot.stamp.load(Ordering::Acquire);
+            if stamp == pos + 1 {
+                match self.pop_pos.compare_exchange_weak(
+                    pos,
+                    self.next(pos),
+                    Ordering::SeqCst,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => {
+                        // SAFETY: the slot is written by its producer and the position is claimed only by this consumer
+                        let value = unsafe { (*slot.value.get()).assume_init_read() };
+                        slot.stamp
+                            .store(pos.wrapping_add(self.one_lap), Ordering::Release);
+                        return Some(value);
+                    }
+                    Err(current) => pos = current,
+                }
+            } else if stamp == pos {
+                // the slot is free for the producer of this position; the ring is empty unless it is being pushed
+                atomic::fence(Ordering::SeqCst);
+                let push_pos = self.push_pos.load(Ordering::Relaxed);
+                if push_pos == pos {
+                    return None;
+                }
+                spin_loop();
+                pos = self.pop_pos.load(Ordering::Relaxed);
+            } else {
+                // another thread claimed the position and is yet to complete its write or read
+                spin_loop();
+                pos = self.pop_pos.load(Ordering::Relaxed);
+            }
+        }
+    }
+
+    /// Returns the position following `pos`; which is the first position of the next lap after the last slot.
+    #[inline(always)]
+    fn next(&self, pos: usize) -> usize {
+        let idx = self.index(pos);
+        match idx + 1 < self.capacity {
+            true => pos + 1,
+            false => (pos - idx).wrapping_add(self.one_lap),
+        }
+    }
+
+    /// Returns the physical position of the slot of the position `pos`.
+    #[inline(always)]
+    fn index(&self, pos: usize) -> usize {
+        pos & (self.one_lap - 1)
+    }
+
+    /// Returns the slot that the position `pos` is mapped to.
+    fn slot(&self, pos: usize) -> &RingSlot<T> {
+        // SAFETY: the physical position is within the fixed capacity, all positions of which are filled with slots
+        unsafe { &*self.col.ptr_mut_at(self.index(pos)) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::{string::ToString, vec::Vec};
+
+    #[test]
+    fn push_pop_across_wrap_of_positions() {
+        for capacity in [1usize, 2, 3, 5, 8] {
+            let one_lap = (capacity + 1).next_power_of_two();
+            // the positions wrap around after two laps
+            let ring = ConcurrentRing::with_first_lap(capacity, 0usize.wrapping_sub(2 * one_lap));
+
+            let mut next_push = 0;
+            let mut next_pop = 0;
+            for round in 0..(4 * capacity + 4) {
+                while ring.try_push(next_push.to_string()).is_ok() {
+                    next_push += 1;
+                }
+                assert!(ring.is_full());
+                assert_eq!(ring.len(), capacity);
+
+                for _ in 0..(round % capacity + 1) {
+                    assert_eq!(ring.try_pop(), Some(next_pop.to_string()));
+                    next_pop += 1;
+                }
+                assert_eq!(ring.len(), next_push - next_pop);
+            }
+
+            let remaining: Vec<_> = core::iter::from_fn(|| ring.try_pop()).collect();
+            let expected: Vec<_> = (next_pop..next_push).map(|x| x.to_string()).collect();
+            assert_eq!(remaining, expected);
+            assert!(ring.is_empty());
+            assert_eq!(ring.try_pop(), None);
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use crate::{PinnedConcurrentCol, state::ConcurrentState};
+use orx_pinned_vec::ConcurrentPinnedVec;
+
+/// A guard keeping track of the elements written to `num_items` sequential positions starting from `begin_idx`,
+/// which rolls back the writes unless it is disarmed.
+///
+/// Rolling back drops the written elements; and if the memory is filled, refills the positions.
+/// Therefore, a panicking or short iterator never leaves a half-written range behind.
+pub(crate) struct WriteRollback<'a, T, P, S>
+where
+    P: ConcurrentPinnedVec<T>,
+    S: ConcurrentState<T>,
+{
+    col: &'a PinnedConcurrentCol<T, P, S>,
+    begin_idx: usize,
+    num_items: usize,
+    num_written: usize,
+}
+
+impl<T, P, S> Drop for WriteRollback<'_, T, P, S>
+where
+    P: ConcurrentPinnedVec<T>,
+    S: ConcurrentState<T>,
+{
+    fn drop(&mut self) {
+        let fill_with = self.col.fill_memory_with();
+        for idx in self.begin_idx..(self.begin_idx + self.num_written) {
+            let ptr = self.col.ptr_mut_at(idx);
+            // SAFETY: the position is written by the guarded write and not yet committed to the state
+            unsafe { ptr.drop_in_place() };
+            if let Some(f) = fill_with {
+                unsafe { ptr.write(f.fill()) };
+            }
+        }
+
+        #[cfg(feature = "debug_checks")]
+        self.col
+            .debug_release(self.begin_idx..(self.begin_idx + self.num_items));
+    }
+}
+
+impl<'a, T, P, S> WriteRollback<'a, T, P, S>
+where
+    P: ConcurrentPinnedVec<T>,
+    S: ConcurrentState<T>,
+{
+    pub(crate) fn new(
+        col: &'a PinnedConcurrentCol<T, P, S>,
+        begin_idx: usize,
+        num_items: usize,
+    ) -> Self {
+        Self {
+            col,
+            begin_idx,
+            num_items,
+            num_written: 0,
+        }
+    }
+
+    /// Records that one more position is written.
+    #[inline(always)]
+    pub(crate) fn increment(&mut self) {
+        debug_assert!(self.num_written < self.num_items);
+        self.num_written += 1;
+    }
+
+    /// Returns the number of written positions.
+    #[inline(always)]
+    pub(crate) fn num_written(&self) -> usize {
+        self.num_written
+    }
+
+    /// Keeps the written elements by disarming the guard.
+    pub(crate) fn disarm(self) {
+        core::mem::forget(self);
+    }
+}
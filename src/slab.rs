@@ -0,0 +1,468 @@
+#[cfg(target_has_atomic = "64")]
+use crate::sync::AtomicU64;
+use crate::{
+    PinnedConcurrentCol,
+    errors::{PinnedConcurrentColError, WriteError, expect_ok},
+    states::FilledState,
+    sync::{AtomicUsize, atomic::Ordering},
+};
+use core::{cell::UnsafeCell, fmt::Debug, mem::MaybeUninit, ops::Deref};
+use orx_fixed_vec::ConcurrentFixedVec;
+use orx_pinned_vec::ConcurrentPinnedVec;
+use orx_split_vec::{ConcurrentSplitVec, Doubling, Linear};
+
+const VACANT: usize = 0;
+const OCCUPIED: usize = 1;
+const REMOVING: usize = 2;
+const TAG_BITS: u32 = 2;
+const TAG_MASK: usize = (1 << TAG_BITS) - 1;
+const GENERATION_MASK: usize = usize::MAX >> TAG_BITS;
+
+/// Free list head packs the index of the top slot into the lower half and an ABA counter into the upper half.
+///
+/// The head is a 64-bit word whenever the target supports 64-bit atomics, leaving 32 bits to the index and to the counter
+/// also on 32-bit targets; it falls back to a `usize` otherwise.
+#[cfg(target_has_atomic = "64")]
+type FreeHead = AtomicU64;
+#[cfg(target_has_atomic = "64")]
+type HeadWord = u64;
+#[cfg(not(target_has_atomic = "64"))]
+type FreeHead = AtomicUsize;
+#[cfg(not(target_has_atomic = "64"))]
+type HeadWord = usize;
+
+const INDEX_BITS: u32 = HeadWord::BITS / 2;
+const INDEX_MASK: HeadWord = (1 << INDEX_BITS) - 1;
+/// Index marking the end of the free list; which is also the maximum number of slots of a slab.
+const NIL: usize = INDEX_MASK as usize;
+
+#[inline(always)]
+fn slot_state(generation: usize, tag: usize) -> usize {
+    (generation << TAG_BITS) | tag
+}
+
+#[inline(always)]
+fn free_head(idx: usize, counter: HeadWord) -> HeadWord {
+    (counter << INDEX_BITS) | idx as HeadWord
+}
+
+#[inline(always)]
+fn head_index(head: HeadWord) -> usize {
+    (head & INDEX_MASK) as usize
+}
+
+/// Key of an entry of a [`ConcurrentSlab`], which is composed of the index of its slot and the generation of the slot at the time of insertion.
+///
+/// Once the entry is removed, the slot is reused by subsequent insertions with an incremented generation;
+/// and hence, the key never refers to another entry occupying the same slot.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SlabKey {
+    idx: usize,
+    generation: usize,
+}
+
+impl SlabKey {
+    /// Returns the index of the slot of the entry, which is the position of the slot in the underlying pinned vector.
+    pub fn index(&self) -> usize {
+        self.idx
+    }
+
+    /// Returns the generation of the slot at the time the entry is inserted.
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+}
+
+/// A slot of a [`ConcurrentSlab`], which is the element type of its underlying pinned vector.
+///
+/// A slot is either vacant or occupied by an entry; and it keeps track of its generation and the number of its active readers.
+pub struct SlabSlot<T> {
+    state: AtomicUsize,
+    num_readers: AtomicUsize,
+    next_free: AtomicUsize,
+    value: UnsafeCell<MaybeUninit<T>>,
+}
+
+impl<T> Default for SlabSlot<T> {
+    fn default() -> Self {
+        Self {
+            state: AtomicUsize::new(slot_state(0, VACANT)),
+            num_readers: AtomicUsize::new(0),
+            next_free: AtomicUsize::new(NIL),
+            value: UnsafeCell::new(MaybeUninit::uninit()),
+        }
+    }
+}
+
+impl<T> Drop for SlabSlot<T> {
+    fn drop(&mut self) {
+        if self.state.load(Ordering::Acquire) & TAG_MASK == OCCUPIED {
+            // SAFETY: the value of an occupied slot is initialized
+            unsafe { self.value.get_mut().assume_init_drop() };
+        }
+    }
+}
+
+/// A reference to an entry of a [`ConcurrentSlab`].
+///
+/// The entry cannot be removed while the reference is alive: [`ConcurrentSlab::remove`] waits until all references to the entry are dropped.
+pub struct SlabRef<'a, T> {
+    slot: &'a SlabSlot<T>,
+}
+
+impl<T> Drop for SlabRef<'_, T> {
+    fn drop(&mut self) {
+        self.slot.num_readers.fetch_sub(1, Ordering::Release);
+    }
+}
+
+impl<T> Deref for SlabRef<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        // SAFETY: a reference is created only for an occupied slot, which cannot be vacated while it has readers
+        unsafe { (*self.slot.value.get()).assume_init_ref() }
+    }
+}
+
+impl<T: Debug> Debug for SlabRef<'_, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        (**self).fmt(f)
+    }
+}
+
+/// A safe concurrent slab built on top of a [`PinnedConcurrentCol`], which reuses the slots of removed entries.
+///
+/// * Entries are inserted concurrently by [`ConcurrentSlab::insert`] which returns a stable [`SlabKey`].
+/// * Entries are removed concurrently by [`ConcurrentSlab::remove`] which takes the value out and pushes the slot to a lock-free free list.
+///   Subsequent insertions reuse the vacated slots before growing the collection.
+/// * [`ConcurrentSlab::get`] validates the generation of the key; hence, a key of a removed entry never refers to a later entry reusing its slot.
+///
+/// Since the underlying storage is pinned, addresses of the entries never change while the slab grows.
+///
+/// The slab satisfies the safety requirements of the core as follows:
+/// * the memory is filled with vacant slots on allocation, and hence, every allocated position holds a valid slot;
+/// * each slot is written only by the thread which exclusively owns it: the inserter which popped or freshly reserved it,
+///   or the remover which claimed the occupied slot;
+/// * readers access the value of a slot only while it is occupied, and the remover waits until all readers are gone.
+///
+/// Note that the free list packs the index of a slot and an ABA counter into a single atomic word.
+/// The word has 64 bits on targets supporting 64-bit atomics, which limits the slab to `2^32 - 1` slots on all such targets.
+/// On targets without 64-bit atomics the word is a `usize`, which limits the slab to `2^(usize::BITS / 2) - 1` slots;
+/// i.e., to 65,535 slots with a 16-bit counter on 32-bit targets.
+/// Inserting beyond the limit fails with [`PinnedConcurrentColError::OutOfMaximumCapacity`], and [`ConcurrentSlab::maximum_capacity`] never exceeds it.
+///
+/// # Examples
+///
+/// ```
+/// use orx_pinned_concurrent_col::prelude::*;
+///
+/// let slab = ConcurrentSlab::new();
+///
+/// let keys: Vec<_> = std::thread::scope(|s| {
+///     let handles: Vec<_> = (0..4)
+///         .map(|t| {
+///             let slab = &slab;
+///             s.spawn(move || (0..100).map(|i| slab.insert(t * 1000 + i)).collect::<Vec<_>>())
+///         })
+///         .collect();
+///     handles.into_iter().flat_map(|h| h.join().unwrap()).collect()
+/// });
+/// assert_eq!(slab.len(), 400);
+///
+/// let key = keys[42];
+/// let value = *slab.get(key).unwrap();
+/// assert_eq!(slab.remove(key), Some(value));
+/// assert!(slab.get(key).is_none());
+///
+/// // the vacated slot is reused with a new generation
+/// let new_key = slab.insert(7);
+/// assert_eq!(new_key.index(), key.index());
+/// assert_ne!(new_key.generation(), key.generation());
+/// assert_eq!(slab.remove(key), None);
+/// assert_eq!(slab.get(new_key).as_deref(), Some(&7));
+/// ```
+pub struct ConcurrentSlab<T, P = ConcurrentSplitVec<SlabSlot<T>, Doubling>>
+where
+    P: ConcurrentPinnedVec<SlabSlot<T>>,
+{
+    col: PinnedConcurrentCol<SlabSlot<T>, P, FilledState<SlabSlot<T>>>,
+    num_slots: AtomicUsize,
+    len: AtomicUsize,
+    free_head: FreeHead,
+}
+
+unsafe impl<T: Send, P: ConcurrentPinnedVec<SlabSlot<T>>> Send for ConcurrentSlab<T, P> {}
+
+unsafe impl<T: Send + Sync, P: ConcurrentPinnedVec<SlabSlot<T>>> Sync for ConcurrentSlab<T, P> {}
+
+impl<T> ConcurrentSlab<T, ConcurrentSplitVec<SlabSlot<T>, Doubling>> {
+    /// Creates a new empty slab using a `SplitVec<SlabSlot<T>, Doubling>` as the underlying storage.
+    pub fn new() -> Self {
+        Self::with_doubling_growth()
+    }
+
+    /// Creates a new empty slab using a `SplitVec<SlabSlot<T>, Doubling>` as the underlying storage.
+    pub fn with_doubling_growth() -> Self {
+        Self::from_col(PinnedConcurrentCol::with_doubling_growth())
+    }
+}
+
+impl<T> Default for ConcurrentSlab<T, ConcurrentSplitVec<SlabSlot<T>, Doubling>> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> ConcurrentSlab<T, ConcurrentSplitVec<SlabSlot<T>, Linear>> {
+    /// Creates a new empty slab using a `SplitVec<SlabSlot<T>, Linear>` as the underlying storage.
+    ///
+    /// See [`PinnedConcurrentCol::with_linear_growth`] for details of the arguments.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `fragments_capacity == 0`.
+    pub fn with_linear_growth(
+        constant_fragment_capacity_exponent: usize,
+        fragments_capacity: usize,
+    ) -> Self {
+        Self::from_col(PinnedConcurrentCol::with_linear_growth(
+            constant_fragment_capacity_exponent,
+            fragments_capacity,
+        ))
+    }
+}
+
+impl<T> ConcurrentSlab<T, ConcurrentFixedVec<SlabSlot<T>>> {
+    /// Creates a new empty slab using a `FixedVec<SlabSlot<T>>` as the underlying storage.
+    ///
+    /// Note that the slab cannot hold more than `fixed_capacity` slots.
+    pub fn with_fixed_capacity(fixed_capacity: usize) -> Self {
+        Self::from_col(PinnedConcurrentCol::with_fixed_capacity(fixed_capacity))
+    }
+}
+
+impl<T, P> ConcurrentSlab<T, P>
+where
+    P: ConcurrentPinnedVec<SlabSlot<T>>,
+{
+    fn from_col(col: PinnedConcurrentCol<SlabSlot<T>, P, FilledState<SlabSlot<T>>>) -> Self {
+        Self {
+            col,
+            num_slots: AtomicUsize::new(0),
+            len: AtomicUsize::new(0),
+            free_head: FreeHead::new(free_head(NIL, 0)),
+        }
+    }
+
+    /// Returns the number of entries in the slab.
+    ///
+    /// Note that the value is only a snapshot while other threads concurrently insert or remove entries.
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.len.load(Ordering::Acquire)
+    }
+
+    /// Returns whether or not the slab has any entry.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the current allocated capacity of the slab; i.e., the number of slots.
+    pub fn capacity(&self) -> usize {
+        self.col.capacity()
+    }
+
+    /// Returns maximum possible capacity that the slab can concurrently grow to.
+    pub fn maximum_capacity(&self) -> usize {
+        self.col.maximum_capacity().min(NIL)
+    }
+
+    /// Inserts the `value` to a vacant slot and returns its key.
+    ///
+    /// Slots vacated by removals are reused first; a new slot is reserved only when there is no vacant slot.
+    ///
+    /// See [`ConcurrentSlab::try_insert`] for the fallible version of the method.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slab is out of its maximum capacity.
+    pub fn insert(&self, value: T) -> SlabKey {
+        expect_ok(self.try_insert(value))
+    }
+
+    /// Inserts the `value` to a vacant slot and returns its key; returns an error rather than panicking if the slab cannot grow.
+    ///
+    /// When the request fails, the `value` is given back to the caller within the [`WriteError`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`WriteError`] with the same causes as the errors of [`PinnedConcurrentCol::try_reserve_n_items`] when a new slot is required.
+    pub fn try_insert(&self, value: T) -> Result<SlabKey, WriteError<T>> {
+        let idx = match self.pop_free() {
+            Some(idx) => idx,
+            None => match self.reserve_new_slot() {
+                Ok(idx) => idx,
+                Err(error) => return Err(WriteError::new(error, value)),
+            },
+        };
+
+        let slot = self.allocated_slot(idx);
+        let generation = slot.state.load(Ordering::Acquire) >> TAG_BITS;
+        // SAFETY: the vacant slot is exclusively owned by this inserter, and its value is not read until it is occupied
+        unsafe { (*slot.value.get()).write(value) };
+        self.len.fetch_add(1, Ordering::AcqRel);
+        slot.state
+            .store(slot_state(generation, OCCUPIED), Ordering::Release);
+
+        Ok(SlabKey { idx, generation })
+    }
+
+    /// Removes the entry with the given `key` and returns its value; returns None if the key does not refer to an entry of the slab.
+    ///
+    /// The slot of the entry is then reused by subsequent insertions.
+    ///
+    /// The method waits until all [`SlabRef`]s to the entry are dropped.
+    /// Therefore, a thread holding a reference to the entry must not remove it, which would never return.
+    pub fn remove(&self, key: SlabKey) -> Option<T> {
+        let slot = self.slot(key.idx)?;
+        slot.state
+            .compare_exchange(
+                slot_state(key.generation, OCCUPIED),
+                slot_state(key.generation, REMOVING),
+                Ordering::SeqCst,
+                Ordering::Relaxed,
+            )
+            .ok()?;
+
+        let mut backoff = self.col.spin_strategy().backoff();
+        while slot.num_readers.load(Ordering::SeqCst) > 0 {
+            backoff.spin();
+        }
+
+        // SAFETY: the occupied slot is exclusively claimed by this remover and it has no readers
+        let value = unsafe { (*slot.value.get()).assume_init_read() };
+        let generation = key.generation.wrapping_add(1) & GENERATION_MASK;
+        slot.state
+            .store(slot_state(generation, VACANT), Ordering::Release);
+        self.len.fetch_sub(1, Ordering::AcqRel);
+        self.push_free(key.idx);
+
+        Some(value)
+    }
+
+    /// Returns a reference to the entry with the given `key`; returns None if the key does not refer to an entry of the slab.
+    ///
+    /// The entry cannot be removed while the returned reference is alive.
+    pub fn get(&self, key: SlabKey) -> Option<SlabRef<'_, T>> {
+        self.read(key.idx)
+            .filter(|(x, _)| x.generation == key.generation)
+            .map(|(_, x)| x)
+    }
+
+    /// Returns a mutable reference to the entry with the given `key`; returns None if the key does not refer to an entry of the slab.
+    pub fn get_mut(&mut self, key: SlabKey) -> Option<&mut T> {
+        let slot = self.slot(key.idx)?;
+        match slot.state.load(Ordering::Acquire) == slot_state(key.generation, OCCUPIED) {
+            // SAFETY: the slot is occupied and the slab is exclusively borrowed
+            true => Some(unsafe { (*slot.value.get()).assume_init_mut() }),
+            false => None,
+        }
+    }
+
+    /// Returns whether or not the `key` refers to an entry of the slab.
+    pub fn contains_key(&self, key: SlabKey) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Returns an iterator over the keys and references of the entries which are found in the slab at the time each slot is visited.
+    pub fn iter(&self) -> impl Iterator<Item = (SlabKey, SlabRef<'_, T>)> {
+        let num_slots = self.num_slots.load(Ordering::Acquire);
+        (0..num_slots).filter_map(|idx| self.read(idx))
+    }
+
+    /// Returns the key and a reference of the entry occupying the `idx`-th slot, if any.
+    fn read(&self, idx: usize) -> Option<(SlabKey, SlabRef<'_, T>)> {
+        let slot = self.slot(idx)?;
+        slot.num_readers.fetch_add(1, Ordering::SeqCst);
+        let reference = SlabRef { slot };
+        let state = slot.state.load(Ordering::SeqCst);
+        match state & TAG_MASK == OCCUPIED {
+            true => {
+                let generation = state >> TAG_BITS;
+                Some((SlabKey { idx, generation }, reference))
+            }
+            false => None,
+        }
+    }
+
+    /// Returns the `idx`-th slot if it is allocated.
+    fn slot(&self, idx: usize) -> Option<&SlabSlot<T>> {
+        // SAFETY: all allocated positions are filled with slots
+        unsafe { self.col.get(idx) }
+    }
+
+    /// Returns the `idx`-th slot which is known to be allocated.
+    fn allocated_slot(&self, idx: usize) -> &SlabSlot<T> {
+        // SAFETY: all allocated positions are filled with slots, and they are never written by the col once allocated
+        unsafe { &*self.col.ptr_mut_at(idx) }
+    }
+
+    /// Reserves a new slot at the end of the underlying collection and returns its index.
+    fn reserve_new_slot(&self) -> Result<usize, PinnedConcurrentColError> {
+        let idx = self.num_slots.fetch_add(1, Ordering::AcqRel);
+        if idx >= NIL {
+            return Err(PinnedConcurrentColError::OutOfMaximumCapacity {
+                required_capacity: idx + 1,
+                maximum_capacity: NIL,
+            });
+        }
+
+        // SAFETY: idx is reserved only for this inserter
+        let mut reservation = unsafe { self.col.try_reserve_n_items(idx, 1) }?;
+        // SAFETY: the memory is filled with vacant slots on allocation
+        unsafe { reservation.assume_init(1) };
+        let _ = reservation.finish();
+        Ok(idx)
+    }
+
+    fn push_free(&self, idx: usize) {
+        let slot = self.allocated_slot(idx);
+        let mut head = self.free_head.load(Ordering::Acquire);
+        loop {
+            slot.next_free.store(head_index(head), Ordering::Relaxed);
+            let new_head = free_head(idx, (head >> INDEX_BITS).wrapping_add(1));
+            match self.free_head.compare_exchange_weak(
+                head,
+                new_head,
+                Ordering::AcqRel,
+                Ordering::Acquire,
+            ) {
+                Ok(_) => return,
+                Err(current) => head = current,
+            }
+        }
+    }
+
+    fn pop_free(&self) -> Option<usize> {
+        let mut head = self.free_head.load(Ordering::Acquire);
+        loop {
+            let idx = head_index(head);
+            if idx == NIL {
+                return None;
+            }
+            let next = self.allocated_slot(idx).next_free.load(Ordering::Relaxed);
+            let new_head = free_head(next, (head >> INDEX_BITS).wrapping_add(1));
+            match self.free_head.compare_exchange_weak(
+                head,
+                new_head,
+                Ordering::AcqRel,
+                Ordering::Acquire,
+            ) {
+                Ok(_) => return Some(idx),
+                Err(current) => head = current,
+            }
+        }
+    }
+}
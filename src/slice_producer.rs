@@ -0,0 +1,197 @@
+use alloc::vec::Vec;
+use core::slice;
+
+/// A splittable producer over the contiguous slices of a range of a [`PinnedConcurrentCol`](crate::PinnedConcurrentCol),
+/// which allows a thread pool to process the range in parallel.
+///
+/// * The producer is created by [`PinnedConcurrentCol::committed_slice_producer`](crate::PinnedConcurrentCol::committed_slice_producer)
+///   or [`PinnedConcurrentCol::slice_producer`](crate::PinnedConcurrentCol::slice_producer).
+/// * It can be split at any element position by [`SliceProducer::split_at`], possibly splitting a slice into two;
+///   and each part can be sent to a different thread.
+/// * Elements of each part can be processed slice by slice with [`SliceProducer::slices`], or one by one by iterating over the producer.
+///
+/// When the `rayon` feature is enabled, the producer implements rayon's `IntoParallelIterator`.
+///
+/// # Examples
+///
+/// ```
+/// use orx_pinned_concurrent_col::prelude::*;
+///
+/// let col: PinnedConcurrentCol<usize, _, OrderedState<_>> = PinnedConcurrentCol::with_doubling_growth();
+/// unsafe { col.write_n_items(0, 20, 0..20) };
+///
+/// let producer = col.committed_slice_producer();
+/// assert_eq!(producer.len(), 20);
+/// assert_eq!(producer.slices().map(|x| x.len()).collect::<Vec<_>>(), [4, 8, 8]);
+///
+/// let (left, right) = producer.split_at(6);
+/// let sums = std::thread::scope(|s| {
+///     let left = s.spawn(move || left.slices().map(|x| x.iter().sum::<usize>()).sum::<usize>());
+///     let right = s.spawn(move || right.into_iter().sum::<usize>());
+///     [left.join().unwrap(), right.join().unwrap()]
+/// });
+/// assert_eq!(sums, [(0..6).sum(), (6..20).sum()]);
+/// ```
+#[derive(Debug)]
+pub struct SliceProducer<'a, T> {
+    slices: Vec<&'a [T]>,
+    len: usize,
+}
+
+impl<T> Clone for SliceProducer<'_, T> {
+    fn clone(&self) -> Self {
+        Self {
+            slices: self.slices.clone(),
+            len: self.len,
+        }
+    }
+}
+
+impl<T> Default for SliceProducer<'_, T> {
+    fn default() -> Self {
+        Self {
+            slices: Vec::new(),
+            len: 0,
+        }
+    }
+}
+
+impl<'a, T> SliceProducer<'a, T> {
+    pub(crate) fn new<I>(slices: I) -> Self
+    where
+        I: IntoIterator<Item = &'a [T]>,
+    {
+        let slices: Vec<_> = slices.into_iter().filter(|x| !x.is_empty()).collect();
+        let len = slices.iter().map(|x| x.len()).sum();
+        Self { slices, len }
+    }
+
+    /// Returns the number of elements of the producer.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether or not the producer has any element.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the number of non-empty contiguous slices of the producer.
+    pub fn num_slices(&self) -> usize {
+        self.slices.len()
+    }
+
+    /// Returns an iterator over the non-empty contiguous slices of the producer.
+    pub fn slices(&self) -> impl ExactSizeIterator<Item = &'a [T]> + '_ {
+        self.slices.iter().copied()
+    }
+
+    /// Splits the producer into two at the given element `index`;
+    /// the first part contains the elements in `0..index` and the second part the elements in `index..len`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    pub fn split_at(self, index: usize) -> (Self, Self) {
+        assert!(
+            index <= self.len,
+            "split index is out of bounds of the slice producer"
+        );
+
+        let mut left = Vec::new();
+        let mut right = Vec::new();
+        let mut remaining = index;
+        for slice in self.slices {
+            match remaining {
+                0 => right.push(slice),
+                n if n >= slice.len() => {
+                    remaining -= slice.len();
+                    left.push(slice);
+                }
+                n => {
+                    let (a, b) = slice.split_at(n);
+                    left.push(a);
+                    right.push(b);
+                    remaining = 0;
+                }
+            }
+        }
+
+        let left = Self {
+            slices: left,
+            len: index,
+        };
+        let right = Self {
+            slices: right,
+            len: self.len - index,
+        };
+        (left, right)
+    }
+}
+
+impl<'a, T> IntoIterator for SliceProducer<'a, T> {
+    type Item = &'a T;
+    type IntoIter = SliceProducerIter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        SliceProducerIter {
+            slices: self.slices.into_iter(),
+            front: Default::default(),
+            back: Default::default(),
+            len: self.len,
+        }
+    }
+}
+
+/// Exact size and double ended iterator over the elements of a [`SliceProducer`].
+#[derive(Debug)]
+pub struct SliceProducerIter<'a, T> {
+    slices: alloc::vec::IntoIter<&'a [T]>,
+    front: slice::Iter<'a, T>,
+    back: slice::Iter<'a, T>,
+    len: usize,
+}
+
+impl<'a, T> Iterator for SliceProducerIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let next = loop {
+            if let Some(x) = self.front.next() {
+                break Some(x);
+            }
+            match self.slices.next() {
+                Some(slice) => self.front = slice.iter(),
+                None => break self.back.next(),
+            }
+        };
+        self.len -= next.is_some() as usize;
+        next
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> DoubleEndedIterator for SliceProducerIter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let next = loop {
+            if let Some(x) = self.back.next_back() {
+                break Some(x);
+            }
+            match self.slices.next_back() {
+                Some(slice) => self.back = slice.iter(),
+                None => break self.front.next_back(),
+            }
+        };
+        self.len -= next.is_some() as usize;
+        next
+    }
+}
+
+impl<T> ExactSizeIterator for SliceProducerIter<'_, T> {
+    fn len(&self) -> usize {
+        self.len
+    }
+}
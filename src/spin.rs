@@ -0,0 +1,91 @@
+use crate::sync::spin_loop;
+
+/// Strategy that a thread follows while it waits for a permission; such as,
+/// while it waits for another thread to complete the growth on a [`WritePermit::Spin`](crate::WritePermit::Spin).
+///
+/// The strategy can be defined per concurrent state by [`ConcurrentState::spin_strategy`](crate::ConcurrentState::spin_strategy),
+/// and overwritten per collection by [`PinnedConcurrentCol::set_spin_strategy`](crate::PinnedConcurrentCol::set_spin_strategy).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SpinStrategy {
+    /// The waiting thread busy spins; it only emits a single spin-loop hint to the processor at each attempt.
+    ///
+    /// This is the most responsive strategy and the default one; however, it keeps a core busy during the wait.
+    #[default]
+    Spin,
+    /// The waiting thread backs off exponentially; the number of spin-loop hints is doubled after each failed attempt
+    /// until it reaches `2 ^ max_exponent`.
+    ///
+    /// This strategy reduces the contention on the shared atomic state while a long allocation is in progress.
+    ExponentialBackoff {
+        /// Exponent of the maximum number of spin-loop hints emitted at a single attempt.
+        max_exponent: u32,
+    },
+    /// The waiting thread spins with a spin-loop hint for `num_spins` attempts;
+    /// after which it yields its time slice to the operating system scheduler at each further attempt.
+    ///
+    /// This strategy is suitable for oversubscribed thread pools where the thread performing the allocation might be descheduled.
+    ///
+    /// Yielding requires the `std` feature; without it, the thread keeps emitting a single spin-loop hint at each further attempt.
+    YieldAfter {
+        /// Number of attempts to spin before starting to yield.
+        num_spins: u32,
+    },
+}
+
+impl SpinStrategy {
+    /// Strategy for the waits which might take long, such as waiting for the other writers or for the consumers:
+    /// the thread yields after 64 spins when the `std` feature is enabled, and it busy spins otherwise.
+    pub const LONG_WAIT: Self = Self::YieldAfter { num_spins: 64 };
+
+    /// Creates a new [`Backoff`] which waits following this strategy.
+    pub fn backoff(self) -> Backoff {
+        Backoff::new(self)
+    }
+}
+
+/// Waiting state of a single thread following a [`SpinStrategy`].
+///
+/// A backoff is created per wait; i.e., each wait starts with the most responsive step of the strategy.
+#[derive(Clone, Debug)]
+pub struct Backoff {
+    strategy: SpinStrategy,
+    step: u32,
+}
+
+impl Backoff {
+    /// Creates a new backoff for a wait following the given `strategy`.
+    pub fn new(strategy: SpinStrategy) -> Self {
+        Self { strategy, step: 0 }
+    }
+
+    /// Returns the number of times the thread has waited so far.
+    pub fn num_steps(&self) -> u32 {
+        self.step
+    }
+
+    /// Resets the backoff to its initial step.
+    pub fn reset(&mut self) {
+        self.step = 0;
+    }
+
+    /// Waits once following the strategy.
+    pub fn spin(&mut self) {
+        match self.strategy {
+            SpinStrategy::Spin => spin_loop(),
+            SpinStrategy::ExponentialBackoff { max_exponent } => {
+                let exponent = self.step.min(max_exponent).min(u32::BITS - 1);
+                for _ in 0..(1u32 << exponent) {
+                    spin_loop();
+                }
+            }
+            SpinStrategy::YieldAfter { num_spins } => match self.step < num_spins {
+                true => spin_loop(),
+                #[cfg(feature = "std")]
+                false => crate::sync::yield_now(),
+                #[cfg(not(feature = "std"))]
+                false => spin_loop(),
+            },
+        }
+        self.step = self.step.saturating_add(1);
+    }
+}
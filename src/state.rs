@@ -1,4 +1,7 @@
-use crate::{write_permit::WritePermit, PinnedConcurrentCol};
+use crate::{
+    PinnedConcurrentCol, growth_policy::GrowthPolicy, memory_fill::MemoryFill, spin::SpinStrategy,
+    write_permit::WritePermit,
+};
 use alloc::string::{String, ToString};
 use orx_pinned_vec::{ConcurrentPinnedVec, PinnedVec};
 
@@ -9,9 +12,11 @@ where
 {
     /// Determines whether or not new allocations of the pinned vector will be automatically zeroed out immediately after allocation.
     ///
-    /// * If the method returns Some(f), new positions will be filled with f().
+    /// * If the method returns Some(fill), new positions will be filled with fill.fill().
     /// * Otherwise, new positions will not be initialized.
-    fn fill_memory_with(&self) -> Option<fn() -> T>;
+    ///
+    /// See [`MemoryFill`] for filling with a function, the default value, a cloned template value or a closure capturing runtime state.
+    fn fill_memory_with(&self) -> Option<MemoryFill<T>>;
 
     /// Creates a new state for the given `pinned_vec` which is to be wrapped by a [`PinnedConcurrentCol`].
     fn new_for_pinned_vec<P: PinnedVec<T>>(pinned_vec: &P) -> Self;
@@ -43,12 +48,84 @@ where
         self.write_permit(col, last_idx)
     }
 
-    /// If `write_permit` call returning [`WritePermit::GrowThenWrite`] grabs a growth handle, it must be released with this method.
+    /// If `write_permit` call returning [`WritePermit::GrowThenWrite`] or [`WritePermit::WriteAndGrowAhead`] grabs a growth handle, it must be released with this method.
     /// Otherwise, it might be an empty method.
     fn release_growth_handle(&self);
 
+    /// Updates the state so that it covers a collection with the given `capacity`.
+    ///
+    /// The thread growing the collection calls the method with the requested capacity before the pinned vector publishes its new capacity,
+    /// and with the resulting capacity afterwards; hence, the method might be called more than once per growth and with a capacity
+    /// which is not yet allocated.
+    ///
+    /// The calls are serialized when the state holds a growth handle until `release_growth_handle`.
+    /// Otherwise, a writer might grow the collection as soon as the prior growth publishes its capacity;
+    /// hence, the method might be called concurrently and must then be thread-safe. It is an empty method by default.
+    fn update_after_growth(&self, _capacity: usize) {}
+
     /// Updates the state after writing values onto the range `begin_idx...end_idx`.
-    fn update_after_write(&self, begin_idx: usize, end_idx: usize);
+    ///
+    /// The `spin_strategy` is the strategy of the collection, [`PinnedConcurrentCol::spin_strategy`],
+    /// which the state must follow whenever the writer waits for other writers within the update.
+    fn update_after_write(&self, begin_idx: usize, end_idx: usize, spin_strategy: SpinStrategy);
+
+    /// Publishes the range `begin_idx..end_idx` after its values are completely written.
+    ///
+    /// Note that `update_after_write` is also called by the buffer methods, such as [`PinnedConcurrentCol::n_items_buffer_as_mut_slices`],
+    /// before the caller writes the positions. This method, on the other hand, is called only once the values are written:
+    /// by the `write` and `write_n_items` methods, by the [`Reservation`](crate::Reservation)s and by [`PinnedConcurrentCol::commit_range`].
+    /// Hence, states publishing the written positions to the readers (see [`PublishedState`]) must publish within this method.
+    ///
+    /// It is an empty method by default.
+    fn publish_after_write(
+        &self,
+        _begin_idx: usize,
+        _end_idx: usize,
+        _spin_strategy: SpinStrategy,
+    ) {
+    }
+
+    /// Returns the strategy that threads follow while waiting on a [`WritePermit::Spin`].
+    ///
+    /// Note that the strategy can be overwritten for a particular collection by [`PinnedConcurrentCol::set_spin_strategy`].
+    fn spin_strategy(&self) -> SpinStrategy {
+        SpinStrategy::default()
+    }
+
+    /// Returns the policy determining when the collection allocates new positions.
+    ///
+    /// States which return [`WritePermit::WriteAndGrowAhead`] are expected to respect the policy of the collection, [`PinnedConcurrentCol::growth_policy`],
+    /// which can be overwritten for a particular collection by [`PinnedConcurrentCol::set_growth_policy`].
+    fn growth_policy(&self) -> GrowthPolicy {
+        GrowthPolicy::default()
+    }
+
+    /// Determines whether or not the collection keeps track of its written positions.
+    ///
+    /// When the method returns true, the collection maintains an atomic bitmap alongside the pinned vector
+    /// and marks every position that is written by `write`, `write_n_items` or reserved by the buffer methods.
+    /// This allows the collection to drop only the initialized positions even when the writes leave gaps,
+    /// and to verify that a prefix is gap-free before taking out the pinned vector by [`PinnedConcurrentCol::try_into_inner`].
+    ///
+    /// Tracking is disabled by default since states which only push to the back of the collection do not need it.
+    fn track_written_positions(&self) -> bool {
+        false
+    }
+
+    /// Determines whether or not the collection maintains a committed length, the length of the longest prefix which is completely written.
+    ///
+    /// When the method returns true, the collection advances the committed length as the writes by `write`, `write_n_items`
+    /// and the [`Reservation`](crate::Reservation)s complete, possibly out of order.
+    /// This allows readers to safely access the committed prefix by [`PinnedConcurrentCol::committed_len`],
+    /// [`PinnedConcurrentCol::get_committed`] and [`PinnedConcurrentCol::iter_committed`].
+    ///
+    /// Positions acquired by the buffer methods such as [`PinnedConcurrentCol::n_items_buffer_as_mut_slices`] are committed only once the caller
+    /// calls [`PinnedConcurrentCol::commit_range`] after writing them.
+    ///
+    /// Committing costs an additional atomic bitmap alongside the pinned vector; hence, it is disabled by default.
+    fn track_committed_len(&self) -> bool {
+        false
+    }
 
     /// Returns the debug information of the underlying pinned vector.
     #[allow(unused_variables)]
@@ -67,3 +144,20 @@ where
     /// Returns `None` if it is not known with certainty.
     fn try_get_no_gap_len(&self) -> Option<usize>;
 }
+
+/// Extension of the [`ConcurrentState`] for states which publish the written positions to the readers,
+/// enabling readers to wait for a position to be written.
+///
+/// The collection methods [`PinnedConcurrentCol::try_get_published`] and [`PinnedConcurrentCol::wait_get`] are built on this trait.
+///
+/// # Safety
+///
+/// Readers access the published positions safely through a shared reference; therefore, the implementor must guarantee that:
+/// * positions are published only by [`ConcurrentState::publish_after_write`], which is never called before the positions are written;
+/// * once `is_published(idx)` returns true, the `idx`-th position is completely written,
+///   and the write happens-before the call; i.e., the state publishes with a `Release` store which pairs with an `Acquire` load in `is_published`;
+/// * a published position is never written again through a shared reference of the collection.
+pub unsafe trait PublishedState<T>: ConcurrentState<T> {
+    /// Returns whether or not the `idx`-th position is written and published to the readers.
+    fn is_published(&self, idx: usize) -> bool;
+}
@@ -0,0 +1,127 @@
+use crate::{
+    ConcurrentState, MemoryFill, PinnedConcurrentCol,
+    spin::SpinStrategy,
+    sync::{AtomicUsize, atomic},
+    write_permit::WritePermit,
+};
+use core::{cmp::Ordering, fmt::Debug, marker::PhantomData};
+use orx_pinned_vec::{ConcurrentPinnedVec, PinnedVec};
+
+/// Concurrent state of an append-only collection such as a concurrent bag.
+///
+/// * Positions are reserved by incrementing an atomic length with [`AppendOnlyState::fetch_increment_len`].
+/// * Since positions are reserved sequentially, exactly one request covers the position at the current capacity.
+///   The thread making this request grows the collection; hence, the state does not require a growth handle.
+/// * Every reserved position is expected to be written; then, the length is also the length written without a gap.
+///
+/// # Examples
+///
+/// ```
+/// use orx_pinned_concurrent_col::*;
+/// use orx_pinned_concurrent_col::states::AppendOnlyState;
+///
+/// let col: PinnedConcurrentCol<&str, _, AppendOnlyState<_>> = PinnedConcurrentCol::with_doubling_growth();
+///
+/// for value in ["a", "b", "c"] {
+///     let idx = col.state().fetch_increment_len(1);
+///     unsafe { col.write(idx, value) };
+/// }
+///
+/// assert_eq!(col.state().len(), 3);
+///
+/// let vec = unsafe { col.into_inner(3) };
+/// assert_eq!(vec, &["a", "b", "c"]);
+/// ```
+pub struct AppendOnlyState<T> {
+    len: AtomicUsize,
+    phantom: PhantomData<T>,
+}
+
+impl<T> Debug for AppendOnlyState<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("AppendOnlyState")
+            .field("len", &self.len())
+            .finish()
+    }
+}
+
+impl<T> AppendOnlyState<T> {
+    fn new(len: usize) -> Self {
+        Self {
+            len: len.into(),
+            phantom: PhantomData,
+        }
+    }
+
+    /// Returns the number of reserved positions.
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.len.load(atomic::Ordering::Acquire)
+    }
+
+    /// Returns whether or not there exists any reserved position.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Reserves `increment_by` sequential positions and returns the first of them.
+    ///
+    /// The caller is responsible for writing all reserved positions.
+    #[inline(always)]
+    pub fn fetch_increment_len(&self, increment_by: usize) -> usize {
+        self.len.fetch_add(increment_by, atomic::Ordering::AcqRel)
+    }
+}
+
+impl<T> ConcurrentState<T> for AppendOnlyState<T> {
+    fn fill_memory_with(&self) -> Option<MemoryFill<T>> {
+        None
+    }
+
+    fn new_for_pinned_vec<P: PinnedVec<T>>(pinned_vec: &P) -> Self {
+        Self::new(pinned_vec.len())
+    }
+
+    fn new_for_con_pinned_vec<P: ConcurrentPinnedVec<T>>(_: &P, len: usize) -> Self {
+        Self::new(len)
+    }
+
+    fn write_permit<P>(&self, col: &PinnedConcurrentCol<T, P, Self>, idx: usize) -> WritePermit
+    where
+        P: ConcurrentPinnedVec<T>,
+    {
+        match idx.cmp(&col.capacity()) {
+            Ordering::Less => WritePermit::JustWrite,
+            Ordering::Equal => WritePermit::GrowThenWrite,
+            Ordering::Greater => WritePermit::Spin,
+        }
+    }
+
+    fn write_permit_n_items<P>(
+        &self,
+        col: &PinnedConcurrentCol<T, P, Self>,
+        begin_idx: usize,
+        num_items: usize,
+    ) -> WritePermit
+    where
+        P: ConcurrentPinnedVec<T>,
+    {
+        let capacity = col.capacity();
+        let last_idx = begin_idx + num_items - 1;
+
+        match (begin_idx.cmp(&capacity), last_idx.cmp(&capacity)) {
+            (_, Ordering::Less) => WritePermit::JustWrite,
+            (Ordering::Greater, _) => WritePermit::Spin,
+            _ => WritePermit::GrowThenWrite,
+        }
+    }
+
+    fn release_growth_handle(&self) {}
+
+    fn update_after_write(&self, _: usize, _: usize, _: SpinStrategy) {}
+
+    fn try_get_no_gap_len(&self) -> Option<usize> {
+        Some(self.len())
+    }
+}
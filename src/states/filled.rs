@@ -0,0 +1,107 @@
+use super::growth_handle::GrowthHandle;
+use crate::{
+    ConcurrentState, MemoryFill, PinnedConcurrentCol,
+    spin::SpinStrategy,
+    sync::{AtomicUsize, atomic},
+    write_permit::WritePermit,
+};
+use core::{fmt::Debug, marker::PhantomData};
+use orx_pinned_vec::{ConcurrentPinnedVec, PinnedVec};
+
+/// Concurrent state of a collection whose memory is filled with `T::default()` immediately after allocation.
+///
+/// * Every allocated position holds a valid value; hence, the collection never contains uninitialized gaps
+///   and the entire capacity is dropped together with the collection.
+/// * Writes to arbitrary positions are allowed, and growth is guarded by a growth handle.
+/// * The state keeps track of the end of the furthest written position.
+///
+/// Note that writing to a position does not drop the default value that it replaces.
+/// Therefore, the state is most suitable for types whose default value does not own resources, such as `Option<T>`.
+///
+/// # Examples
+///
+/// ```
+/// use orx_pinned_concurrent_col::*;
+/// use orx_pinned_concurrent_col::states::FilledState;
+///
+/// let col: PinnedConcurrentCol<Option<String>, _, FilledState<_>> = PinnedConcurrentCol::with_doubling_growth();
+///
+/// unsafe { col.write(5, Some("f".to_string())) };
+/// assert_eq!(col.state().len(), 6);
+///
+/// assert_eq!(unsafe { col.get(1) }, Some(&None));
+/// assert_eq!(unsafe { col.get(5) }, Some(&Some("f".to_string())));
+/// ```
+pub struct FilledState<T: Default> {
+    len: AtomicUsize,
+    growth_handle: GrowthHandle,
+    phantom: PhantomData<T>,
+}
+
+impl<T: Default> Debug for FilledState<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("FilledState")
+            .field("len", &self.len())
+            .finish()
+    }
+}
+
+impl<T: Default> FilledState<T> {
+    fn new(len: usize) -> Self {
+        Self {
+            len: len.into(),
+            growth_handle: GrowthHandle::default(),
+            phantom: PhantomData,
+        }
+    }
+
+    /// Returns the end of the furthest written position; i.e., one plus the maximum written position.
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.len.load(atomic::Ordering::Acquire)
+    }
+
+    /// Returns whether or not any position is written.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<T: Default> ConcurrentState<T> for FilledState<T> {
+    fn fill_memory_with(&self) -> Option<MemoryFill<T>> {
+        Some(MemoryFill::from_default())
+    }
+
+    fn new_for_pinned_vec<P: PinnedVec<T>>(pinned_vec: &P) -> Self {
+        Self::new(pinned_vec.len())
+    }
+
+    fn new_for_con_pinned_vec<P: ConcurrentPinnedVec<T>>(_: &P, len: usize) -> Self {
+        Self::new(len)
+    }
+
+    fn write_permit<P>(&self, col: &PinnedConcurrentCol<T, P, Self>, idx: usize) -> WritePermit
+    where
+        P: ConcurrentPinnedVec<T>,
+    {
+        self.growth_handle.write_permit(
+            idx,
+            || col.capacity(),
+            || col.latest_allocation(),
+            col.growth_policy(),
+        )
+    }
+
+    fn release_growth_handle(&self) {
+        self.growth_handle.release();
+    }
+
+    fn update_after_write(&self, _: usize, end_idx: usize, _: SpinStrategy) {
+        self.len.fetch_max(end_idx, atomic::Ordering::AcqRel);
+    }
+
+    fn try_get_no_gap_len(&self) -> Option<usize> {
+        Some(self.len())
+    }
+}
@@ -0,0 +1,64 @@
+use crate::{
+    growth_policy::GrowthPolicy,
+    sync::{AtomicBool, Ordering},
+    write_permit::WritePermit,
+};
+use core::ops::Range;
+
+/// A flag making sure that only one thread is responsible for the growth at a given point in time.
+#[derive(Debug, Default)]
+pub(crate) struct GrowthHandle {
+    is_taken: AtomicBool,
+}
+
+impl GrowthHandle {
+    /// Evaluates the write permit for a request whose last position is `last_idx`.
+    ///
+    /// The thread receiving [`WritePermit::GrowThenWrite`] or [`WritePermit::WriteAndGrowAhead`] holds the handle, which must be released by `release`.
+    pub(crate) fn write_permit<C, A>(
+        &self,
+        last_idx: usize,
+        capacity: C,
+        latest_allocation: A,
+        growth_policy: GrowthPolicy,
+    ) -> WritePermit
+    where
+        C: Fn() -> usize,
+        A: Fn() -> Range<usize>,
+    {
+        match last_idx < capacity() {
+            true => match growth_policy.grows_ahead(last_idx, latest_allocation())
+                && self.try_acquire()
+            {
+                false => WritePermit::JustWrite,
+                true => match growth_policy.grows_ahead(last_idx, latest_allocation()) {
+                    true => WritePermit::WriteAndGrowAhead,
+                    false => {
+                        self.release();
+                        WritePermit::JustWrite
+                    }
+                },
+            },
+            false => match self.try_acquire() {
+                false => WritePermit::Spin,
+                true => match last_idx < capacity() {
+                    true => {
+                        self.release();
+                        WritePermit::JustWrite
+                    }
+                    false => WritePermit::GrowThenWrite,
+                },
+            },
+        }
+    }
+
+    pub(crate) fn release(&self) {
+        self.is_taken.store(false, Ordering::Release);
+    }
+
+    fn try_acquire(&self) -> bool {
+        self.is_taken
+            .compare_exchange(false, true, Ordering::Acquire, Ordering::Relaxed)
+            .is_ok()
+    }
+}
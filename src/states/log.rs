@@ -0,0 +1,201 @@
+use crate::{
+    ConcurrentState, MemoryFill, PinnedConcurrentCol,
+    committed::CommittedLen,
+    spin::SpinStrategy,
+    state::PublishedState,
+    sync::{AtomicUsize, atomic},
+    write_permit::WritePermit,
+};
+use core::{cmp::Ordering, fmt::Debug, marker::PhantomData};
+use orx_pinned_vec::{ConcurrentPinnedVec, PinnedVec};
+
+/// Concurrent state of an append-only log which additionally maintains a committed length.
+///
+/// * Positions are reserved by incrementing an atomic length with [`LogState::fetch_increment_len`], similar to the [`AppendOnlyState`](crate::states::AppendOnlyState).
+/// * The committed length is the length of the prefix of the collection which is completely written.
+///   Readers can safely read all elements within `0..committed_len()`.
+/// * Writes might complete in any order: each written range is marked, and the committed length advances over the marked positions
+///   as soon as the gaps before them are written; hence, a writer never waits for another writer.
+///
+/// The state publishes the committed positions; hence, readers can wait for a position to be written
+/// by [`PinnedConcurrentCol::wait_get`].
+///
+/// Commits are performed by `publish_after_write`, which is called only once the values are written;
+/// positions acquired by the buffer methods, such as [`PinnedConcurrentCol::n_items_buffer_as_mut_slices`],
+/// are committed once the caller calls [`PinnedConcurrentCol::commit_range`] after writing them.
+///
+/// A reserved range which fails to be written can never be committed; the writer must then [`LogState::poison`] the state at the range.
+/// A poisoned state rejects the write requests at or after the failed range with [`WritePermit::Reject`];
+/// the ranges after the failed range might be written, but they are never committed.
+pub struct LogState<T> {
+    len: AtomicUsize,
+    committed: CommittedLen,
+    poisoned_at: AtomicUsize,
+    phantom: PhantomData<T>,
+}
+
+impl<T> Debug for LogState<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("LogState")
+            .field("len", &self.len())
+            .field("committed_len", &self.committed_len())
+            .field("poisoned_at", &self.poisoned_at())
+            .finish()
+    }
+}
+
+impl<T> LogState<T> {
+    fn new(len: usize, capacity: usize) -> Self {
+        Self {
+            len: len.into(),
+            committed: CommittedLen::new(capacity, len),
+            poisoned_at: usize::MAX.into(),
+            phantom: PhantomData,
+        }
+    }
+
+    /// Returns the number of reserved positions.
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.len.load(atomic::Ordering::Acquire)
+    }
+
+    /// Returns whether or not there exists any reserved position.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the length of the prefix of the collection which is completely written.
+    #[inline(always)]
+    pub fn committed_len(&self) -> usize {
+        self.committed.len()
+    }
+
+    /// Reserves `increment_by` sequential positions and returns the first of them.
+    ///
+    /// The caller is responsible for writing all reserved positions;
+    /// or for poisoning the state by [`LogState::poison`] if the write fails, since the committed length never advances beyond an unwritten position.
+    #[inline(always)]
+    pub fn fetch_increment_len(&self, increment_by: usize) -> usize {
+        self.len.fetch_add(increment_by, atomic::Ordering::AcqRel)
+    }
+
+    /// Returns whether or not the state is poisoned; i.e., whether a reserved range failed to be written.
+    #[inline(always)]
+    pub fn is_poisoned(&self) -> bool {
+        self.poisoned_at().is_some()
+    }
+
+    /// Returns the first position of the earliest reserved range which failed to be written, if any.
+    ///
+    /// The committed length never advances beyond this position.
+    #[inline(always)]
+    pub fn poisoned_at(&self) -> Option<usize> {
+        match self.poisoned_at.load(atomic::Ordering::Acquire) {
+            usize::MAX => None,
+            idx => Some(idx),
+        }
+    }
+
+    /// Poisons the state after the reserved range beginning at `begin_idx` failed to be written, and hence, can never be committed.
+    ///
+    /// Write requests at or after `begin_idx` are rejected; while the prior ranges are still written and committed.
+    pub fn poison(&self, begin_idx: usize) {
+        self.poisoned_at
+            .fetch_min(begin_idx, atomic::Ordering::AcqRel);
+    }
+
+    /// Returns an iterator over the positions below `end_idx` which are written but never going to be committed,
+    /// since they are beyond a range which failed to be written.
+    pub(crate) fn written_uncommitted(&self, end_idx: usize) -> impl Iterator<Item = usize> + '_ {
+        self.committed.completed_beyond(end_idx)
+    }
+
+    fn is_rejected(&self, begin_idx: usize) -> bool {
+        begin_idx >= self.poisoned_at.load(atomic::Ordering::Acquire)
+    }
+}
+
+impl<T> ConcurrentState<T> for LogState<T> {
+    fn fill_memory_with(&self) -> Option<MemoryFill<T>> {
+        None
+    }
+
+    fn new_for_pinned_vec<P: PinnedVec<T>>(pinned_vec: &P) -> Self {
+        Self::new(pinned_vec.len(), pinned_vec.capacity())
+    }
+
+    fn new_for_con_pinned_vec<P: ConcurrentPinnedVec<T>>(con_pinned_vec: &P, len: usize) -> Self {
+        Self::new(len, con_pinned_vec.capacity())
+    }
+
+    fn write_permit<P>(&self, col: &PinnedConcurrentCol<T, P, Self>, idx: usize) -> WritePermit
+    where
+        P: ConcurrentPinnedVec<T>,
+    {
+        if self.is_rejected(idx) {
+            return WritePermit::Reject;
+        }
+
+        match idx.cmp(&col.capacity()) {
+            Ordering::Less => WritePermit::JustWrite,
+            Ordering::Equal => WritePermit::GrowThenWrite,
+            Ordering::Greater => WritePermit::Spin,
+        }
+    }
+
+    fn write_permit_n_items<P>(
+        &self,
+        col: &PinnedConcurrentCol<T, P, Self>,
+        begin_idx: usize,
+        num_items: usize,
+    ) -> WritePermit
+    where
+        P: ConcurrentPinnedVec<T>,
+    {
+        if self.is_rejected(begin_idx) {
+            return WritePermit::Reject;
+        }
+
+        let capacity = col.capacity();
+        let last_idx = begin_idx + num_items - 1;
+
+        match (begin_idx.cmp(&capacity), last_idx.cmp(&capacity)) {
+            (_, Ordering::Less) => WritePermit::JustWrite,
+            (Ordering::Greater, _) => WritePermit::Spin,
+            _ => WritePermit::GrowThenWrite,
+        }
+    }
+
+    fn spin_strategy(&self) -> SpinStrategy {
+        SpinStrategy::LONG_WAIT
+    }
+
+    fn release_growth_handle(&self) {}
+
+    fn update_after_growth(&self, capacity: usize) {
+        self.committed.grow_to(capacity);
+    }
+
+    fn update_after_write(&self, _: usize, _: usize, _: SpinStrategy) {}
+
+    fn publish_after_write(&self, begin_idx: usize, end_idx: usize, spin_strategy: SpinStrategy) {
+        // all marked positions are reserved; hence, the committed length cannot advance beyond the reserved length
+        self.committed
+            .commit(begin_idx..end_idx, self.len(), spin_strategy);
+    }
+
+    fn try_get_no_gap_len(&self) -> Option<usize> {
+        Some(self.committed_len())
+    }
+}
+
+// SAFETY: committed length advances with Release after the writes of all positions below it are marked, and it is loaded with Acquire;
+// committed positions are never written again.
+unsafe impl<T> PublishedState<T> for LogState<T> {
+    #[inline(always)]
+    fn is_published(&self, idx: usize) -> bool {
+        idx < self.committed_len()
+    }
+}
@@ -0,0 +1,10 @@
+mod append_only;
+mod filled;
+mod growth_handle;
+mod log;
+mod ordered;
+
+pub use append_only::AppendOnlyState;
+pub use filled::FilledState;
+pub use log::LogState;
+pub use ordered::OrderedState;
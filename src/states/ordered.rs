@@ -0,0 +1,147 @@
+use super::growth_handle::GrowthHandle;
+use crate::{
+    ConcurrentState, MemoryFill, PinnedConcurrentCol,
+    spin::SpinStrategy,
+    sync::{AtomicUsize, atomic},
+    write_permit::WritePermit,
+};
+use core::{fmt::Debug, marker::PhantomData};
+use orx_pinned_vec::{ConcurrentPinnedVec, PinnedVec};
+
+/// Concurrent state of a collection which allows writing to arbitrary positions, such as a concurrent ordered bag.
+///
+/// * Since any thread might request a position beyond the capacity, growth is guarded by a growth handle.
+///   Only the thread attaining the handle grows the collection while others spin.
+/// * The state keeps track of the number of written elements and the end of the furthest written position.
+///   The collection is known to be gap-free when these two values are equal, provided that each position is written at most once.
+/// * The memory is not filled; hence, when the collection has gaps, the no-gap length is reported as unknown, `None`.
+///   However, the state enables tracking of the written positions (see [`ConcurrentState::track_written_positions`]).
+///   Therefore, the collection drops exactly the written elements even when it has gaps,
+///   and the pinned vector can be safely taken out by [`PinnedConcurrentCol::try_into_inner`].
+/// * The state further enables the committed length (see [`ConcurrentState::track_committed_len`]);
+///   hence, readers can safely access the gap-free prefix by [`PinnedConcurrentCol::iter_committed`] while the writes complete out of order.
+///
+/// # Examples
+///
+/// ```
+/// use orx_pinned_concurrent_col::*;
+/// use orx_pinned_concurrent_col::states::OrderedState;
+///
+/// let col: PinnedConcurrentCol<&str, _, OrderedState<_>> = PinnedConcurrentCol::with_doubling_growth();
+///
+/// unsafe { col.write(2, "c") };
+/// unsafe { col.write(0, "a") };
+/// assert_eq!(col.state().try_get_no_gap_len(), None);
+///
+/// assert_eq!(col.committed_len(), Some(1));
+///
+/// unsafe { col.write(1, "b") };
+/// assert_eq!(col.state().try_get_no_gap_len(), Some(3));
+/// assert_eq!(col.iter_committed().collect::<Vec<_>>(), [&"a", &"b", &"c"]);
+///
+/// let vec = unsafe { col.into_inner(3) };
+/// assert_eq!(vec, &["a", "b", "c"]);
+/// ```
+pub struct OrderedState<T> {
+    len: AtomicUsize,
+    num_written: AtomicUsize,
+    growth_handle: GrowthHandle,
+    phantom: PhantomData<T>,
+}
+
+impl<T> Debug for OrderedState<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("OrderedState")
+            .field("len", &self.len())
+            .field("num_written", &self.num_written())
+            .finish()
+    }
+}
+
+impl<T> OrderedState<T> {
+    fn new(len: usize) -> Self {
+        Self {
+            len: len.into(),
+            num_written: len.into(),
+            growth_handle: GrowthHandle::default(),
+            phantom: PhantomData,
+        }
+    }
+
+    /// Returns the end of the furthest written position; i.e., one plus the maximum written position.
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.len.load(atomic::Ordering::Acquire)
+    }
+
+    /// Returns whether or not any position is written.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the number of written positions.
+    #[inline(always)]
+    pub fn num_written(&self) -> usize {
+        self.num_written.load(atomic::Ordering::Acquire)
+    }
+}
+
+impl<T> ConcurrentState<T> for OrderedState<T> {
+    fn fill_memory_with(&self) -> Option<MemoryFill<T>> {
+        None
+    }
+
+    fn new_for_pinned_vec<P: PinnedVec<T>>(pinned_vec: &P) -> Self {
+        Self::new(pinned_vec.len())
+    }
+
+    fn new_for_con_pinned_vec<P: ConcurrentPinnedVec<T>>(_: &P, len: usize) -> Self {
+        Self::new(len)
+    }
+
+    fn write_permit<P>(&self, col: &PinnedConcurrentCol<T, P, Self>, idx: usize) -> WritePermit
+    where
+        P: ConcurrentPinnedVec<T>,
+    {
+        self.growth_handle.write_permit(
+            idx,
+            || col.capacity(),
+            || col.latest_allocation(),
+            col.growth_policy(),
+        )
+    }
+
+    fn release_growth_handle(&self) {
+        self.growth_handle.release();
+    }
+
+    fn update_after_write(&self, begin_idx: usize, end_idx: usize, _: SpinStrategy) {
+        // len must be extended before the range is counted; see try_get_no_gap_len
+        self.len.fetch_max(end_idx, atomic::Ordering::AcqRel);
+        self.num_written
+            .fetch_add(end_idx - begin_idx, atomic::Ordering::AcqRel);
+    }
+
+    fn track_written_positions(&self) -> bool {
+        true
+    }
+
+    fn track_committed_len(&self) -> bool {
+        true
+    }
+
+    fn try_get_no_gap_len(&self) -> Option<usize> {
+        // Every range counted in `num_written` extended `len` before it was counted.
+        // Loading `num_written` first therefore guarantees that all counted ranges lie below the subsequently loaded `len`;
+        // and since each position is written at most once, the counts can only be equal when `0..len` is completely written.
+        // Loading in the opposite order would count ranges beyond `len`, which might hide a gap below it.
+        let num_written = self.num_written();
+        let len = self.len();
+        // with gaps, the gap-free length is unknown to the state; the collection then relies on its bitmap of written positions
+        match num_written == len {
+            true => Some(len),
+            false => None,
+        }
+    }
+}
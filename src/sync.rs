@@ -0,0 +1,27 @@
+//! Synchronization primitives of the write and growth protocol.
+//!
+//! When compiled with `--cfg loom`, the primitives are replaced by their [loom](https://docs.rs/loom) counterparts
+//! so that the interleavings of concurrent writes and growths can be exhaustively model checked.
+//! Note that the atomics internal to the underlying pinned vectors, such as their capacities and fragment pointers, are not replaced;
+//! hence, the models do not observe the synchronization through, nor the races on, the memory of the pinned vectors.
+//! The growth of the bitmaps of the written positions is instead modelled by a loom cell standing in for their memory.
+
+#[cfg(all(not(loom), target_has_atomic = "64"))]
+pub(crate) use core::sync::atomic::AtomicU64;
+#[cfg(not(loom))]
+pub(crate) use core::{
+    hint::spin_loop,
+    sync::atomic::{self, AtomicBool, AtomicUsize, Ordering},
+};
+#[cfg(all(not(loom), feature = "std"))]
+pub(crate) use std::thread::yield_now;
+
+#[cfg(all(loom, target_has_atomic = "64"))]
+pub(crate) use loom::sync::atomic::AtomicU64;
+#[cfg(all(loom, feature = "std"))]
+pub(crate) use loom::thread::yield_now;
+#[cfg(loom)]
+pub(crate) use loom::{
+    hint::spin_loop,
+    sync::atomic::{self, AtomicBool, AtomicUsize, Ordering},
+};
@@ -7,6 +7,15 @@ pub enum WritePermit {
     /// Furthermore, the caller thread must take the responsibility of the allocation.
     /// Then, it is free to write the value or values.
     GrowThenWrite,
+    /// Concurrent collection is allowed to directly write to the positions; however, the write frontier is close to the end of the capacity.
+    /// The caller thread must take the responsibility of allocating ahead of the frontier; see [`GrowthPolicy::GrowAhead`](crate::GrowthPolicy::GrowAhead).
+    /// Then, it is free to write the value or values.
+    WriteAndGrowAhead,
     /// The caller thread must spin and re-evaluate the write permit.
     Spin,
+    /// The write request is rejected by the state and must not be performed.
+    ///
+    /// Fallible write methods such as [`PinnedConcurrentCol::try_write`](crate::PinnedConcurrentCol::try_write) return
+    /// [`PinnedConcurrentColError::RejectedByState`](crate::PinnedConcurrentColError::RejectedByState), while the infallible ones panic.
+    Reject,
 }
@@ -0,0 +1,194 @@
+use crate::{
+    spin::SpinStrategy,
+    sync::{AtomicBool, AtomicUsize, Ordering, spin_loop},
+};
+use core::ops::Range;
+use orx_pinned_vec::{ConcurrentPinnedVec, IntoConcurrentPinnedVec};
+use orx_split_vec::{ConcurrentSplitVec, Doubling, SplitVec};
+
+const BITS: usize = usize::BITS as usize;
+
+/// Atomic bitmap keeping track of the written positions of a pinned concurrent collection.
+///
+/// The bitmap is itself stored in a concurrent pinned vector of atomic words;
+/// and hence, it grows together with the collection without moving the already allocated words.
+///
+/// The growth of the words is not thread-safe on its own; therefore, the bitmap serializes the growth requests
+/// by a flag, since the bitmap might be grown concurrently by the collections whose states do not hold a growth handle.
+pub(crate) struct WrittenPositions {
+    words: ConcurrentSplitVec<AtomicUsize, Doubling>,
+    growing: AtomicBool,
+    /// Stand-in for the memory of the words when model checking, since loom does not see the atomics of the pinned vector;
+    /// concurrent growths of the words are reported by loom as concurrent mutable accesses to the cell.
+    #[cfg(loom)]
+    words_growth: loom::cell::UnsafeCell<()>,
+}
+
+impl WrittenPositions {
+    /// Creates the bitmap for a collection with the given `capacity` where positions `0..written_len` are already written.
+    pub(crate) fn new(capacity: usize, written_len: usize) -> Self {
+        let words = SplitVec::with_doubling_growth_and_max_concurrent_capacity()
+            .into_concurrent_filled_with(new_word);
+        let written = Self {
+            words,
+            growing: false.into(),
+            #[cfg(loom)]
+            words_growth: loom::cell::UnsafeCell::new(()),
+        };
+        written.grow_to(capacity);
+        // never waits since the words covering the capacity are allocated above
+        written.mark(0..written_len, SpinStrategy::Spin);
+        written
+    }
+
+    /// Makes sure that the bitmap covers all positions of a collection with the given `capacity`.
+    ///
+    /// The method can be called concurrently: only the thread acquiring the growing flag grows the words,
+    /// while the other threads wait until either the words cover their capacity or they acquire the flag.
+    pub(crate) fn grow_to(&self, capacity: usize) {
+        let num_words = capacity.div_ceil(BITS);
+        while self.words.capacity() < num_words {
+            match self
+                .growing
+                .compare_exchange(false, true, Ordering::Acquire, Ordering::Relaxed)
+            {
+                Ok(_) => {
+                    #[cfg(loom)]
+                    self.words_growth.with_mut(|_| ());
+                    if self.words.capacity() < num_words {
+                        let _ = self
+                            .words
+                            .grow_to_and_fill_with(num_words, new_word)
+                            .expect("bitmap of written positions failed to grow");
+                    }
+                    self.growing.store(false, Ordering::Release);
+                }
+                Err(_) => spin_loop(),
+            }
+        }
+    }
+
+    /// Marks all positions in the `range` as written.
+    ///
+    /// If the range is beyond the allocated words, the call waits following the `spin_strategy` until the bitmap grows,
+    /// which happens immediately after the collection grows.
+    pub(crate) fn mark(&self, range: Range<usize>, spin_strategy: SpinStrategy) {
+        for (w, mask) in word_masks(range) {
+            self.wait_for_word(w, spin_strategy)
+                .fetch_or(mask, Ordering::AcqRel);
+        }
+    }
+
+    /// Marks all positions in the `range` as written;
+    /// returns the first position of the range which was already marked, if any.
+    #[cfg(feature = "debug_checks")]
+    pub(crate) fn try_mark(
+        &self,
+        range: Range<usize>,
+        spin_strategy: SpinStrategy,
+    ) -> Result<(), usize> {
+        let mut first_marked = None;
+        for (w, mask) in word_masks(range) {
+            let prior = self
+                .wait_for_word(w, spin_strategy)
+                .fetch_or(mask, Ordering::AcqRel);
+            if prior & mask != 0 && first_marked.is_none() {
+                first_marked = Some(w * BITS + (prior & mask).trailing_zeros() as usize);
+            }
+        }
+        match first_marked {
+            None => Ok(()),
+            Some(idx) => Err(idx),
+        }
+    }
+
+    /// Marks all positions in the `range` as not written.
+    pub(crate) fn unmark(&self, range: Range<usize>) {
+        for (w, mask) in word_masks(range) {
+            if let Some(word) = self.word(w) {
+                word.fetch_and(!mask, Ordering::AcqRel);
+            }
+        }
+    }
+
+    /// Returns whether or not the position `idx` is marked as written.
+    pub(crate) fn is_marked(&self, idx: usize) -> bool {
+        match self.word(idx / BITS) {
+            Some(word) => word.load(Ordering::Acquire) & (1 << (idx % BITS)) != 0,
+            None => false,
+        }
+    }
+
+    /// Returns the first position within the `range` which is not marked as written;
+    /// returns the end of the range if all positions are marked.
+    pub(crate) fn first_unmarked(&self, range: Range<usize>) -> usize {
+        let mut idx = range.start;
+        while idx < range.end {
+            let Some(word) = self.word(idx / BITS) else {
+                return idx;
+            };
+            let offset = idx % BITS;
+            let num_available = BITS - offset;
+            let num_marked = ((word.load(Ordering::Acquire) >> offset).trailing_ones() as usize)
+                .min(num_available);
+            idx += num_marked;
+            if num_marked < num_available {
+                return idx.min(range.end);
+            }
+        }
+        range.end
+    }
+
+    /// Returns an iterator over the positions within the `range` which are marked as written.
+    pub(crate) fn marked(&self, range: Range<usize>) -> impl Iterator<Item = usize> + '_ {
+        range.filter(|idx| self.is_marked(*idx))
+    }
+
+    /// Marks all positions as not written.
+    pub(crate) fn clear(&mut self) {
+        for w in 0..self.words.capacity() {
+            if let Some(word) = self.word(w) {
+                word.store(0, Ordering::Relaxed);
+            }
+        }
+    }
+
+    fn word(&self, w: usize) -> Option<&AtomicUsize> {
+        // SAFETY: all allocated words are filled with zero
+        unsafe { self.words.get(w) }
+    }
+
+    fn wait_for_word(&self, w: usize, spin_strategy: SpinStrategy) -> &AtomicUsize {
+        let mut backoff = spin_strategy.backoff();
+        loop {
+            match self.word(w) {
+                Some(word) => return word,
+                None => backoff.spin(),
+            }
+        }
+    }
+}
+
+fn new_word() -> AtomicUsize {
+    AtomicUsize::new(0)
+}
+
+/// Returns the words covering the `range` together with the masks of the positions of the range within each word.
+fn word_masks(range: Range<usize>) -> impl Iterator<Item = (usize, usize)> {
+    let first_word = range.start / BITS;
+    let end_word = range.end.div_ceil(BITS);
+    let range_of_words = match range.is_empty() {
+        true => 0..0,
+        false => first_word..end_word,
+    };
+    range_of_words.map(move |w| {
+        let word_begin = w * BITS;
+        let begin = range.start.max(word_begin) - word_begin;
+        let end = range.end.min(word_begin + BITS) - word_begin;
+        let mask = match end - begin {
+            BITS => usize::MAX,
+            len => ((1 << len) - 1) << begin,
+        };
+        (w, mask)
+    })
+}
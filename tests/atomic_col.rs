@@ -0,0 +1,106 @@
+mod common;
+
+use common::{NUM_ITEMS_PER_THREAD, NUM_THREADS};
+use orx_pinned_concurrent_col::*;
+use orx_pinned_vec::ConcurrentPinnedVec;
+use std::sync::atomic::{AtomicI32, AtomicU8, AtomicUsize, Ordering};
+
+#[test]
+fn new() {
+    let col: ConcurrentAtomicCol<AtomicUsize> = ConcurrentAtomicCol::new();
+    assert_eq!(col.load(0), 0);
+    assert!(col.get(1000).is_none());
+
+    let col: ConcurrentAtomicCol<AtomicUsize, _> = ConcurrentAtomicCol::with_linear_growth(4, 10);
+    assert_eq!(col.maximum_capacity(), 160);
+
+    let col: ConcurrentAtomicCol<AtomicUsize, _> = ConcurrentAtomicCol::with_fixed_capacity(42);
+    assert_eq!(col.capacity(), 42);
+    assert!(col.iter().all(|x| x.load(Ordering::Relaxed) == 0));
+}
+
+fn update_in_place<P: ConcurrentPinnedVec<AtomicI32>>(col: ConcurrentAtomicCol<AtomicI32, P>) {
+    assert_eq!(col.fetch_add(70, 5), 0);
+    assert_eq!(col.fetch_sub(70, 7), 5);
+    assert_eq!(col.load(70), -2);
+    assert!(col.capacity() > 70);
+
+    col.store(3, 10);
+    assert_eq!(col.swap(3, 11), 10);
+    assert_eq!(col.compare_exchange(3, 10, 12), Err(11));
+    assert_eq!(col.compare_exchange(3, 11, 12), Ok(11));
+    assert_eq!(col.fetch_update(3, |x| Some(x * 2)), Ok(12));
+    assert_eq!(col.fetch_update(3, |x| (x < 0).then_some(0)), Err(24));
+    assert_eq!(col.atomic(3).load(Ordering::Relaxed), 24);
+
+    for idx in (0..col.capacity()).filter(|i| ![3, 70].contains(i)) {
+        assert_eq!(col.load(idx), 0);
+    }
+}
+
+#[test]
+fn update_in_place_doubling() {
+    update_in_place(ConcurrentAtomicCol::with_doubling_growth());
+}
+
+#[test]
+fn update_in_place_linear() {
+    update_in_place(ConcurrentAtomicCol::with_linear_growth(2, 64));
+}
+
+#[test]
+fn update_in_place_fixed() {
+    update_in_place(ConcurrentAtomicCol::with_fixed_capacity(100));
+}
+
+#[test]
+fn try_atomic_out_of_maximum_capacity() {
+    let col: ConcurrentAtomicCol<AtomicU8, _> = ConcurrentAtomicCol::with_fixed_capacity(4);
+    assert!(col.try_atomic(3).is_ok());
+    assert_eq!(
+        col.try_atomic(4).err(),
+        Some(PinnedConcurrentColError::OutOfMaximumCapacity {
+            required_capacity: 5,
+            maximum_capacity: 4
+        })
+    );
+    assert_eq!(col.load(4), 0);
+}
+
+#[test]
+#[should_panic]
+fn fetch_add_out_of_maximum_capacity() {
+    let col: ConcurrentAtomicCol<AtomicU8, _> = ConcurrentAtomicCol::with_fixed_capacity(4);
+    col.fetch_add(4, 1);
+}
+
+#[test]
+fn concurrent_counters() {
+    let col: ConcurrentAtomicCol<AtomicUsize, _> = ConcurrentAtomicCol::with_linear_growth(3, 1024);
+    let num_counters = NUM_ITEMS_PER_THREAD / 4;
+
+    std::thread::scope(|s| {
+        for t in 0..NUM_THREADS {
+            let col = &col;
+            s.spawn(move || {
+                for i in 0..NUM_ITEMS_PER_THREAD {
+                    // threads grow the collection from different ends
+                    let idx = match t % 2 {
+                        0 => i % num_counters,
+                        _ => num_counters - 1 - i % num_counters,
+                    };
+                    col.fetch_add(idx, 1);
+                    let _ = col.fetch_update(num_counters, |x| Some(x.max(idx)));
+                }
+            });
+        }
+    });
+
+    for idx in 0..num_counters {
+        assert_eq!(
+            col.load(idx),
+            NUM_THREADS * NUM_ITEMS_PER_THREAD / num_counters
+        );
+    }
+    assert_eq!(col.load(num_counters), num_counters - 1);
+}
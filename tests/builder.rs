@@ -0,0 +1,196 @@
+mod state;
+
+use orx_pinned_concurrent_col::states::*;
+use orx_pinned_concurrent_col::*;
+use orx_pinned_vec::PinnedVec;
+use orx_split_vec::SplitVec;
+use state::MyConState;
+
+#[test]
+fn build_default() {
+    let col: PinnedConcurrentCol<String, _, MyConState<_>> = PinnedConcurrentColBuilder::new()
+        .build()
+        .expect("default configuration is valid");
+
+    let reference: PinnedConcurrentCol<String, _, MyConState<_>> =
+        PinnedConcurrentCol::with_doubling_growth();
+    assert_eq!(col.capacity(), reference.capacity());
+    assert_eq!(col.maximum_capacity(), reference.maximum_capacity());
+    assert_eq!(col.spin_strategy(), reference.spin_strategy());
+    assert!(col.fill_memory_with().is_none());
+}
+
+#[test]
+fn build_storage() {
+    let col: PinnedConcurrentCol<String, _, MyConState<_>> = PinnedConcurrentColBuilder::new()
+        .with_linear_growth(4, 10)
+        .build()
+        .expect("valid configuration");
+    assert_eq!(col.capacity(), 16);
+    assert_eq!(col.maximum_capacity(), 160);
+
+    let col: PinnedConcurrentCol<String, _, MyConState<_>> = PinnedConcurrentColBuilder::new()
+        .with_fixed_capacity(42)
+        .build()
+        .expect("valid configuration");
+    assert_eq!(col.capacity(), 42);
+    assert_eq!(col.maximum_capacity(), 42);
+
+    let mut vec = SplitVec::with_doubling_growth_and_fragments_capacity(16);
+    vec.push("a".to_string());
+    vec.push("b".to_string());
+    let col: PinnedConcurrentCol<String, _, MyConState<_>> = PinnedConcurrentColBuilder::new()
+        .with_pinned_vec(vec)
+        .initial_capacity(100)
+        .build()
+        .expect("valid configuration");
+    assert_eq!(col.state().initial_len, 2);
+    assert_eq!(col.capacity(), 4 + 8 + 16 + 32 + 64);
+    assert_eq!(unsafe { col.get(1) }, Some(&"b".to_string()));
+
+    col.state().set_final_len(2);
+}
+
+#[test]
+fn build_initial_capacity() {
+    let col: PinnedConcurrentCol<String, _, MyConState<_>> = PinnedConcurrentColBuilder::new()
+        .initial_capacity(100)
+        .build()
+        .expect("valid configuration");
+    assert_eq!(col.capacity(), 4 + 8 + 16 + 32 + 64);
+    assert_eq!(col.state().initial_cap, 4 + 8 + 16 + 32 + 64);
+
+    let col: PinnedConcurrentCol<String, _, MyConState<_>> = PinnedConcurrentColBuilder::new()
+        .with_linear_growth(4, 10)
+        .initial_capacity(33)
+        .build()
+        .expect("valid configuration");
+    assert_eq!(col.capacity(), 48);
+
+    let result: Result<PinnedConcurrentCol<String, _, MyConState<_>>, _> =
+        PinnedConcurrentColBuilder::new()
+            .with_fixed_capacity(42)
+            .initial_capacity(43)
+            .build();
+    assert_eq!(
+        result.err(),
+        Some(PinnedConcurrentColError::OutOfMaximumCapacity {
+            required_capacity: 43,
+            maximum_capacity: 42
+        })
+    );
+}
+
+#[test]
+fn build_maximum_capacity() {
+    let col: PinnedConcurrentCol<String, _, MyConState<_>> = PinnedConcurrentColBuilder::new()
+        .with_linear_growth(4, 10)
+        .maximum_capacity(1000)
+        .initial_capacity(500)
+        .build()
+        .expect("linear growth can reserve maximum capacity");
+    assert!(col.maximum_capacity() >= 1000);
+    assert_eq!(col.capacity(), 512);
+
+    let col: PinnedConcurrentCol<String, _, MyConState<_>> = PinnedConcurrentColBuilder::new()
+        .with_fixed_capacity(42)
+        .maximum_capacity(40)
+        .build()
+        .expect("fixed capacity is sufficient");
+    assert_eq!(col.maximum_capacity(), 42);
+
+    let result: Result<PinnedConcurrentCol<String, _, MyConState<_>>, _> =
+        PinnedConcurrentColBuilder::new()
+            .with_fixed_capacity(42)
+            .maximum_capacity(43)
+            .build();
+    assert_eq!(
+        result.err(),
+        Some(PinnedConcurrentColError::OutOfMaximumCapacity {
+            required_capacity: 43,
+            maximum_capacity: 42
+        })
+    );
+}
+
+#[test]
+fn build_memory_fill() {
+    let col: PinnedConcurrentCol<Option<String>, _, FilledState<_>> =
+        PinnedConcurrentColBuilder::new()
+            .initial_capacity(10)
+            .build()
+            .expect("valid configuration");
+    assert!(col.fill_memory_with().is_some());
+    for idx in 0..col.capacity() {
+        assert_eq!(unsafe { col.get(idx) }, Some(&None));
+    }
+
+    let col: PinnedConcurrentCol<Option<String>, _, FilledState<_>> =
+        PinnedConcurrentColBuilder::new()
+            .with_fixed_capacity(20)
+            .fill_memory_with(|| Some("x".to_string()))
+            .build()
+            .expect("valid configuration");
+    for idx in 0..20 {
+        assert_eq!(unsafe { col.get(idx) }, Some(&Some("x".to_string())));
+    }
+
+    let col: PinnedConcurrentCol<u64, _, MyConState<_>> = PinnedConcurrentColBuilder::new()
+        .with_fixed_capacity(20)
+        .fill_memory_with(|| 7)
+        .build()
+        .expect("valid configuration");
+    assert!(col.fill_memory_with().is_some());
+    let vec = unsafe { col.into_inner(3) };
+    assert_eq!(vec.len(), 3);
+    assert_eq!(vec.get(2), Some(&7));
+
+    let result: Result<PinnedConcurrentCol<Option<String>, _, FilledState<_>>, _> =
+        PinnedConcurrentColBuilder::new()
+            .without_memory_fill()
+            .build();
+    assert_eq!(
+        result.err(),
+        Some(PinnedConcurrentColError::MissingMemoryFill)
+    );
+
+    let col: PinnedConcurrentCol<String, _, MyConState<_>> = PinnedConcurrentColBuilder::new()
+        .without_memory_fill()
+        .build()
+        .expect("state does not require memory fill");
+    assert!(col.fill_memory_with().is_none());
+}
+
+#[test]
+fn build_spin_strategy() {
+    let col: PinnedConcurrentCol<String, _, MyConState<_>> = PinnedConcurrentColBuilder::new()
+        .spin_strategy(SpinStrategy::ExponentialBackoff { max_exponent: 3 })
+        .build()
+        .expect("valid configuration");
+    assert_eq!(
+        col.spin_strategy(),
+        SpinStrategy::ExponentialBackoff { max_exponent: 3 }
+    );
+    assert_eq!(
+        ConcurrentState::<String>::spin_strategy(col.state()),
+        SpinStrategy::Spin
+    );
+}
+
+#[test]
+fn build_and_write() {
+    let col: PinnedConcurrentCol<String, _, OrderedState<_>> = PinnedConcurrentColBuilder::new()
+        .with_linear_growth(3, 8)
+        .maximum_capacity(300)
+        .initial_capacity(16)
+        .build()
+        .expect("valid configuration");
+
+    for idx in 0..300 {
+        unsafe { col.write(idx, idx.to_string()) };
+    }
+    let vec = col.try_into_inner(300).expect("all positions are written");
+    for idx in 0..300 {
+        assert_eq!(vec.get(idx), Some(&idx.to_string()));
+    }
+}
@@ -0,0 +1,170 @@
+mod common;
+
+use common::{NUM_ITEMS_PER_THREAD, NUM_THREADS, SyncCol};
+use orx_fixed_vec::FixedVec;
+use orx_pinned_concurrent_col::states::*;
+use orx_pinned_concurrent_col::*;
+use orx_pinned_vec::PinnedVec;
+use orx_split_vec::SplitVec;
+use prelude::IntoConcurrentPinnedVec;
+use test_case::test_matrix;
+
+#[test]
+fn committed_len_not_maintained_by_default() {
+    let col: PinnedConcurrentCol<String, _, AppendOnlyState<_>> =
+        PinnedConcurrentCol::with_doubling_growth();
+    unsafe { col.write(0, 0.to_string()) };
+
+    assert_eq!(col.committed_len(), None);
+    assert_eq!(col.get_committed(0), None);
+    assert_eq!(col.iter_committed().count(), 0);
+
+    unsafe { col.commit_range(0..1) };
+    assert_eq!(col.committed_len(), None);
+    col.state().fetch_increment_len(1);
+}
+
+#[test]
+fn committed_len_of_existing_elements() {
+    let mut vec = SplitVec::with_doubling_growth_and_max_concurrent_capacity();
+    vec.extend_from_slice(&[0.to_string(), 1.to_string(), 2.to_string()]);
+    let col: PinnedConcurrentCol<_, _, OrderedState<_>> = PinnedConcurrentCol::new_from_pinned(vec);
+
+    assert_eq!(col.committed_len(), Some(3));
+    assert_eq!(col.get_committed(2), Some(&2.to_string()));
+    assert_eq!(col.get_committed(3), None);
+
+    let clone = unsafe { col.clone_with_len(2) };
+    assert_eq!(clone.committed_len(), Some(2));
+}
+
+#[test_matrix([
+    FixedVec::new(100),
+    SplitVec::with_doubling_growth_and_fragments_capacity(16),
+    SplitVec::with_linear_growth_and_fragments_capacity(2, 64)
+])]
+fn committed_len_advances_out_of_order<P: IntoConcurrentPinnedVec<String>>(vec: P) {
+    let col: PinnedConcurrentCol<_, _, OrderedState<_>> = PinnedConcurrentCol::new_from_pinned(vec);
+    assert_eq!(col.committed_len(), Some(0));
+
+    unsafe { col.write(2, 2.to_string()) };
+    assert_eq!(col.committed_len(), Some(0));
+
+    unsafe { col.write(0, 0.to_string()) };
+    assert_eq!(col.committed_len(), Some(1));
+
+    unsafe { col.write_n_items(3, 7, (3..10).map(|i| i.to_string())) };
+    assert_eq!(col.committed_len(), Some(1));
+    assert_eq!(col.get_committed(3), None);
+
+    unsafe { col.write(1, 1.to_string()) };
+    assert_eq!(col.committed_len(), Some(10));
+
+    let committed: Vec<_> = col.iter_committed().cloned().collect();
+    assert_eq!(
+        committed,
+        (0..10).map(|i| i.to_string()).collect::<Vec<_>>()
+    );
+    assert_eq!(col.get_committed(9), Some(&9.to_string()));
+    assert_eq!(col.get_committed(10), None);
+}
+
+#[test]
+fn failed_writes_are_not_committed() {
+    let col: PinnedConcurrentCol<String, _, OrderedState<_>> =
+        PinnedConcurrentCol::with_doubling_growth();
+
+    let result = unsafe { col.try_write_n_items(0, 3, (0..2).map(|i| i.to_string())) };
+    assert!(result.is_err());
+    assert_eq!(col.committed_len(), Some(0));
+
+    let mut reservation = unsafe { col.reserve_n_items(0, 3) };
+    assert_eq!(reservation.push(0.to_string()), Ok(()));
+    assert_eq!(
+        reservation.finish(),
+        ReservationOutcome::Partial { num_initialized: 1 }
+    );
+    assert_eq!(col.committed_len(), Some(1));
+
+    unsafe { col.write_n_items(1, 2, (1..3).map(|i| i.to_string())) };
+    assert_eq!(col.committed_len(), Some(3));
+}
+
+#[test]
+fn buffers_are_committed_by_commit_range() {
+    let col: PinnedConcurrentCol<String, _, OrderedState<_>> =
+        PinnedConcurrentCol::with_doubling_growth();
+
+    let slices = unsafe { col.n_items_buffer_as_mut_slices(0, 6) };
+    let mut i = 0;
+    for slice in slices {
+        for x in slice.iter_mut() {
+            unsafe { (x as *mut String).write(i.to_string()) };
+            i += 1;
+        }
+    }
+    assert_eq!(col.committed_len(), Some(0));
+
+    unsafe { col.commit_range(0..6) };
+    assert_eq!(col.committed_len(), Some(6));
+    assert_eq!(col.iter_committed().count(), 6);
+}
+
+#[test]
+fn clear_resets_committed_len() {
+    let mut col: PinnedConcurrentCol<String, _, OrderedState<_>> =
+        PinnedConcurrentCol::with_doubling_growth();
+    unsafe { col.write_n_items(0, 5, (0..5).map(|i| i.to_string())) };
+    assert_eq!(col.committed_len(), Some(5));
+
+    unsafe { col.clear(5) };
+    assert_eq!(col.committed_len(), Some(0));
+
+    unsafe { col.write(1, 1.to_string()) };
+    assert_eq!(col.committed_len(), Some(0));
+    unsafe { col.write(0, 0.to_string()) };
+    assert_eq!(col.committed_len(), Some(2));
+}
+
+#[test_matrix([
+    FixedVec::new(NUM_THREADS * NUM_ITEMS_PER_THREAD),
+    SplitVec::with_doubling_growth_and_fragments_capacity(32),
+    SplitVec::with_linear_growth_and_fragments_capacity(6, 8192)
+])]
+fn concurrent_out_of_order_writes_with_committed_reader<P: IntoConcurrentPinnedVec<String>>(
+    vec: P,
+) {
+    let len = NUM_THREADS * NUM_ITEMS_PER_THREAD;
+    let col: PinnedConcurrentCol<_, _, OrderedState<_>> = PinnedConcurrentCol::new_from_pinned(vec);
+    let col = SyncCol(col);
+
+    std::thread::scope(|s| {
+        // threads write interleaved positions in reverse order, so that the prefix completes late
+        for t in 0..NUM_THREADS {
+            let col = &col;
+            s.spawn(move || {
+                for j in (0..NUM_ITEMS_PER_THREAD).rev() {
+                    let idx = j * NUM_THREADS + t;
+                    unsafe { col.0.write(idx, idx.to_string()) };
+                }
+            });
+        }
+
+        let col = &col;
+        s.spawn(move || {
+            let mut prior_len = 0;
+            while prior_len < len {
+                let committed_len = col.0.committed_len().expect("is maintained");
+                assert!(committed_len >= prior_len);
+                for (i, x) in col.0.iter_committed().enumerate() {
+                    assert_eq!(x, &i.to_string());
+                }
+                prior_len = committed_len;
+            }
+        });
+    });
+
+    assert_eq!(col.0.committed_len(), Some(len));
+    let vec = col.0.try_into_inner(len).expect("is gap-free");
+    assert_eq!(vec.len(), len);
+}
@@ -0,0 +1,59 @@
+#![allow(dead_code)]
+
+use orx_fixed_vec::FixedVec;
+use orx_split_vec::{Doubling, Linear, SplitVec};
+use std::sync::{
+    Arc,
+    atomic::{AtomicUsize, Ordering},
+};
+
+#[cfg(not(miri))]
+pub const NUM_ITEMS_PER_THREAD: usize = 512;
+#[cfg(miri)]
+pub const NUM_ITEMS_PER_THREAD: usize = 16;
+
+pub const NUM_THREADS: usize = 4;
+
+/// Wrapper sharing a collection among the threads of a test without requiring it to be `Sync`.
+pub struct SyncCol<C>(pub C);
+
+unsafe impl<C> Sync for SyncCol<C> {}
+
+unsafe impl<C> Send for SyncCol<C> {}
+
+/// Fixed capacity vector large enough for the concurrent tests.
+pub fn fixed_vec<T>() -> FixedVec<T> {
+    FixedVec::new(4096)
+}
+
+/// Doubling split vector with small fragments so that the concurrent tests grow it many times.
+pub fn doubling_vec<T>() -> SplitVec<T, Doubling> {
+    SplitVec::with_doubling_growth_and_fragments_capacity(16)
+}
+
+/// Linear split vector with small fragments so that the concurrent tests grow it many times.
+pub fn linear_vec<T>() -> SplitVec<T, Linear> {
+    SplitVec::with_linear_growth_and_fragments_capacity(4, 256)
+}
+
+/// Value counting the number of times the values sharing the counter are dropped.
+#[derive(Clone, Debug, Default)]
+pub struct DropCounter {
+    pub value: usize,
+    num_dropped: Arc<AtomicUsize>,
+}
+
+impl DropCounter {
+    pub fn new(value: usize, num_dropped: &Arc<AtomicUsize>) -> Self {
+        Self {
+            value,
+            num_dropped: num_dropped.clone(),
+        }
+    }
+}
+
+impl Drop for DropCounter {
+    fn drop(&mut self) {
+        self.num_dropped.fetch_add(1, Ordering::Relaxed);
+    }
+}
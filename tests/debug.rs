@@ -6,6 +6,25 @@ use orx_split_vec::SplitVec;
 use prelude::FixedVec;
 use state::MyConState;
 
+/// Asserts the debug output; with the `metrics` feature, the output additionally ends with the stats,
+/// whose durations are not deterministic, see tests/metrics.rs for the stats.
+fn assert_debug(debug: &str, expected: &str) {
+    #[cfg(not(feature = "metrics"))]
+    assert_eq!(debug, expected);
+
+    #[cfg(feature = "metrics")]
+    {
+        let expected = expected
+            .strip_suffix(" }")
+            .expect("debug output of a struct");
+        let expected = format!("{}, stats: ColStats {{ ", expected);
+        assert!(
+            debug.starts_with(&expected),
+            "{debug} does not start with {expected}"
+        );
+    }
+}
+
 #[test]
 fn debug_split_doubling() {
     let mut vec: SplitVec<usize> = SplitVec::with_doubling_growth_and_max_concurrent_capacity();
@@ -26,7 +45,7 @@ fn debug_split_doubling() {
     #[cfg(target_pointer_width = "64")]
     let expected = "PinnedConcurrentCol { state: MyConState { initial_len: 187, initial_cap: 252, len: 187, phantom: PhantomData<usize> }, capacity: 508, maximum_capacity: 17179869180 }";
 
-    assert_eq!(debug, expected);
+    assert_debug(&debug, expected);
 }
 
 #[test]
@@ -45,7 +64,7 @@ fn debug_split_linear() {
     let debug = format!("{:?}", col);
     let expected = "PinnedConcurrentCol { state: MyConState { initial_len: 187, initial_cap: 1024, len: 187, phantom: PhantomData<usize> }, capacity: 2048, maximum_capacity: 32768 }";
 
-    assert_eq!(debug, expected);
+    assert_debug(&debug, expected);
 }
 
 #[test]
@@ -64,5 +83,5 @@ fn debug_fixed() {
     let debug = format!("{:?}", col);
     let expected = "PinnedConcurrentCol { state: MyConState { initial_len: 187, initial_cap: 333, len: 187, phantom: PhantomData<usize> }, capacity: 333, maximum_capacity: 333 }";
 
-    assert_eq!(debug, expected);
+    assert_debug(&debug, expected);
 }
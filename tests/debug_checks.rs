@@ -0,0 +1,134 @@
+#![cfg(feature = "debug_checks")]
+
+mod common;
+mod state;
+
+use common::{NUM_ITEMS_PER_THREAD, NUM_THREADS, SyncCol};
+use orx_pinned_concurrent_col::states::*;
+use orx_pinned_concurrent_col::*;
+use orx_pinned_vec::PinnedVec;
+use orx_split_vec::{Linear, SplitVec};
+use state::MyConState;
+
+#[test]
+fn valid_usage_does_not_panic() {
+    let mut vec = SplitVec::with_doubling_growth_and_max_concurrent_capacity();
+    vec.push("0".to_string());
+    let col: PinnedConcurrentCol<String, _, OrderedState<_>> =
+        PinnedConcurrentCol::new_from_pinned(vec);
+
+    unsafe { col.write(1, 1.to_string()) };
+    unsafe { col.write_n_items(2, 30, (2..32).map(|x| x.to_string())) };
+    assert_eq!(unsafe { col.get(0) }, Some(&0.to_string()));
+    assert_eq!(unsafe { col.iter(32) }.count(), 32);
+    assert_eq!(unsafe { col.iter_over_range(10..20) }.count(), 10);
+    assert_eq!(unsafe { col.get(1000) }, None);
+
+    let vec = unsafe { col.into_inner(32) };
+    assert_eq!(vec.len(), 32);
+}
+
+#[test]
+fn concurrent_growth_without_growth_handle_does_not_panic() {
+    // fragments of two positions so that the writers grow the collection concurrently across many fragment boundaries
+    let num_items = NUM_THREADS * NUM_ITEMS_PER_THREAD;
+    let vec: SplitVec<String, Linear> =
+        SplitVec::with_linear_growth_and_fragments_capacity(1, num_items);
+    let col: PinnedConcurrentCol<_, _, AppendOnlyState<_>> =
+        PinnedConcurrentCol::new_from_pinned(vec);
+    let col = SyncCol(col);
+    let shared = &col;
+
+    std::thread::scope(|s| {
+        for _ in 0..NUM_THREADS {
+            s.spawn(move || {
+                let col = &shared.0;
+                for _ in 0..NUM_ITEMS_PER_THREAD {
+                    let idx = col.state().fetch_increment_len(1);
+                    unsafe { col.write(idx, idx.to_string()) };
+                }
+            });
+        }
+    });
+
+    let vec = unsafe { col.0.into_inner(num_items) };
+    assert_eq!(vec.len(), num_items);
+}
+
+#[test]
+fn rolled_back_positions_can_be_written_again() {
+    let col: PinnedConcurrentCol<String, _, OrderedState<_>> =
+        PinnedConcurrentCol::with_doubling_growth();
+
+    let result = unsafe { col.try_write_n_items(0, 10, (0..4).map(|x| x.to_string())) };
+    assert!(result.is_err());
+    unsafe { col.write_n_items(0, 10, (0..10).map(|x| x.to_string())) };
+
+    let mut reservation = unsafe { col.reserve_n_items(10, 5) };
+    assert!(reservation.push(10.to_string()).is_ok());
+    drop(reservation);
+    unsafe { col.write_n_items(11, 4, (11..15).map(|x| x.to_string())) };
+
+    let vec = col.try_into_inner(15).expect("all positions are written");
+    assert_eq!(vec.len(), 15);
+}
+
+#[test]
+fn filled_memory_can_be_read() {
+    let col: PinnedConcurrentCol<Option<String>, _, FilledState<_>> =
+        PinnedConcurrentCol::with_doubling_growth();
+    unsafe { col.write(2, Some(2.to_string())) };
+    assert_eq!(unsafe { col.get(1) }, Some(&None));
+    let vec = unsafe { col.into_inner(3) };
+    assert_eq!(vec.len(), 3);
+}
+
+#[test]
+#[should_panic(
+    expected = "debug_checks: position 3 of the write request 3..4 is written more than once"
+)]
+fn double_write() {
+    let col: PinnedConcurrentCol<String, _, OrderedState<_>> =
+        PinnedConcurrentCol::with_doubling_growth();
+    unsafe { col.write(3, "a".to_string()) };
+    unsafe { col.write(3, "b".to_string()) };
+}
+
+#[test]
+#[should_panic(
+    expected = "debug_checks: position 70 of the write request 70..80 is written more than once"
+)]
+fn overlapping_write_n_items() {
+    let col: PinnedConcurrentCol<usize, _, OrderedState<_>> =
+        PinnedConcurrentCol::with_doubling_growth();
+    unsafe { col.write_n_items(50, 21, 50..71) };
+    unsafe { col.write_n_items(70, 10, 70..80) };
+}
+
+#[test]
+#[should_panic(expected = "debug_checks: position 1 is read before it is written")]
+fn get_unwritten() {
+    let col: PinnedConcurrentCol<String, _, OrderedState<_>> =
+        PinnedConcurrentCol::with_doubling_growth();
+    unsafe { col.write(0, "a".to_string()) };
+    unsafe { col.write(2, "c".to_string()) };
+    let _ = unsafe { col.get(1) };
+}
+
+#[test]
+#[should_panic(expected = "debug_checks: position 6 is read before it is written")]
+fn iter_unwritten() {
+    let col: PinnedConcurrentCol<usize, _, MyConState<_>> =
+        PinnedConcurrentCol::with_doubling_growth();
+    unsafe { col.write_n_items(0, 6, 0..6) };
+    let _ = unsafe { col.iter(7) }.count();
+}
+
+#[test]
+#[should_panic(expected = "debug_checks: position 7 below the length 10 is never written")]
+fn into_inner_with_unwritten_positions() {
+    let col: PinnedConcurrentCol<usize, _, MyConState<_>> =
+        PinnedConcurrentCol::with_doubling_growth();
+    unsafe { col.write_n_items(0, 7, 0..7) };
+    let _ = unsafe { col.into_inner(10) };
+}
@@ -0,0 +1,192 @@
+mod common;
+mod state;
+
+use common::{DropCounter, NUM_ITEMS_PER_THREAD, NUM_THREADS, SyncCol};
+use orx_fixed_vec::FixedVec;
+use orx_pinned_concurrent_col::states::*;
+use orx_pinned_concurrent_col::*;
+use orx_split_vec::SplitVec;
+use prelude::IntoConcurrentPinnedVec;
+use state::MyConStateCommitted;
+use std::sync::{
+    Arc,
+    atomic::{AtomicUsize, Ordering},
+};
+use test_case::test_matrix;
+
+#[test]
+fn drain_without_committed_len() {
+    let col: PinnedConcurrentCol<String, _, AppendOnlyState<_>> =
+        PinnedConcurrentCol::with_doubling_growth();
+    unsafe { col.write(0, 0.to_string()) };
+    col.state().fetch_increment_len(1);
+
+    assert_eq!(unsafe { col.drain_committed() }, Vec::<String>::new());
+    assert_eq!(col.consumed_len(), None);
+}
+
+#[test_matrix([
+    FixedVec::new(100),
+    SplitVec::with_doubling_growth_and_fragments_capacity(16),
+    SplitVec::with_linear_growth_and_fragments_capacity(2, 64)
+])]
+fn drain_committed_prefix<P: IntoConcurrentPinnedVec<String>>(vec: P) {
+    let col: PinnedConcurrentCol<_, _, OrderedState<_>> = PinnedConcurrentCol::new_from_pinned(vec);
+
+    unsafe { col.write(1, 1.to_string()) };
+    assert_eq!(unsafe { col.drain_committed() }, Vec::<String>::new());
+
+    unsafe { col.write(0, 0.to_string()) };
+    assert_eq!(
+        unsafe { col.drain_committed() },
+        [0.to_string(), 1.to_string()]
+    );
+    assert_eq!(col.consumed_len(), Some(2));
+    assert_eq!(col.committed_len(), Some(2));
+    assert_eq!(col.get_committed(1), None);
+    assert_eq!(col.iter_committed().count(), 0);
+    assert_eq!(col.is_written(1), Some(false));
+
+    unsafe { col.write_n_items(2, 8, (2..10).map(|i| i.to_string())) };
+    assert_eq!(col.get_committed(2), Some(&2.to_string()));
+    assert_eq!(col.iter_committed().count(), 8);
+
+    let mut drained = vec![];
+    let num_drained = unsafe { col.drain_committed_with(|x| drained.push(x)) };
+    assert_eq!(num_drained, 8);
+    assert_eq!(drained, (2..10).map(|i| i.to_string()).collect::<Vec<_>>());
+    assert_eq!(col.consumed_len(), Some(10));
+
+    assert!(col.try_into_inner(10).is_err());
+}
+
+#[test]
+fn drain_tracked_drops_once() {
+    let num_dropped = Arc::new(AtomicUsize::new(0));
+    let col: PinnedConcurrentCol<_, _, OrderedState<_>> =
+        PinnedConcurrentCol::with_doubling_growth();
+    for i in [0, 1, 2, 4] {
+        unsafe { col.write(i, DropCounter::new(i, &num_dropped)) };
+    }
+
+    let drained = unsafe { col.drain_committed() };
+    assert_eq!(
+        drained.iter().map(|x| x.value).collect::<Vec<_>>(),
+        [0, 1, 2]
+    );
+    assert_eq!(num_dropped.load(Ordering::Relaxed), 0);
+
+    drop(drained);
+    assert_eq!(num_dropped.load(Ordering::Relaxed), 3);
+
+    drop(col);
+    assert_eq!(num_dropped.load(Ordering::Relaxed), 4);
+}
+
+#[test]
+fn drain_filled_drops_once() {
+    let num_dropped = Arc::new(AtomicUsize::new(0));
+    let col: PinnedConcurrentCol<Option<DropCounter>, _, OrderedState<_>> =
+        PinnedConcurrentColBuilder::new()
+            .fill_memory_with_default()
+            .build()
+            .expect("valid configuration");
+    for i in 0..3 {
+        unsafe { col.write(i, Some(DropCounter::new(i, &num_dropped))) };
+    }
+
+    let drained = unsafe { col.drain_committed() };
+    assert_eq!(drained.len(), 3);
+    assert_eq!(unsafe { col.get(0) }.map(|x| x.is_none()), Some(true));
+
+    drop(drained);
+    drop(col);
+    assert_eq!(num_dropped.load(Ordering::Relaxed), 3);
+}
+
+#[test]
+fn drain_untracked_drops_once() {
+    let num_dropped = Arc::new(AtomicUsize::new(0));
+    let col: PinnedConcurrentCol<_, _, MyConStateCommitted<_>> =
+        PinnedConcurrentCol::with_doubling_growth();
+    for i in 0..5 {
+        unsafe { col.write(i, DropCounter::new(i, &num_dropped)) };
+    }
+
+    let drained = unsafe { col.drain_committed() };
+    assert_eq!(drained.len(), 5);
+    drop(drained);
+
+    for i in 5..7 {
+        unsafe { col.write(i, DropCounter::new(i, &num_dropped)) };
+    }
+    assert!(col.try_into_inner(7).is_err());
+    assert_eq!(num_dropped.load(Ordering::Relaxed), 7);
+}
+
+#[test]
+fn clear_after_drain() {
+    let num_dropped = Arc::new(AtomicUsize::new(0));
+    let mut col: PinnedConcurrentCol<_, _, MyConStateCommitted<_>> =
+        PinnedConcurrentCol::with_doubling_growth();
+    for i in 0..5 {
+        unsafe { col.write(i, DropCounter::new(i, &num_dropped)) };
+    }
+    let num_drained = unsafe { col.drain_committed_with(drop) };
+    assert_eq!(num_drained, 5);
+    unsafe { col.write(5, DropCounter::new(5, &num_dropped)) };
+
+    unsafe { col.clear(6) };
+    assert_eq!(num_dropped.load(Ordering::Relaxed), 6);
+    assert_eq!(col.consumed_len(), Some(0));
+    assert_eq!(col.committed_len(), Some(0));
+}
+
+#[test_matrix([
+    FixedVec::new(NUM_THREADS * NUM_ITEMS_PER_THREAD),
+    SplitVec::with_doubling_growth_and_fragments_capacity(32),
+    SplitVec::with_linear_growth_and_fragments_capacity(6, 8192)
+])]
+fn drain_while_writing<P: IntoConcurrentPinnedVec<DropCounter>>(vec: P) {
+    let len = NUM_THREADS * NUM_ITEMS_PER_THREAD;
+    let num_dropped = Arc::new(AtomicUsize::new(0));
+    let col: PinnedConcurrentCol<_, _, OrderedState<_>> = PinnedConcurrentCol::new_from_pinned(vec);
+    let col = SyncCol(col);
+    let next_idx = AtomicUsize::new(0);
+
+    let mut drained = std::thread::scope(|s| {
+        let (col, next_idx, num_dropped) = (&col, &next_idx, &num_dropped);
+        for _ in 0..NUM_THREADS {
+            s.spawn(move || {
+                for _ in 0..NUM_ITEMS_PER_THREAD {
+                    let idx = next_idx.fetch_add(1, Ordering::Relaxed);
+                    unsafe { col.0.write(idx, DropCounter::new(idx, num_dropped)) };
+                }
+            });
+        }
+
+        let drainers: Vec<_> = (0..2)
+            .map(|_| {
+                s.spawn(move || {
+                    let mut drained = vec![];
+                    while col.0.consumed_len() < Some(len) {
+                        unsafe { col.0.drain_committed_with(|x| drained.push(x.value)) };
+                    }
+                    drained
+                })
+            })
+            .collect();
+
+        drainers
+            .into_iter()
+            .flat_map(|x| x.join().expect("drainer panicked"))
+            .collect::<Vec<_>>()
+    });
+
+    drained.sort();
+    assert_eq!(drained, (0..len).collect::<Vec<_>>());
+    assert_eq!(num_dropped.load(Ordering::Relaxed), len);
+
+    drop(col);
+    assert_eq!(num_dropped.load(Ordering::Relaxed), len);
+}
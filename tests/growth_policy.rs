@@ -0,0 +1,262 @@
+mod common;
+
+use common::{NUM_ITEMS_PER_THREAD, NUM_THREADS, SyncCol};
+use orx_pinned_concurrent_col::states::*;
+use orx_pinned_concurrent_col::*;
+use orx_split_vec::SplitVec;
+use prelude::IntoConcurrentPinnedVec;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use test_case::test_matrix;
+
+const GROW_AHEAD: GrowthPolicy = GrowthPolicy::GrowAhead {
+    threshold_percent: 75,
+};
+
+#[test]
+fn grows_ahead() {
+    assert!(!GrowthPolicy::OnDemand.grows_ahead(99, 0..100));
+
+    assert!(!GROW_AHEAD.grows_ahead(74, 0..100));
+    assert!(GROW_AHEAD.grows_ahead(75, 0..100));
+    assert!(GROW_AHEAD.grows_ahead(99, 0..100));
+    assert!(!GROW_AHEAD.grows_ahead(100, 0..100));
+
+    assert!(!GROW_AHEAD.grows_ahead(99, 100..200));
+    assert!(!GROW_AHEAD.grows_ahead(174, 100..200));
+    assert!(GROW_AHEAD.grows_ahead(175, 100..200));
+
+    let on_demand = GrowthPolicy::GrowAhead {
+        threshold_percent: 100,
+    };
+    assert!(!on_demand.grows_ahead(99, 0..100));
+    assert!(!on_demand.grows_ahead(100, 0..100));
+
+    let huge = usize::MAX / 2;
+    assert!(GROW_AHEAD.grows_ahead(huge - 1, 0..huge));
+}
+
+#[test]
+fn is_valid() {
+    assert!(GrowthPolicy::OnDemand.is_valid());
+    assert!(GROW_AHEAD.is_valid());
+
+    for threshold_percent in [1, 100] {
+        assert!(GrowthPolicy::GrowAhead { threshold_percent }.is_valid());
+    }
+    for threshold_percent in [0, 101, u8::MAX] {
+        assert!(!GrowthPolicy::GrowAhead { threshold_percent }.is_valid());
+    }
+}
+
+#[test]
+fn zero_threshold_is_rejected() {
+    let result = PinnedConcurrentColBuilder::<String, OrderedState<_>>::new()
+        .growth_policy(GrowthPolicy::GrowAhead {
+            threshold_percent: 0,
+        })
+        .build();
+    assert_eq!(
+        result.err(),
+        Some(PinnedConcurrentColError::InvalidGrowthPolicy)
+    );
+}
+
+#[test]
+#[should_panic]
+fn zero_threshold_panics_on_set() {
+    let mut col: PinnedConcurrentCol<String, _, OrderedState<_>> =
+        PinnedConcurrentCol::with_doubling_growth();
+    col.set_growth_policy(GrowthPolicy::GrowAhead {
+        threshold_percent: 0,
+    });
+}
+
+#[test]
+fn one_percent_threshold_grows_once_per_capacity() {
+    let mut col: PinnedConcurrentCol<String, _, OrderedState<_>> =
+        PinnedConcurrentCol::with_doubling_growth();
+    col.set_growth_policy(GrowthPolicy::GrowAhead {
+        threshold_percent: 1,
+    });
+
+    // doubling fragments: [0, 4), [4, 12), [12, 28), [28, 60), [60, 124)
+    for idx in 0..20 {
+        unsafe { col.write(idx, idx.to_string()) };
+        assert!(col.capacity() <= 60);
+    }
+    assert_eq!(col.capacity(), 60);
+    assert_eq!(col.latest_allocation(), 28..60);
+
+    // a write far from the frontier within the latest allocation grows ahead once
+    unsafe { col.write(50, 50.to_string()) };
+    assert_eq!(col.capacity(), 124);
+    unsafe { col.write(55, 55.to_string()) };
+    assert_eq!(col.capacity(), 124);
+
+    let col: PinnedConcurrentCol<String, _, OrderedState<_>> = PinnedConcurrentColBuilder::new()
+        .growth_policy(GrowthPolicy::GrowAhead {
+            threshold_percent: 1,
+        })
+        .build()
+        .expect("valid configuration");
+    for idx in 0..20 {
+        unsafe { col.write(idx, idx.to_string()) };
+    }
+    assert_eq!(col.capacity(), 60);
+}
+
+#[test]
+fn default_growth_policy() {
+    let mut col: PinnedConcurrentCol<String, _, OrderedState<_>> =
+        PinnedConcurrentCol::with_doubling_growth();
+    assert_eq!(col.growth_policy(), GrowthPolicy::OnDemand);
+
+    col.set_growth_policy(GROW_AHEAD);
+    assert_eq!(col.growth_policy(), GROW_AHEAD);
+
+    let clone = unsafe { col.clone_with_len(0) };
+    assert_eq!(clone.growth_policy(), GROW_AHEAD);
+
+    let col: PinnedConcurrentCol<String, _, OrderedState<_>> = PinnedConcurrentColBuilder::new()
+        .growth_policy(GROW_AHEAD)
+        .build()
+        .expect("valid configuration");
+    assert_eq!(col.growth_policy(), GROW_AHEAD);
+}
+
+/// Writes sequential positions and returns the number of writes which found the frontier at or beyond the capacity;
+/// i.e., the writes which had to wait for an allocation.
+fn num_frontier_waits<P>(vec: P, growth_policy: GrowthPolicy, len: usize) -> usize
+where
+    P: IntoConcurrentPinnedVec<String>,
+{
+    let mut col: PinnedConcurrentCol<_, _, OrderedState<_>> =
+        PinnedConcurrentCol::new_from_pinned(vec);
+    col.set_growth_policy(growth_policy);
+
+    let mut num_waits = 0;
+    for idx in 0..len {
+        if idx >= col.capacity() {
+            num_waits += 1;
+        }
+        unsafe { col.write(idx, idx.to_string()) };
+    }
+
+    for idx in 0..len {
+        assert_eq!(unsafe { col.get(idx) }, Some(&idx.to_string()));
+    }
+    num_waits
+}
+
+#[test_matrix([
+    SplitVec::with_doubling_growth_and_fragments_capacity(32),
+    SplitVec::with_linear_growth_and_fragments_capacity(4, 1024)
+])]
+fn grow_ahead_sequential<P: IntoConcurrentPinnedVec<String> + Clone>(vec: P) {
+    let len = NUM_THREADS * NUM_ITEMS_PER_THREAD;
+
+    let on_demand = num_frontier_waits(vec.clone(), GrowthPolicy::OnDemand, len);
+    let grow_ahead = num_frontier_waits(vec, GROW_AHEAD, len);
+
+    assert!(on_demand > 0);
+    assert_eq!(grow_ahead, 0);
+}
+
+#[test]
+fn grow_ahead_stops_at_maximum_capacity() {
+    let mut col: PinnedConcurrentCol<String, _, OrderedState<_>> =
+        PinnedConcurrentCol::with_linear_growth(2, 3);
+    col.set_growth_policy(GROW_AHEAD);
+
+    for idx in 0..col.maximum_capacity() {
+        unsafe { col.write(idx, idx.to_string()) };
+    }
+    assert_eq!(col.capacity(), 12);
+    assert_eq!(
+        unsafe { col.try_write(12, 12.to_string()) }.map_err(PinnedConcurrentColError::from),
+        Err(PinnedConcurrentColError::OutOfMaximumCapacity {
+            required_capacity: 13,
+            maximum_capacity: 12
+        })
+    );
+}
+
+#[test_matrix([
+    SplitVec::with_doubling_growth_and_fragments_capacity(32),
+    SplitVec::with_linear_growth_and_fragments_capacity(6, 1024)
+])]
+fn grow_ahead_stress<P: IntoConcurrentPinnedVec<String>>(vec: P) {
+    let mut col: PinnedConcurrentCol<_, _, OrderedState<_>> =
+        PinnedConcurrentCol::new_from_pinned(vec);
+    col.set_growth_policy(GROW_AHEAD);
+
+    let col = SyncCol(col);
+    let shared = &col;
+    let len = NUM_THREADS * NUM_ITEMS_PER_THREAD;
+
+    let mut begin_idx = 0;
+    while begin_idx < len {
+        // each round concurrently writes up to the current capacity, and hence, never reaches the frontier
+        let capacity = col.0.capacity();
+        let end_idx = capacity.min(len);
+        let next_idx = AtomicUsize::new(begin_idx);
+
+        std::thread::scope(|s| {
+            for _ in 0..NUM_THREADS {
+                s.spawn(|| {
+                    let col = &shared.0;
+                    loop {
+                        let idx = next_idx.fetch_add(1, Ordering::Relaxed);
+                        if idx >= end_idx {
+                            break;
+                        }
+                        unsafe { col.write(idx, idx.to_string()) };
+                    }
+                });
+            }
+        });
+
+        // the writer crossing the threshold grows ahead before its write returns;
+        // hence, capacity is already beyond the frontier before any writer reaches it
+        if end_idx == capacity {
+            assert!(col.0.capacity() > capacity);
+        }
+        begin_idx = end_idx;
+    }
+
+    let col = col.0;
+    assert_eq!(col.state().num_written(), len);
+    for idx in 0..len {
+        assert_eq!(unsafe { col.get(idx) }, Some(&idx.to_string()));
+    }
+}
+
+#[test]
+fn latest_allocation_after_concurrent_growth_without_growth_handle() {
+    // fragments of two positions so that the writers grow the collection concurrently across many fragment boundaries
+    let num_items = NUM_THREADS * NUM_ITEMS_PER_THREAD;
+    let vec = SplitVec::with_linear_growth_and_fragments_capacity(1, num_items);
+    let mut col: PinnedConcurrentCol<String, _, AppendOnlyState<_>> =
+        PinnedConcurrentCol::new_from_pinned(vec);
+    assert_eq!(col.latest_allocation(), 0..col.capacity());
+
+    let shared = SyncCol(&col);
+    let shared = &shared;
+    std::thread::scope(|s| {
+        for _ in 0..NUM_THREADS {
+            s.spawn(move || {
+                let col = shared.0;
+                for _ in 0..NUM_ITEMS_PER_THREAD {
+                    let idx = col.state().fetch_increment_len(1);
+                    unsafe { col.write(idx, idx.to_string()) };
+                }
+            });
+        }
+    });
+
+    assert_eq!(col.capacity(), num_items);
+    assert_eq!(col.latest_allocation(), (num_items - 2)..num_items);
+
+    unsafe { col.clear(num_items) };
+    assert_eq!(col.latest_allocation(), 0..col.capacity());
+}
@@ -0,0 +1,168 @@
+mod common;
+
+use common::{NUM_ITEMS_PER_THREAD, NUM_THREADS, SyncCol};
+use orx_fixed_vec::FixedVec;
+use orx_pinned_concurrent_col::states::*;
+use orx_pinned_concurrent_col::*;
+use orx_pinned_vec::PinnedVec;
+use orx_split_vec::SplitVec;
+use prelude::IntoConcurrentPinnedVec;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use test_case::test_matrix;
+
+#[test]
+fn local_writer_reserves_chunks() {
+    let col: PinnedConcurrentCol<String, _, AppendOnlyState<_>> =
+        PinnedConcurrentCol::with_doubling_growth();
+    let num_reserves = AtomicUsize::new(0);
+
+    let mut writer = unsafe {
+        col.local_writer(4, |n| {
+            num_reserves.fetch_add(1, Ordering::Relaxed);
+            col.state().fetch_increment_len(n)
+        })
+    };
+    assert_eq!(writer.chunk_size(), 4);
+    assert_eq!(writer.unused_range(), 0..0);
+
+    for i in 0..10 {
+        assert_eq!(writer.push(i.to_string()), i);
+    }
+    assert_eq!(num_reserves.load(Ordering::Relaxed), 3);
+    assert_eq!(col.state().len(), 12);
+    assert_eq!(writer.unused_range(), 10..12);
+
+    assert_eq!(writer.flush(), Some(10..12));
+    assert_eq!(writer.flush(), None);
+    drop(writer);
+
+    let vec = unsafe { col.into_inner(10) };
+    assert_eq!(vec.len(), 10);
+    for i in 0..10 {
+        assert_eq!(vec.get(i), Some(&i.to_string()));
+    }
+}
+
+#[test]
+fn local_writer_leaves_holes_on_drop() {
+    let col: PinnedConcurrentCol<String, _, OrderedState<_>> =
+        PinnedConcurrentCol::with_doubling_growth();
+    let len = AtomicUsize::new(0);
+
+    let mut first = unsafe { col.local_writer(8, |n| len.fetch_add(n, Ordering::Relaxed)) };
+    let mut second = unsafe { col.local_writer(8, |n| len.fetch_add(n, Ordering::Relaxed)) };
+    for i in 0..3 {
+        assert_eq!(first.push(i.to_string()), i);
+        assert_eq!(second.push((8 + i).to_string()), 8 + i);
+    }
+    drop(first);
+    drop(second);
+
+    assert_eq!(col.state().num_written(), 6);
+    assert_eq!(col.committed_len(), Some(3));
+    for idx in 0..16 {
+        assert_eq!(col.is_written(idx), Some(idx % 8 < 3));
+    }
+    assert_eq!(
+        col.try_into_inner(4).err(),
+        Some(PinnedConcurrentColError::UnwrittenPosition { idx: 3 })
+    );
+}
+
+#[test]
+fn local_writer_fills_unused_positions() {
+    let col: PinnedConcurrentCol<Option<String>, _, FilledState<_>> =
+        PinnedConcurrentCol::with_doubling_growth();
+    let len = AtomicUsize::new(0);
+
+    let mut first = unsafe { col.local_writer(8, |n| len.fetch_add(n, Ordering::Relaxed)) };
+    let mut second = unsafe { col.local_writer(8, |n| len.fetch_add(n, Ordering::Relaxed)) };
+    for i in 0..3 {
+        first.push(Some(i.to_string()));
+        second.push(Some((8 + i).to_string()));
+    }
+    assert_eq!(first.flush(), None);
+    assert_eq!(second.flush(), None);
+    drop((first, second));
+
+    let vec = unsafe { col.into_inner(16) };
+    for idx in 0..16 {
+        let expected = (idx % 8 < 3).then(|| idx.to_string());
+        assert_eq!(vec.get(idx), Some(&expected));
+    }
+}
+
+#[test]
+#[should_panic]
+fn local_writer_zero_chunk_size() {
+    let col: PinnedConcurrentCol<String, _, AppendOnlyState<_>> =
+        PinnedConcurrentCol::with_doubling_growth();
+    let _writer = unsafe { col.local_writer(0, |n| col.state().fetch_increment_len(n)) };
+}
+
+#[test]
+fn local_writer_out_of_maximum_capacity() {
+    let col: PinnedConcurrentCol<String, _, AppendOnlyState<_>> =
+        PinnedConcurrentCol::new_from_pinned(FixedVec::new(6));
+
+    let mut writer = unsafe { col.local_writer(4, |n| col.state().fetch_increment_len(n)) };
+    for i in 0..4 {
+        assert_eq!(writer.try_push(i.to_string()), Ok(i));
+    }
+    assert_eq!(
+        writer.try_push(4.to_string()),
+        Err(WriteError {
+            error: PinnedConcurrentColError::OutOfMaximumCapacity {
+                required_capacity: 8,
+                maximum_capacity: 6
+            },
+            value: 4.to_string()
+        })
+    );
+    drop(writer);
+
+    let vec = unsafe { col.into_inner(4) };
+    assert_eq!(vec.len(), 4);
+}
+
+#[test_matrix([
+    FixedVec::new(NUM_THREADS * NUM_ITEMS_PER_THREAD + NUM_THREADS * 64),
+    SplitVec::with_doubling_growth_and_fragments_capacity(32),
+    SplitVec::with_linear_growth_and_fragments_capacity(6, 8192)
+], [1, 7, 64])]
+fn concurrent_local_writers<P: IntoConcurrentPinnedVec<Option<usize>>>(vec: P, chunk_size: usize) {
+    let col: PinnedConcurrentCol<_, _, FilledState<_>> = PinnedConcurrentCol::new_from_pinned(vec);
+    let col = SyncCol(col);
+    let len = AtomicUsize::new(0);
+
+    std::thread::scope(|s| {
+        let (col, len) = (&col, &len);
+        for t in 0..NUM_THREADS {
+            s.spawn(move || {
+                let mut writer = unsafe {
+                    col.0
+                        .local_writer(chunk_size, |n| len.fetch_add(n, Ordering::Relaxed))
+                };
+                for j in 0..NUM_ITEMS_PER_THREAD {
+                    let value = t * NUM_ITEMS_PER_THREAD + j;
+                    let idx = writer.push(Some(value));
+                    assert!(idx < len.load(Ordering::Relaxed));
+                }
+            });
+        }
+    });
+
+    let len = len.load(Ordering::Relaxed);
+    assert!(len >= NUM_THREADS * NUM_ITEMS_PER_THREAD);
+    assert!(len <= NUM_THREADS * (NUM_ITEMS_PER_THREAD + chunk_size));
+
+    let vec = unsafe { col.0.into_inner(len) };
+    let mut values: Vec<_> = (0..len)
+        .filter_map(|i| *vec.get(i).expect("within len"))
+        .collect();
+    values.sort();
+    assert_eq!(
+        values,
+        (0..NUM_THREADS * NUM_ITEMS_PER_THREAD).collect::<Vec<_>>()
+    );
+}
@@ -0,0 +1,241 @@
+mod common;
+
+use common::{NUM_ITEMS_PER_THREAD, NUM_THREADS, doubling_vec, fixed_vec, linear_vec};
+use orx_pinned_concurrent_col::*;
+use orx_pinned_vec::PinnedVec;
+use orx_split_vec::SplitVec;
+use prelude::IntoConcurrentPinnedVec;
+use test_case::test_matrix;
+
+#[cfg(not(miri))]
+const NUM_ROUNDS: usize = 64;
+#[cfg(miri)]
+const NUM_ROUNDS: usize = 1;
+
+#[test]
+fn new() {
+    let log: ConcurrentLog<String> = ConcurrentLog::new();
+    assert!(log.is_empty());
+    assert_eq!(log.committed_len(), 0);
+    assert_eq!(log.reserved_len(), 0);
+
+    let log: ConcurrentLog<String, _> = ConcurrentLog::with_linear_growth(4, 10);
+    assert_eq!(log.maximum_capacity(), 160);
+
+    let log: ConcurrentLog<String, _> = ConcurrentLog::with_fixed_capacity(42);
+    assert_eq!(log.capacity(), 42);
+}
+
+#[test]
+fn new_from_pinned() {
+    let mut vec = SplitVec::new();
+    vec.push("a".to_string());
+    vec.push("b".to_string());
+
+    let log = ConcurrentLog::new_from_pinned(vec);
+    assert_eq!(log.committed_len(), 2);
+    assert_eq!(log.push("c".to_string()), 2);
+    assert_eq!(
+        log.iter().collect::<Vec<_>>(),
+        [&"a".to_string(), &"b".to_string(), &"c".to_string()]
+    );
+}
+
+#[test_matrix([fixed_vec(), doubling_vec(), linear_vec()])]
+fn push_and_extend<P: IntoConcurrentPinnedVec<String>>(vec: P) {
+    let log = ConcurrentLog::new_from_pinned(vec);
+
+    assert_eq!(log.push(0.to_string()), 0);
+    assert_eq!(log.extend((1..5).map(|x| x.to_string())), 1..5);
+    assert_eq!(log.extend(Vec::<String>::new()), 5..5);
+    assert_eq!(log.push(5.to_string()), 5);
+
+    assert_eq!(log.committed_len(), 6);
+    for i in 0..6 {
+        assert_eq!(log.get(i), Some(&i.to_string()));
+    }
+    assert_eq!(log.get(6), None);
+
+    let vec = log.into_inner();
+    assert_eq!(vec.len(), 6);
+}
+
+#[test_matrix([fixed_vec(), doubling_vec(), linear_vec()])]
+fn concurrent_push_and_read<P: IntoConcurrentPinnedVec<String>>(vec: P) {
+    let log = ConcurrentLog::new_from_pinned(vec);
+
+    std::thread::scope(|s| {
+        for t in 0..NUM_THREADS {
+            let log = &log;
+            s.spawn(move || {
+                for i in 0..NUM_ITEMS_PER_THREAD {
+                    let value = format!("{}-{}", t, i);
+                    match i % 3 {
+                        0 => {
+                            log.push(value);
+                        }
+                        _ => {
+                            log.extend([value.clone(), value]);
+                        }
+                    }
+                }
+            });
+        }
+
+        for _ in 0..2 {
+            let log = &log;
+            s.spawn(move || {
+                let mut num_read = 0;
+                while num_read < NUM_THREADS * NUM_ITEMS_PER_THREAD {
+                    let committed_len = log.committed_len();
+                    assert!(committed_len <= log.reserved_len());
+                    for value in log.iter().skip(num_read) {
+                        assert!(value.contains('-'));
+                    }
+                    num_read = committed_len;
+                }
+            });
+        }
+    });
+
+    let num_pushes = NUM_ITEMS_PER_THREAD.div_ceil(3);
+    let num_extends = NUM_ITEMS_PER_THREAD - num_pushes;
+    let len = NUM_THREADS * (num_pushes + 2 * num_extends);
+    assert_eq!(log.committed_len(), len);
+    assert_eq!(log.reserved_len(), len);
+
+    let vec = log.into_inner();
+    assert_eq!(vec.len(), len);
+    for t in 0..NUM_THREADS {
+        let prefix = format!("{}-", t);
+        let count = vec.iter().filter(|x| x.starts_with(&prefix)).count();
+        assert_eq!(count, num_pushes + 2 * num_extends);
+    }
+}
+
+#[test]
+fn concurrent_push_across_many_fragments() {
+    let num_items = NUM_THREADS * NUM_ITEMS_PER_THREAD;
+
+    // the bitmaps of the log grow rarely; hence, the concurrent growths across fragment boundaries are repeated on new logs
+    for _ in 0..NUM_ROUNDS {
+        // fragments of two positions so that the writers grow the log concurrently across many fragment boundaries
+        let log = ConcurrentLog::with_linear_growth(1, num_items);
+
+        std::thread::scope(|s| {
+            for t in 0..NUM_THREADS {
+                let log = &log;
+                s.spawn(move || {
+                    for i in 0..NUM_ITEMS_PER_THREAD {
+                        log.push(t * NUM_ITEMS_PER_THREAD + i);
+                    }
+                });
+            }
+
+            let log = &log;
+            s.spawn(move || {
+                for idx in 0..num_items {
+                    assert!(*log.wait_get(idx) < num_items);
+                }
+            });
+        });
+
+        assert_eq!(log.committed_len(), num_items);
+        let mut values: Vec<_> = log.iter().copied().collect();
+        values.sort();
+        assert_eq!(values, (0..num_items).collect::<Vec<_>>());
+    }
+}
+
+#[test]
+fn debug() {
+    let log = ConcurrentLog::with_fixed_capacity(10);
+    log.push(42);
+
+    let debug = format!("{:?}", log);
+    assert_eq!(
+        debug,
+        "ConcurrentLog { committed_len: 1, reserved_len: 1, capacity: 10, maximum_capacity: 10 }"
+    );
+}
+
+#[test]
+fn extend_with_panicking_iterator() {
+    let log = ConcurrentLog::new();
+    log.push(0);
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        log.extend((1..10).map(|x| match x {
+            5 => panic!("iterator panics"),
+            x => x,
+        }))
+    }));
+    assert!(result.is_err());
+
+    assert!(!log.is_poisoned());
+    assert_eq!(log.reserved_len(), 1);
+    assert_eq!(log.push(1), 1);
+    assert_eq!(log.extend(2..4), 2..4);
+    assert_eq!(log.iter().copied().collect::<Vec<_>>(), [0, 1, 2, 3]);
+}
+
+struct LyingIter(core::ops::Range<usize>);
+
+impl Iterator for LyingIter {
+    type Item = usize;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (100, Some(100))
+    }
+}
+
+impl ExactSizeIterator for LyingIter {}
+
+#[test]
+fn extend_with_misreporting_iterator() {
+    let log = ConcurrentLog::new();
+
+    assert_eq!(log.extend(LyingIter(0..3)), 0..3);
+    assert_eq!(log.push(3), 3);
+    assert_eq!(log.committed_len(), 4);
+    assert_eq!(log.iter().copied().collect::<Vec<_>>(), [0, 1, 2, 3]);
+}
+
+#[test]
+fn failed_write_poisons_log() {
+    let log = ConcurrentLog::with_fixed_capacity(4);
+    assert_eq!(log.extend(0..3), 0..3);
+
+    assert!(matches!(
+        log.try_extend(3..6),
+        Err(PinnedConcurrentColError::OutOfMaximumCapacity { .. })
+    ));
+    assert!(log.is_poisoned());
+    assert_eq!(log.committed_len(), 3);
+
+    let error = log.try_push(6).expect_err("log is poisoned");
+    assert_eq!(error.into_value(), 6);
+    assert_eq!(log.committed_len(), 3);
+    assert_eq!(log.iter().copied().collect::<Vec<_>>(), [0, 1, 2]);
+}
+
+#[test]
+fn poisoned_log_drops_uncommitted_values() {
+    let log = ConcurrentLog::with_fixed_capacity(4);
+    assert_eq!(log.push(0.to_string()), 0);
+    assert!(log.try_extend((1..8).map(|x| x.to_string())).is_err());
+    assert!(log.try_push(8.to_string()).is_err());
+
+    let vec = log.into_inner();
+    assert_eq!(vec.iter().collect::<Vec<_>>(), [&0.to_string()]);
+}
+
+#[test]
+#[should_panic]
+fn push_to_poisoned_log_panics() {
+    let log = ConcurrentLog::with_fixed_capacity(2);
+    assert!(log.try_extend(0..3).is_err());
+    log.push(0);
+}
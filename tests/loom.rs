@@ -0,0 +1,260 @@
+//! Exhaustive model checking of the write and growth protocol.
+//!
+//! The models cover the protocol atomics of the collection, its states and bitmaps, which are swapped with their loom counterparts.
+//! The capacities and fragment pointers of the pinned vectors are not seen by loom; hence, the models check that the growths are
+//! serialized by the protocol, while the races on the memory of the pinned vectors themselves are out of their reach.
+//! The concurrent growths of the bitmaps are detected through a loom cell standing in for their memory.
+//!
+//! Run with `RUSTFLAGS="--cfg loom" cargo test --test loom --release`.
+
+#![cfg(loom)]
+
+mod common;
+
+use common::SyncCol;
+use loom::{sync::Arc, thread};
+use orx_pinned_concurrent_col::states::*;
+use orx_pinned_concurrent_col::*;
+use orx_pinned_vec::PinnedVec;
+use orx_split_vec::{ConcurrentSplitVec, Doubling, SplitVec};
+
+type Col<S> = PinnedConcurrentCol<String, ConcurrentSplitVec<String, Doubling>, S>;
+
+const PREEMPTION_BOUND: usize = 3;
+
+fn model<F>(f: F)
+where
+    F: Fn() + Sync + Send + 'static,
+{
+    let mut builder = loom::model::Builder::new();
+    builder.preemption_bound = Some(PREEMPTION_BOUND);
+    builder.check(f);
+}
+
+/// Returns a collection with the given `len` where the capacity of the first fragment, 4, is allocated.
+fn new_col<S>(len: usize) -> Col<S>
+where
+    S: ConcurrentState<String>,
+{
+    let mut vec = SplitVec::with_doubling_growth_and_max_concurrent_capacity();
+    for i in 0..len {
+        vec.push(i.to_string());
+    }
+    let mut col = PinnedConcurrentCol::new_from_pinned(vec);
+    col.set_spin_strategy(SpinStrategy::Spin);
+    col
+}
+
+fn spawn_write<S>(col: &Arc<SyncCol<Col<S>>>, idx: usize) -> thread::JoinHandle<()>
+where
+    S: ConcurrentState<String> + 'static,
+{
+    let col = col.clone();
+    thread::spawn(move || unsafe { col.0.write(idx, idx.to_string()) })
+}
+
+fn assert_written<S>(col: Col<S>, len: usize)
+where
+    S: ConcurrentState<String>,
+{
+    assert!(col.capacity() >= len);
+    let vec = unsafe { col.into_inner(len) };
+    assert_eq!(vec.len(), len);
+    for i in 0..len {
+        assert_eq!(vec.get(i), Some(&i.to_string()));
+    }
+}
+
+#[test]
+fn append_only_grow_then_write_and_spin() {
+    model(|| {
+        let col = Arc::new(SyncCol(new_col::<AppendOnlyState<_>>(4)));
+        assert_eq!(col.0.capacity(), 4);
+
+        // idx 4 receives `GrowThenWrite` while idx 5 receives `Spin` until the growth is completed
+        let handles = [spawn_write(&col, 4), spawn_write(&col, 5)];
+        handles
+            .into_iter()
+            .for_each(|h| h.join().expect("writer panicked"));
+
+        let col = Arc::try_unwrap(col).ok().expect("writers joined").0;
+        assert_written(col, 6);
+    });
+}
+
+#[test]
+fn ordered_growth_handle_contention() {
+    model(|| {
+        let col = Arc::new(SyncCol(new_col::<OrderedState<_>>(2)));
+
+        // idx 4 and 5 contend for the growth handle, while idx 2 and 3 are written without waiting
+        let handles = [
+            spawn_write(&col, 5),
+            spawn_write(&col, 4),
+            spawn_write(&col, 2),
+        ];
+        handles
+            .into_iter()
+            .for_each(|h| h.join().expect("writer panicked"));
+        unsafe { col.0.write(3, 3.to_string()) };
+
+        let col = Arc::try_unwrap(col).ok().expect("writers joined").0;
+        assert_eq!(col.state().num_written(), 6);
+        assert_written(col, 6);
+    });
+}
+
+#[test]
+fn append_only_write_n_items_crossing_capacity() {
+    model(|| {
+        let col = Arc::new(SyncCol(new_col::<AppendOnlyState<_>>(2)));
+
+        // positions 2..5 cross the capacity and receive `GrowThenWrite` while idx 5 spins
+        let writer = {
+            let col = col.clone();
+            thread::spawn(move || unsafe {
+                col.0.write_n_items(2, 3, (2..5).map(|i| i.to_string()));
+            })
+        };
+        let handles = [writer, spawn_write(&col, 5)];
+        handles
+            .into_iter()
+            .for_each(|h| h.join().expect("writer panicked"));
+
+        let col = Arc::try_unwrap(col).ok().expect("writers joined").0;
+        assert_written(col, 6);
+    });
+}
+
+#[test]
+fn ordered_write_n_items_contending_growth() {
+    model(|| {
+        let col = Arc::new(SyncCol(new_col::<OrderedState<_>>(3)));
+
+        // both requests cross the capacity; the one failing to acquire the growth handle spins
+        let handles: Vec<_> = [(3, 2), (5, 2)]
+            .into_iter()
+            .map(|(begin_idx, num_items)| {
+                let col = col.clone();
+                thread::spawn(move || unsafe {
+                    let values = (begin_idx..begin_idx + num_items).map(|i| i.to_string());
+                    col.0.write_n_items(begin_idx, num_items, values);
+                })
+            })
+            .collect();
+        handles
+            .into_iter()
+            .for_each(|h| h.join().expect("writer panicked"));
+
+        let col = Arc::try_unwrap(col).ok().expect("writers joined").0;
+        assert_eq!(col.state().num_written(), 7);
+        assert_written(col, 7);
+    });
+}
+
+#[test]
+fn ordered_committed_len_out_of_order() {
+    model(|| {
+        let col = Arc::new(SyncCol(new_col::<OrderedState<_>>(0)));
+
+        // ranges complete in any order; whichever completes last advances the committed length over both
+        let handles = [spawn_write(&col, 1), spawn_write(&col, 0)];
+        handles
+            .into_iter()
+            .for_each(|h| h.join().expect("writer panicked"));
+
+        let col = Arc::try_unwrap(col).ok().expect("writers joined").0;
+        assert_eq!(col.committed_len(), Some(2));
+        assert_written(col, 2);
+    });
+}
+
+#[test]
+fn ordered_no_gap_len_never_skips_a_gap() {
+    model(|| {
+        let col = Arc::new(SyncCol(new_col::<OrderedState<_>>(0)));
+        unsafe { col.0.write(0, 0.to_string()) };
+        unsafe { col.0.write(2, 2.to_string()) };
+
+        // position 1 is not written; the concurrent write to position 3 must not make the prefix look gap-free
+        let writer = spawn_write(&col, 3);
+        let no_gap_len = col.0.state().try_get_no_gap_len();
+        assert!(
+            no_gap_len <= Some(1),
+            "reported no-gap length {:?}",
+            no_gap_len
+        );
+        writer.join().expect("writer panicked");
+
+        unsafe { col.0.write(1, 1.to_string()) };
+        assert_eq!(col.0.state().try_get_no_gap_len(), Some(4));
+    });
+}
+
+#[test]
+fn log_concurrent_growths_without_growth_handle() {
+    model(|| {
+        // doubling fragments: [0, 4), ..., [124, 252), [252, 508), [508, 1020);
+        // the bitmap of 64-bit words grows beyond its first fragment of 4 words with both growths
+        let col = Arc::new(SyncCol(new_col::<LogState<_>>(252)));
+        assert_eq!(col.0.capacity(), 252);
+        assert_eq!(col.0.state().fetch_increment_len(257), 252);
+
+        // idx 252 receives `GrowThenWrite`; idx 508 spins until the capacity is published, and then grows while the first growth completes
+        let handles = [spawn_write(&col, 252), spawn_write(&col, 508)];
+        handles
+            .into_iter()
+            .for_each(|h| h.join().expect("writer panicked"));
+
+        let col = Arc::try_unwrap(col).ok().expect("writers joined").0;
+        assert_eq!(col.capacity(), 1020);
+        assert_eq!(col.state().committed_len(), 253);
+        assert_written(col, 253);
+    });
+}
+
+#[test]
+fn ring_single_slot_accepts_one_producer() {
+    model(|| {
+        let ring = Arc::new(ConcurrentRing::new(1));
+
+        let handles: Vec<_> = [1, 2]
+            .map(|value| {
+                let ring = ring.clone();
+                thread::spawn(move || ring.try_push(value.to_string()).is_ok())
+            })
+            .into_iter()
+            .collect();
+        let pushed: Vec<_> = handles
+            .into_iter()
+            .map(|h| h.join().expect("producer panicked"))
+            .collect();
+
+        assert_eq!(pushed.iter().filter(|x| **x).count(), 1);
+        let expected = match pushed[0] {
+            true => 1.to_string(),
+            false => 2.to_string(),
+        };
+        assert_eq!(ring.try_pop(), Some(expected));
+        assert_eq!(ring.try_pop(), None);
+    });
+}
+
+#[test]
+fn ring_producer_waits_for_consumer() {
+    model(|| {
+        let ring = Arc::new(ConcurrentRing::new(1));
+        ring.push(0.to_string());
+
+        // the producer waits until the consumer releases the single slot
+        let producer = {
+            let ring = ring.clone();
+            thread::spawn(move || ring.push(1.to_string()))
+        };
+
+        assert_eq!(ring.pop(), 0.to_string());
+        assert_eq!(ring.pop(), 1.to_string());
+        producer.join().expect("producer panicked");
+        assert!(ring.is_empty());
+    });
+}
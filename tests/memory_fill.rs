@@ -0,0 +1,146 @@
+mod common;
+mod state;
+
+use common::DropCounter;
+
+use orx_fixed_vec::FixedVec;
+use orx_pinned_concurrent_col::states::*;
+use orx_pinned_concurrent_col::*;
+use orx_split_vec::SplitVec;
+use prelude::IntoConcurrentPinnedVec;
+use state::MyConState;
+use std::sync::{
+    Arc,
+    atomic::{AtomicUsize, Ordering},
+};
+use test_case::test_matrix;
+
+#[derive(Clone, Debug, PartialEq)]
+struct Slot {
+    key: u64,
+    value: Option<String>,
+}
+
+#[test]
+fn memory_fill_kinds() {
+    let fill = MemoryFill::from_fn(|| 3);
+    assert_eq!(fill.fill(), 3);
+
+    let fill = MemoryFill::<Option<u64>>::from_default();
+    assert_eq!(fill.fill(), None);
+
+    let fill = MemoryFill::from_value("empty".to_string());
+    assert_eq!(fill.fill(), "empty".to_string());
+    assert_eq!(fill.clone().fill(), "empty".to_string());
+
+    let num_calls = Arc::new(AtomicUsize::new(0));
+    let counter = num_calls.clone();
+    let fill = MemoryFill::from_closure(move || counter.fetch_add(1, Ordering::Relaxed));
+    assert_eq!(fill.fill(), 0);
+    assert_eq!(fill.clone().fill(), 1);
+    assert_eq!(num_calls.load(Ordering::Relaxed), 2);
+}
+
+#[test_matrix([
+    FixedVec::new(222),
+    SplitVec::with_doubling_growth_and_fragments_capacity(16),
+    SplitVec::with_linear_growth_and_fragments_capacity(5, 33)
+])]
+fn fill_with_runtime_sentinel<P: IntoConcurrentPinnedVec<Slot>>(vec: P) {
+    let sentinel = Slot {
+        key: std::process::id() as u64 + u32::MAX as u64,
+        value: None,
+    };
+
+    let col: PinnedConcurrentCol<Slot, _, OrderedState<_>> = PinnedConcurrentColBuilder::new()
+        .with_pinned_vec(vec)
+        .fill_memory_with_value(sentinel.clone())
+        .build()
+        .expect("valid configuration");
+
+    for idx in (0..200).step_by(3) {
+        let slot = Slot {
+            key: idx as u64,
+            value: Some(idx.to_string()),
+        };
+        unsafe { col.write(idx, slot) };
+    }
+
+    for idx in 0..col.capacity() {
+        let expected = match idx < 200 && idx.is_multiple_of(3) {
+            true => Slot {
+                key: idx as u64,
+                value: Some(idx.to_string()),
+            },
+            false => sentinel.clone(),
+        };
+        assert_eq!(unsafe { col.get(idx) }, Some(&expected));
+    }
+
+    let vec = col.try_into_inner(200).expect("filled memory has no gaps");
+    assert_eq!(vec.get(1), Some(&sentinel));
+}
+
+#[test_matrix([
+    SplitVec::with_doubling_growth_and_fragments_capacity(16),
+    SplitVec::with_linear_growth_and_fragments_capacity(5, 33)
+])]
+fn fill_with_closure_on_growth_and_reserve<P: IntoConcurrentPinnedVec<usize>>(vec: P) {
+    let num_filled = Arc::new(AtomicUsize::new(0));
+    let counter = num_filled.clone();
+
+    let mut col: PinnedConcurrentCol<usize, _, MyConState<_>> = PinnedConcurrentColBuilder::new()
+        .with_pinned_vec(vec)
+        .fill_memory_with(move || {
+            counter.fetch_add(1, Ordering::Relaxed);
+            usize::MAX
+        })
+        .build()
+        .expect("valid configuration");
+    assert_eq!(num_filled.load(Ordering::Relaxed), col.capacity());
+
+    for idx in 0..100 {
+        unsafe { col.write(idx, idx) };
+    }
+    assert!(num_filled.load(Ordering::Relaxed) >= col.capacity());
+
+    let max_cap = col.maximum_capacity();
+    let new_max_cap = unsafe { col.reserve_maximum_capacity(100, max_cap + 1) };
+    assert!(new_max_cap > max_cap);
+
+    for idx in 0..col.capacity() {
+        let expected = match idx < 100 {
+            true => idx,
+            false => usize::MAX,
+        };
+        assert_eq!(unsafe { col.get(idx) }, Some(&expected));
+    }
+
+    let clone = unsafe { col.clone_with_len(50) };
+    for idx in 0..clone.capacity() {
+        let expected = match idx < 50 {
+            true => idx,
+            false => usize::MAX,
+        };
+        assert_eq!(unsafe { clone.get(idx) }, Some(&expected));
+    }
+
+    col.state().set_final_len(100);
+    clone.state().set_final_len(50);
+}
+
+#[test]
+fn filled_memory_is_dropped() {
+    let num_dropped = Arc::new(AtomicUsize::new(0));
+
+    let counter = num_dropped.clone();
+    let col: PinnedConcurrentCol<DropCounter, _, MyConState<_>> = PinnedConcurrentColBuilder::new()
+        .with_fixed_capacity(64)
+        .fill_memory_with(move || DropCounter::new(0, &counter))
+        .build()
+        .expect("valid configuration");
+
+    col.state().set_final_len(0);
+    drop(col);
+    assert_eq!(num_dropped.load(Ordering::Relaxed), 64);
+}
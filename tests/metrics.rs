@@ -0,0 +1,166 @@
+#![cfg(feature = "metrics")]
+
+mod common;
+mod state;
+
+use common::SyncCol;
+use orx_fixed_vec::FixedVec;
+use orx_pinned_concurrent_col::states::*;
+use orx_pinned_concurrent_col::*;
+use orx_split_vec::SplitVec;
+use state::MyConState;
+use std::{
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Duration,
+};
+
+#[test]
+fn stats_initially_empty() {
+    let col: PinnedConcurrentCol<String, _, OrderedState<_>> =
+        PinnedConcurrentCol::with_doubling_growth();
+    assert_eq!(col.stats(), ColStats::default());
+
+    let clone = unsafe { col.clone_with_len(0) };
+    assert_eq!(clone.stats(), ColStats::default());
+}
+
+#[test]
+fn stats_grows() {
+    let col: PinnedConcurrentCol<u64, _, OrderedState<_>> =
+        PinnedConcurrentCol::with_doubling_growth();
+    let initial_capacity = col.capacity();
+
+    for idx in 0..100 {
+        unsafe { col.write(idx, idx as u64) };
+    }
+
+    let stats = col.stats();
+    assert_eq!(stats.num_grows, 4);
+    assert_eq!(stats.num_failed_grows, 0);
+    assert_eq!(stats.num_spins_write, 0);
+    assert_eq!(stats.num_spins_write_n_items, 0);
+    assert_eq!(
+        stats.bytes_allocated,
+        (col.capacity() - initial_capacity) * size_of::<u64>()
+    );
+    assert_eq!(stats.fill_duration, Duration::ZERO);
+}
+
+#[test]
+fn stats_grows_with_fill() {
+    let col: PinnedConcurrentCol<Option<u64>, _, FilledState<_>> =
+        PinnedConcurrentCol::with_linear_growth(4, 32);
+
+    unsafe { col.write_n_items(0, 100, (0..100).map(Some)) };
+
+    let stats = col.stats();
+    assert_eq!(col.capacity(), 7 * 16);
+    assert_eq!(stats.num_grows, 1);
+    assert_eq!(stats.bytes_allocated, 6 * 16 * size_of::<Option<u64>>());
+    assert!(stats.fill_duration <= stats.growth_duration);
+    #[cfg(feature = "std")]
+    assert!(stats.fill_duration > Duration::ZERO);
+}
+
+#[test]
+fn stats_fill_after_reserve_maximum_capacity() {
+    let mut col: PinnedConcurrentCol<Option<u64>, _, FilledState<_>> =
+        PinnedConcurrentCol::with_linear_growth(4, 2);
+
+    let maximum_capacity = unsafe { col.reserve_maximum_capacity(0, 1024) };
+    assert!(maximum_capacity >= 1024);
+    assert_eq!(col.stats().num_grows, 0);
+
+    unsafe { col.write_n_items(0, 1000, (0..1000).map(Some)) };
+
+    let stats = col.stats();
+    assert_eq!(stats.num_grows, 1);
+    assert!(stats.fill_duration <= stats.growth_duration);
+    #[cfg(feature = "std")]
+    assert!(stats.fill_duration > Duration::ZERO);
+}
+
+#[test]
+fn stats_failed_grows() {
+    let col: PinnedConcurrentCol<String, _, MyConState<_>> =
+        PinnedConcurrentCol::new_from_pinned(FixedVec::new(10));
+
+    let result = unsafe { col.try_write(10, 10.to_string()) };
+    assert!(result.is_err());
+    assert_eq!(col.stats().num_grows, 0);
+
+    col.state().set_final_len(0);
+}
+
+#[test]
+fn stats_spins() {
+    let col: PinnedConcurrentCol<String, _, OrderedState<_>> = PinnedConcurrentColBuilder::new()
+        .with_pinned_vec(SplitVec::with_linear_growth_and_fragments_capacity(2, 2048))
+        .build()
+        .expect("valid configuration");
+    let col = SyncCol(col);
+    let shared = &col;
+
+    #[cfg(not(miri))]
+    let num_items_per_thread = 1024;
+    #[cfg(miri)]
+    let num_items_per_thread = 16;
+    let num_threads = 4;
+    let next_idx = AtomicUsize::new(0);
+
+    std::thread::scope(|s| {
+        for t in 0..num_threads {
+            let next_idx = &next_idx;
+            s.spawn(move || {
+                let col = &shared.0;
+                for _ in 0..num_items_per_thread {
+                    match t % 2 {
+                        0 => {
+                            let idx = next_idx.fetch_add(1, Ordering::Relaxed);
+                            unsafe { col.write(idx, idx.to_string()) };
+                        }
+                        _ => {
+                            let idx = next_idx.fetch_add(2, Ordering::Relaxed);
+                            let values = (idx..idx + 2).map(|x| x.to_string());
+                            unsafe { col.write_n_items(idx, 2, values) };
+                        }
+                    }
+                }
+            });
+        }
+    });
+
+    let col = col.0;
+    let len = next_idx.into_inner();
+    for idx in 0..len {
+        assert_eq!(unsafe { col.get(idx) }, Some(&idx.to_string()));
+    }
+
+    // a single growth might allocate two fragments for a range beyond the frontier;
+    // hence, only the allocated bytes are deterministic
+    let stats = col.stats();
+    assert!(stats.num_grows > 0);
+    assert!(stats.num_grows < col.capacity() / 4);
+    assert_eq!(stats.num_failed_grows, 0);
+    assert_eq!(
+        stats.bytes_allocated,
+        (col.capacity() - 4) * size_of::<String>()
+    );
+}
+
+#[test]
+fn debug_includes_stats() {
+    let col: PinnedConcurrentCol<usize, _, MyConState<_>> =
+        PinnedConcurrentCol::with_doubling_growth();
+    for idx in 0..10 {
+        unsafe { col.write(idx, idx) };
+    }
+
+    let debug = format!("{:?}", col);
+    assert!(debug.starts_with("PinnedConcurrentCol { state: MyConState {"));
+    assert!(debug.contains(
+        "stats: ColStats { num_grows: 1, num_failed_grows: 0, num_spins_write: 0, num_spins_write_n_items: 0, bytes_allocated: 64,"
+    ));
+
+    col.state().set_final_len(10);
+}
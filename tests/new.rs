@@ -20,6 +20,25 @@ fn with_doubling_growth() {
     assert_eq!(col.maximum_capacity(), 2_147_483_644);
 }
 
+#[test]
+fn with_doubling_growth_and_capacity() {
+    let col: PinnedConcurrentCol<String, _, MyConState<_>> =
+        PinnedConcurrentCol::with_doubling_growth_and_capacity(100);
+
+    assert_eq!(col.capacity(), 4 + 8 + 16 + 32 + 64);
+    assert_eq!(col.state().initial_len, 0);
+    assert_eq!(col.state().initial_cap, 4 + 8 + 16 + 32 + 64);
+
+    #[cfg(target_pointer_width = "64")]
+    assert_eq!(col.maximum_capacity(), 17_179_869_180);
+    #[cfg(target_pointer_width = "32")]
+    assert_eq!(col.maximum_capacity(), 2_147_483_644);
+
+    let col: PinnedConcurrentCol<String, _, MyConState<_>> =
+        PinnedConcurrentCol::with_doubling_growth_and_capacity(3);
+    assert_eq!(col.capacity(), 4);
+}
+
 #[test]
 fn with_linear_growth() {
     let col: PinnedConcurrentCol<String, _, MyConState<_>> =
@@ -31,6 +50,39 @@ fn with_linear_growth() {
     assert_eq!(col.state().initial_cap, 2usize.pow(4));
 }
 
+#[test]
+fn with_linear_growth_and_capacity() {
+    let col: PinnedConcurrentCol<String, _, MyConState<_>> =
+        PinnedConcurrentCol::with_linear_growth_and_capacity(4, 10, 33);
+
+    assert_eq!(col.capacity(), 3 * 2usize.pow(4));
+    assert_eq!(col.maximum_capacity(), 2usize.pow(4) * 10);
+    assert_eq!(col.state().initial_len, 0);
+    assert_eq!(col.state().initial_cap, 3 * 2usize.pow(4));
+
+    let col: PinnedConcurrentCol<String, _, MyConState<_>> =
+        PinnedConcurrentCol::with_linear_growth_and_capacity(4, 10, 160);
+    assert_eq!(col.capacity(), col.maximum_capacity());
+}
+
+#[test]
+#[should_panic]
+fn with_linear_growth_and_capacity_exceeding_maximum_capacity() {
+    let _: PinnedConcurrentCol<String, _, MyConState<_>> =
+        PinnedConcurrentCol::with_linear_growth_and_capacity(4, 10, 161);
+}
+
+#[test]
+fn with_doubling_growth_and_capacity_filled() {
+    let col: PinnedConcurrentCol<Option<String>, _, states::FilledState<_>> =
+        PinnedConcurrentCol::with_doubling_growth_and_capacity(20);
+
+    assert_eq!(col.capacity(), 28);
+    for idx in 0..col.capacity() {
+        assert_eq!(unsafe { col.get(idx) }, Some(&None));
+    }
+}
+
 #[test]
 fn with_fixed_capacity() {
     let col: PinnedConcurrentCol<String, _, MyConState<_>> =
@@ -0,0 +1,140 @@
+mod common;
+
+use common::{DropCounter, NUM_THREADS};
+use orx_pinned_concurrent_col::*;
+use orx_pinned_vec::ConcurrentPinnedVec;
+use std::{
+    panic::{AssertUnwindSafe, catch_unwind},
+    sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    },
+};
+
+#[cfg(not(miri))]
+const NUM_ITEMS: usize = 1024;
+#[cfg(miri)]
+const NUM_ITEMS: usize = 16;
+
+#[test]
+fn new() {
+    let col: ConcurrentOnceCol<String> = ConcurrentOnceCol::new();
+    assert_eq!(col.get(0), None);
+    assert_eq!(col.iter().count(), 0);
+
+    let col: ConcurrentOnceCol<String, _> = ConcurrentOnceCol::with_linear_growth(4, 10);
+    assert_eq!(col.maximum_capacity(), 160);
+
+    let col: ConcurrentOnceCol<String, _> = ConcurrentOnceCol::with_fixed_capacity(42);
+    assert_eq!(col.capacity(), 42);
+}
+
+fn get_or_init_arbitrary_positions<P: ConcurrentPinnedVec<OnceSlot<String>>>(
+    col: ConcurrentOnceCol<String, P>,
+) {
+    assert_eq!(col.get_or_init(70, || 70.to_string()), "70");
+    assert_eq!(col.get_or_init(3, || 3.to_string()), "3");
+    assert_eq!(col.get_or_init(70, || "other".to_string()), "70");
+    assert!(col.capacity() > 70);
+
+    assert!(col.is_initialized(3));
+    assert!(!col.is_initialized(4));
+    assert_eq!(col.get(4), None);
+    assert_eq!(col.get(1000), None);
+
+    let values: Vec<_> = col.iter().map(|(i, x)| (i, x.clone())).collect();
+    assert_eq!(values, [(3, 3.to_string()), (70, 70.to_string())]);
+}
+
+#[test]
+fn get_or_init_arbitrary_positions_doubling() {
+    get_or_init_arbitrary_positions(ConcurrentOnceCol::with_doubling_growth());
+}
+
+#[test]
+fn get_or_init_arbitrary_positions_linear() {
+    get_or_init_arbitrary_positions(ConcurrentOnceCol::with_linear_growth(2, 64));
+}
+
+#[test]
+fn get_or_init_arbitrary_positions_fixed() {
+    get_or_init_arbitrary_positions(ConcurrentOnceCol::with_fixed_capacity(100));
+}
+
+#[test]
+fn get_mut() {
+    let mut col = ConcurrentOnceCol::new();
+    col.get_or_init(5, || 1);
+    *col.get_mut(5).expect("is initialized") += 10;
+    assert_eq!(col.get(5), Some(&11));
+    assert_eq!(col.get_mut(4), None);
+}
+
+#[test]
+fn try_get_or_init_out_of_maximum_capacity() {
+    let col = ConcurrentOnceCol::with_fixed_capacity(4);
+    assert_eq!(col.try_get_or_init(3, || 3), Ok(&3));
+    assert_eq!(
+        col.try_get_or_init(4, || 4),
+        Err(PinnedConcurrentColError::OutOfMaximumCapacity {
+            required_capacity: 5,
+            maximum_capacity: 4
+        })
+    );
+}
+
+#[test]
+fn panicking_init_leaves_position_empty() {
+    let col = ConcurrentOnceCol::new();
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        col.get_or_init(2, || -> usize { panic!("failed to compute") })
+    }));
+    assert!(result.is_err());
+    assert!(!col.is_initialized(2));
+
+    assert_eq!(col.get_or_init(2, || 42), &42);
+}
+
+#[test]
+fn drops_initialized_values_once() {
+    let num_dropped = Arc::new(AtomicUsize::new(0));
+    let col = ConcurrentOnceCol::new();
+    for i in [0, 9, 100, 9] {
+        col.get_or_init(i, || DropCounter::new(0, &num_dropped));
+    }
+    assert_eq!(num_dropped.load(Ordering::Relaxed), 0);
+
+    drop(col);
+    assert_eq!(num_dropped.load(Ordering::Relaxed), 3);
+}
+
+#[test]
+fn concurrent_get_or_init_runs_init_once() {
+    let col = ConcurrentOnceCol::with_linear_growth(4, 1024);
+    let num_computed: Vec<_> = (0..NUM_ITEMS).map(|_| AtomicUsize::new(0)).collect();
+
+    std::thread::scope(|s| {
+        for t in 0..NUM_THREADS {
+            let (col, num_computed) = (&col, &num_computed);
+            s.spawn(move || {
+                // threads visit the positions in different orders
+                for j in 0..NUM_ITEMS {
+                    let idx = match t % 2 {
+                        0 => j,
+                        _ => NUM_ITEMS - 1 - j,
+                    };
+                    let value = col.get_or_init(idx, || {
+                        num_computed[idx].fetch_add(1, Ordering::Relaxed);
+                        idx.to_string()
+                    });
+                    assert_eq!(value, &idx.to_string());
+                }
+            });
+        }
+    });
+
+    for (idx, num_computed) in num_computed.iter().enumerate() {
+        assert_eq!(num_computed.load(Ordering::Relaxed), 1);
+        assert_eq!(col.get(idx), Some(&idx.to_string()));
+    }
+}
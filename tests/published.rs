@@ -0,0 +1,139 @@
+mod common;
+
+use common::{NUM_ITEMS_PER_THREAD, NUM_THREADS, SyncCol, doubling_vec, fixed_vec, linear_vec};
+use orx_pinned_concurrent_col::states::*;
+use orx_pinned_concurrent_col::*;
+use prelude::IntoConcurrentPinnedVec;
+use test_case::test_matrix;
+
+#[test]
+fn try_get_published() {
+    let col: PinnedConcurrentCol<String, _, LogState<_>> =
+        PinnedConcurrentCol::with_doubling_growth();
+
+    let first = col.state().fetch_increment_len(1);
+    let second = col.state().fetch_increment_len(1);
+    assert!(!col.state().is_published(first));
+    assert_eq!(col.try_get_published(first), None);
+
+    unsafe { col.write(first, first.to_string()) };
+    assert!(col.state().is_published(first));
+    assert!(!col.state().is_published(second));
+    assert_eq!(col.try_get_published(first), Some(&first.to_string()));
+    assert_eq!(col.try_get_published(second), None);
+
+    unsafe { col.write(second, second.to_string()) };
+    assert!(col.state().is_published(second));
+    assert_eq!(col.try_get_published(second), Some(&second.to_string()));
+    assert_eq!(col.try_get_published(2), None);
+}
+
+#[test_matrix([fixed_vec(), doubling_vec(), linear_vec()])]
+fn producers_and_consumers<P: IntoConcurrentPinnedVec<String>>(vec: P) {
+    let col: PinnedConcurrentCol<_, _, LogState<_>> = PinnedConcurrentCol::new_from_pinned(vec);
+    let col = SyncCol(col);
+    let shared = &col;
+
+    let len = NUM_THREADS * NUM_ITEMS_PER_THREAD;
+
+    std::thread::scope(|s| {
+        for t in 0..NUM_THREADS {
+            s.spawn(move || {
+                let col = &shared.0;
+                for i in 0..NUM_ITEMS_PER_THREAD {
+                    let idx = col.state().fetch_increment_len(1);
+                    unsafe { col.write(idx, format!("{}-{}", t, i)) };
+                }
+            });
+        }
+
+        for _ in 0..2 {
+            s.spawn(move || {
+                let col = &shared.0;
+                let mut num_consumed_per_thread = vec![0; NUM_THREADS];
+                for idx in 0..len {
+                    let value = col.wait_get(idx);
+                    let (t, i) = value.split_once('-').expect("has separator");
+                    let t: usize = t.parse().expect("is thread idx");
+                    let i: usize = i.parse().expect("is item idx");
+
+                    // each producer's values are consumed in the order they are produced
+                    assert_eq!(num_consumed_per_thread[t], i);
+                    num_consumed_per_thread[t] += 1;
+                }
+                assert_eq!(
+                    num_consumed_per_thread,
+                    vec![NUM_ITEMS_PER_THREAD; NUM_THREADS]
+                );
+            });
+        }
+    });
+}
+
+#[test]
+fn log_wait_get() {
+    let log = ConcurrentLog::new();
+    let len = NUM_THREADS * NUM_ITEMS_PER_THREAD;
+
+    std::thread::scope(|s| {
+        for _ in 0..NUM_THREADS {
+            s.spawn(|| {
+                for _ in 0..NUM_ITEMS_PER_THREAD {
+                    log.push(1usize);
+                }
+            });
+        }
+
+        let consumer = s.spawn(|| (0..len).map(|idx| *log.wait_get(idx)).sum::<usize>());
+        assert_eq!(consumer.join().expect("consumer must not panic"), len);
+    });
+
+    assert_eq!(log.get(len - 1), Some(&1));
+    assert_eq!(log.get(len), None);
+}
+
+#[test]
+fn out_of_order_writes_are_published_once_the_gap_is_written() {
+    let col: PinnedConcurrentCol<String, _, LogState<_>> =
+        PinnedConcurrentCol::with_doubling_growth();
+
+    let first = col.state().fetch_increment_len(1);
+    let second = col.state().fetch_increment_len(2);
+
+    unsafe { col.write_n_items(second, 2, [second, second + 1].map(|x| x.to_string())) };
+    assert_eq!(col.state().committed_len(), 0);
+    assert_eq!(col.try_get_published(second), None);
+
+    unsafe { col.write(first, first.to_string()) };
+    assert_eq!(col.state().committed_len(), 3);
+    for idx in 0..3 {
+        assert_eq!(col.try_get_published(idx), Some(&idx.to_string()));
+    }
+}
+
+#[test]
+fn buffers_are_published_by_commit_range() {
+    let col: PinnedConcurrentCol<String, _, LogState<_>> =
+        PinnedConcurrentCol::with_doubling_growth();
+
+    let begin_idx = col.state().fetch_increment_len(6);
+    let slices = unsafe { col.n_items_buffer_as_mut_slices(begin_idx, 6) };
+    assert!(!col.state().is_published(begin_idx));
+    assert_eq!(col.try_get_published(begin_idx), None);
+
+    let mut idx = begin_idx;
+    for slice in slices {
+        for x in slice.iter_mut() {
+            let ptr = x as *mut String;
+            unsafe { ptr.write(idx.to_string()) };
+            idx += 1;
+        }
+    }
+    assert_eq!(col.state().committed_len(), 0);
+
+    unsafe { col.commit_range(begin_idx..idx) };
+    assert_eq!(col.state().committed_len(), 6);
+    for idx in 0..6 {
+        assert_eq!(col.try_get_published(idx), Some(&idx.to_string()));
+    }
+}
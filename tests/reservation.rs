@@ -0,0 +1,190 @@
+mod common;
+
+use common::{
+    DropCounter, NUM_ITEMS_PER_THREAD, NUM_THREADS, SyncCol, doubling_vec, fixed_vec, linear_vec,
+};
+use orx_fixed_vec::FixedVec;
+use orx_pinned_concurrent_col::states::*;
+use orx_pinned_concurrent_col::*;
+use orx_pinned_vec::PinnedVec;
+use orx_split_vec::SplitVec;
+use prelude::IntoConcurrentPinnedVec;
+use std::sync::{
+    Arc,
+    atomic::{AtomicUsize, Ordering},
+};
+use test_case::test_matrix;
+
+#[test_matrix([fixed_vec(), doubling_vec(), linear_vec()])]
+fn reserve_and_push<P: IntoConcurrentPinnedVec<String>>(vec: P) {
+    let col: PinnedConcurrentCol<_, _, OrderedState<_>> = PinnedConcurrentCol::new_from_pinned(vec);
+
+    let mut reservation = unsafe { col.reserve_n_items(0, 100) };
+    assert_eq!(reservation.range(), 0..100);
+    assert_eq!(reservation.len(), 100);
+    assert!(col.capacity() >= 100);
+
+    for i in 0..100 {
+        assert_eq!(reservation.num_initialized(), i);
+        assert_eq!(reservation.push(i.to_string()), Ok(()));
+    }
+    assert!(reservation.is_complete());
+    assert_eq!(reservation.push(100.to_string()), Err(100.to_string()));
+
+    assert_eq!(col.state().num_written(), 0);
+    assert_eq!(reservation.finish(), ReservationOutcome::Complete);
+    assert_eq!(col.state().num_written(), 100);
+
+    let vec = col.try_into_inner(100).expect("is gap-free");
+    for i in 0..100 {
+        assert_eq!(vec.get(i), Some(&i.to_string()));
+    }
+}
+
+#[test_matrix([fixed_vec(), doubling_vec(), linear_vec()])]
+fn reserve_and_write_uninit_slices<P: IntoConcurrentPinnedVec<String>>(vec: P) {
+    let col: PinnedConcurrentCol<_, _, OrderedState<_>> = PinnedConcurrentCol::new_from_pinned(vec);
+
+    let mut reservation = unsafe { col.reserve_n_items(10, 90) };
+    assert_eq!(reservation.push(10.to_string()), Ok(()));
+
+    let mut values = (11..70).map(|x| x.to_string());
+    for slice in reservation.uninit_slices_mut() {
+        for (x, value) in slice.iter_mut().zip(&mut values) {
+            x.write(value);
+        }
+    }
+    unsafe { reservation.assume_init(59) };
+
+    assert_eq!(reservation.num_initialized(), 60);
+    assert_eq!(reservation.num_remaining(), 30);
+    assert_eq!(
+        reservation.finish(),
+        ReservationOutcome::Partial {
+            num_initialized: 60
+        }
+    );
+
+    for i in 0..100 {
+        assert_eq!(col.is_written(i), Some((10..70).contains(&i)));
+        if (10..70).contains(&i) {
+            assert_eq!(unsafe { col.get(i) }, Some(&i.to_string()));
+        }
+    }
+}
+
+#[test]
+fn commit_on_drop() {
+    let col: PinnedConcurrentCol<String, _, AppendOnlyState<_>> =
+        PinnedConcurrentCol::with_doubling_growth();
+
+    let begin_idx = col.state().fetch_increment_len(3);
+    {
+        let mut reservation = unsafe { col.reserve_n_items(begin_idx, 3) };
+        for i in 0..3 {
+            assert_eq!(reservation.push(i.to_string()), Ok(()));
+        }
+    }
+
+    let vec = unsafe { col.into_inner(3) };
+    for i in 0..3 {
+        assert_eq!(vec.get(i), Some(&i.to_string()));
+    }
+}
+
+#[test]
+fn panic_in_producer() {
+    let num_dropped = Arc::new(AtomicUsize::new(0));
+
+    let col: PinnedConcurrentCol<_, _, OrderedState<_>> =
+        PinnedConcurrentCol::with_doubling_growth();
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        let mut reservation = unsafe { col.reserve_n_items(0, 10) };
+        for i in 0..10 {
+            if i == 4 {
+                panic!("producer failed");
+            }
+            let _ = reservation.push(DropCounter::new(0, &num_dropped));
+        }
+    }));
+    assert!(result.is_err());
+
+    assert_eq!(col.state().num_written(), 4);
+    for i in 0..10 {
+        assert_eq!(col.is_written(i), Some(i < 4));
+    }
+
+    drop(col);
+    assert_eq!(num_dropped.load(Ordering::Relaxed), 4);
+}
+
+#[test]
+fn empty_reservation() {
+    let col: PinnedConcurrentCol<String, _, OrderedState<_>> =
+        PinnedConcurrentCol::with_fixed_capacity(0);
+
+    let reservation = unsafe { col.try_reserve_n_items(0, 0) }.expect("empty reservation");
+    assert!(reservation.is_empty());
+    assert!(reservation.is_complete());
+    assert_eq!(reservation.finish(), ReservationOutcome::Complete);
+    assert_eq!(col.state().num_written(), 0);
+}
+
+#[test]
+fn try_reserve_out_of_capacity() {
+    let col: PinnedConcurrentCol<String, _, OrderedState<_>> =
+        PinnedConcurrentCol::with_fixed_capacity(10);
+
+    let result = unsafe { col.try_reserve_n_items(5, 6) };
+    assert_eq!(
+        result.err(),
+        Some(PinnedConcurrentColError::OutOfMaximumCapacity {
+            required_capacity: 11,
+            maximum_capacity: 10
+        })
+    );
+
+    let result = unsafe { col.try_reserve_n_items(5, 5) };
+    assert!(result.is_ok());
+}
+
+#[test_matrix([
+    FixedVec::new(4096),
+    SplitVec::with_doubling_growth_and_fragments_capacity(16),
+    SplitVec::with_linear_growth_and_fragments_capacity(4, 256)
+], [1, 7, 64])]
+fn concurrent_reservations<P: IntoConcurrentPinnedVec<String>>(vec: P, n: usize) {
+    let col: PinnedConcurrentCol<_, _, AppendOnlyState<_>> =
+        PinnedConcurrentCol::new_from_pinned(vec);
+    let col = SyncCol(col);
+    let shared = &col;
+
+    std::thread::scope(|s| {
+        for _ in 0..NUM_THREADS {
+            s.spawn(move || {
+                let col = &shared.0;
+                for _ in 0..(NUM_ITEMS_PER_THREAD / n) {
+                    let begin_idx = col.state().fetch_increment_len(n);
+                    let mut reservation = unsafe { col.reserve_n_items(begin_idx, n) };
+                    let mut idx = begin_idx;
+                    for slice in reservation.uninit_slices_mut() {
+                        for x in slice.iter_mut() {
+                            x.write(idx.to_string());
+                            idx += 1;
+                        }
+                    }
+                    unsafe { reservation.assume_init(n) };
+                    assert_eq!(reservation.finish(), ReservationOutcome::Complete);
+                }
+            });
+        }
+    });
+
+    let col = col.0;
+    let len = col.state().len();
+    assert_eq!(len, NUM_THREADS * (NUM_ITEMS_PER_THREAD / n) * n);
+    for idx in 0..len {
+        assert_eq!(unsafe { col.get(idx) }, Some(&idx.to_string()));
+    }
+}
@@ -1,3 +1,5 @@
+#![allow(clippy::int_plus_one)]
+
 mod state;
 use orx_fixed_vec::FixedVec;
 use orx_pinned_concurrent_col::*;
@@ -49,3 +51,39 @@ fn reserve_fill_with<P: IntoConcurrentPinnedVec<String>>(mut vec: P) {
     assert!(new_capacity >= max_cap + 1);
     assert!(col.capacity() >= initial_capacity);
 }
+
+#[test]
+fn reserve_after_out_of_maximum_capacity() {
+    let mut col: PinnedConcurrentCol<String, _, MyConState<_>> =
+        PinnedConcurrentCol::with_linear_growth(2, 3);
+    assert_eq!(col.maximum_capacity(), 12);
+
+    let mut len = 0;
+    while let Ok(()) = unsafe { col.try_write(len, len.to_string()) } {
+        len += 1;
+    }
+    assert_eq!(len, 12);
+    let error =
+        unsafe { col.try_write(len, len.to_string()) }.expect_err("out of maximum capacity");
+    assert_eq!(
+        error.error,
+        PinnedConcurrentColError::OutOfMaximumCapacity {
+            required_capacity: 13,
+            maximum_capacity: 12
+        }
+    );
+
+    let new_capacity = unsafe { col.reserve_maximum_capacity(len, 40) };
+    assert!(new_capacity >= 40);
+
+    // the value of the failed request is given back, and hence, it can be written after the reservation
+    unsafe { col.write(len, error.into_value()) };
+    for idx in (len + 1)..40 {
+        unsafe { col.write(idx, idx.to_string()) };
+    }
+    for idx in 0..40 {
+        assert_eq!(unsafe { col.get(idx) }, Some(&idx.to_string()));
+    }
+
+    col.state().set_final_len(40);
+}
@@ -0,0 +1,117 @@
+mod common;
+
+use common::{DropCounter, NUM_ITEMS_PER_THREAD, NUM_THREADS};
+use orx_pinned_concurrent_col::*;
+use std::sync::{
+    Arc,
+    atomic::{AtomicUsize, Ordering},
+};
+use test_case::test_case;
+
+#[test]
+fn new() {
+    let ring: ConcurrentRing<String> = ConcurrentRing::new(3);
+    assert_eq!(ring.capacity(), 3);
+    assert_eq!(ring.len(), 0);
+    assert!(ring.is_empty());
+    assert!(!ring.is_full());
+}
+
+#[test]
+#[should_panic]
+fn new_zero_capacity() {
+    let _ring: ConcurrentRing<String> = ConcurrentRing::new(0);
+}
+
+#[test_case(1)]
+#[test_case(2)]
+#[test_case(5)]
+fn push_pop_wraps_around(capacity: usize) {
+    let ring = ConcurrentRing::new(capacity);
+    assert_eq!(ring.try_pop(), None);
+
+    let mut next_push = 0;
+    let mut next_pop = 0;
+    for round in 0..10 {
+        while ring.try_push(next_push.to_string()).is_ok() {
+            next_push += 1;
+        }
+        assert!(ring.is_full());
+        assert_eq!(ring.len(), capacity);
+        assert_eq!(ring.try_push("x".to_string()), Err("x".to_string()));
+
+        // pop a varying number of values so that the front moves around the slots
+        for _ in 0..(round % capacity + 1) {
+            assert_eq!(ring.try_pop(), Some(next_pop.to_string()));
+            next_pop += 1;
+        }
+    }
+
+    while let Some(value) = ring.try_pop() {
+        assert_eq!(value, next_pop.to_string());
+        next_pop += 1;
+    }
+    assert_eq!(next_pop, next_push);
+    assert!(ring.is_empty());
+}
+
+#[test]
+fn drops_remaining_values_once() {
+    let num_dropped = Arc::new(AtomicUsize::new(0));
+    let ring = ConcurrentRing::new(4);
+    for _ in 0..4 {
+        ring.push(DropCounter::new(0, &num_dropped));
+    }
+    drop(ring.pop());
+    assert_eq!(num_dropped.load(Ordering::Relaxed), 1);
+
+    ring.push(DropCounter::new(0, &num_dropped));
+    drop(ring);
+    assert_eq!(num_dropped.load(Ordering::Relaxed), 5);
+}
+
+#[test_case(1)]
+#[test_case(3)]
+#[test_case(64)]
+fn concurrent_producers_and_consumers(capacity: usize) {
+    let ring = ConcurrentRing::new(capacity);
+    let num_items = NUM_THREADS * NUM_ITEMS_PER_THREAD;
+
+    let mut popped: Vec<_> = std::thread::scope(|s| {
+        let ring = &ring;
+        for t in 0..NUM_THREADS {
+            s.spawn(move || {
+                for i in 0..NUM_ITEMS_PER_THREAD {
+                    ring.push(t * NUM_ITEMS_PER_THREAD + i);
+                }
+            });
+        }
+
+        let consumers: Vec<_> = (0..NUM_THREADS)
+            .map(|_| {
+                s.spawn(move || {
+                    let mut popped = vec![];
+                    let mut last_per_producer = [None; NUM_THREADS];
+                    for _ in 0..NUM_ITEMS_PER_THREAD {
+                        let value = ring.pop();
+                        // values of each producer are popped in the order they are pushed
+                        let producer = value / NUM_ITEMS_PER_THREAD;
+                        assert!(last_per_producer[producer] < Some(value));
+                        last_per_producer[producer] = Some(value);
+                        popped.push(value);
+                    }
+                    popped
+                })
+            })
+            .collect();
+
+        consumers
+            .into_iter()
+            .flat_map(|x| x.join().expect("consumer panicked"))
+            .collect()
+    });
+
+    popped.sort();
+    assert_eq!(popped, (0..num_items).collect::<Vec<_>>());
+    assert!(ring.is_empty());
+}
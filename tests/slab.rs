@@ -0,0 +1,206 @@
+mod common;
+
+use common::{DropCounter, NUM_ITEMS_PER_THREAD, NUM_THREADS};
+use orx_pinned_concurrent_col::*;
+use orx_pinned_vec::ConcurrentPinnedVec;
+use std::{
+    collections::HashSet,
+    sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    },
+};
+
+#[test]
+fn new() {
+    let slab: ConcurrentSlab<String> = ConcurrentSlab::new();
+    assert!(slab.is_empty());
+    assert_eq!(slab.len(), 0);
+    assert_eq!(slab.iter().count(), 0);
+
+    let slab: ConcurrentSlab<String, _> = ConcurrentSlab::with_linear_growth(4, 10);
+    assert_eq!(slab.maximum_capacity(), 160);
+
+    let slab: ConcurrentSlab<String, _> = ConcurrentSlab::with_fixed_capacity(42);
+    assert_eq!(slab.capacity(), 42);
+}
+
+fn insert_get_remove<P: ConcurrentPinnedVec<SlabSlot<String>>>(slab: ConcurrentSlab<String, P>) {
+    let keys: Vec<_> = (0..10).map(|i| slab.insert(i.to_string())).collect();
+    assert_eq!(slab.len(), 10);
+    for (i, key) in keys.iter().enumerate() {
+        assert_eq!(key.index(), i);
+        assert_eq!(key.generation(), 0);
+        assert_eq!(slab.get(*key).as_deref(), Some(&i.to_string()));
+    }
+
+    assert_eq!(slab.remove(keys[3]), Some(3.to_string()));
+    assert_eq!(slab.remove(keys[7]), Some(7.to_string()));
+    assert_eq!(slab.remove(keys[3]), None);
+    assert!(slab.get(keys[3]).is_none());
+    assert!(!slab.contains_key(keys[7]));
+    assert_eq!(slab.len(), 8);
+
+    // vacated slots are reused in LIFO order with incremented generations
+    let a = slab.insert("a".to_string());
+    let b = slab.insert("b".to_string());
+    let c = slab.insert("c".to_string());
+    assert_eq!((a.index(), a.generation()), (7, 1));
+    assert_eq!((b.index(), b.generation()), (3, 1));
+    assert_eq!((c.index(), c.generation()), (10, 0));
+    assert_eq!(slab.len(), 11);
+
+    assert!(slab.get(keys[7]).is_none());
+    assert_eq!(slab.remove(keys[7]), None);
+    assert_eq!(slab.get(a).as_deref(), Some(&"a".to_string()));
+
+    let mut entries: Vec<_> = slab.iter().map(|(k, x)| (k, x.clone())).collect();
+    entries.sort();
+    assert_eq!(entries.len(), 11);
+    assert_eq!(entries[3], (b, "b".to_string()));
+    assert_eq!(entries[7], (a, "a".to_string()));
+}
+
+#[test]
+fn insert_get_remove_doubling() {
+    insert_get_remove(ConcurrentSlab::with_doubling_growth());
+}
+
+#[test]
+fn insert_get_remove_linear() {
+    insert_get_remove(ConcurrentSlab::with_linear_growth(2, 16));
+}
+
+#[test]
+fn insert_get_remove_fixed() {
+    insert_get_remove(ConcurrentSlab::with_fixed_capacity(11));
+}
+
+#[test]
+fn get_mut() {
+    let mut slab = ConcurrentSlab::new();
+    let key = slab.insert(1);
+    *slab.get_mut(key).expect("exists") += 10;
+    assert_eq!(slab.get(key).as_deref(), Some(&11));
+
+    slab.remove(key);
+    assert_eq!(slab.get_mut(key), None);
+}
+
+#[test]
+fn try_insert_out_of_maximum_capacity() {
+    let slab = ConcurrentSlab::with_fixed_capacity(2);
+    let key = slab.insert(0);
+    slab.insert(1);
+    assert_eq!(
+        slab.try_insert(2),
+        Err(WriteError {
+            error: PinnedConcurrentColError::OutOfMaximumCapacity {
+                required_capacity: 3,
+                maximum_capacity: 2
+            },
+            value: 2
+        })
+    );
+
+    slab.remove(key);
+    assert!(slab.try_insert(3).is_ok());
+    assert_eq!(slab.len(), 2);
+}
+
+#[cfg(target_has_atomic = "64")]
+#[test]
+fn maximum_capacity_is_bounded_by_free_list_index() {
+    let slab: ConcurrentSlab<String> = ConcurrentSlab::new();
+    assert!(slab.maximum_capacity() <= u32::MAX as usize);
+}
+
+#[test]
+fn drops_entries_once() {
+    let num_dropped = Arc::new(AtomicUsize::new(0));
+    let slab = ConcurrentSlab::new();
+    let keys: Vec<_> = (0..10)
+        .map(|_| slab.insert(DropCounter::new(0, &num_dropped)))
+        .collect();
+
+    for key in keys.iter().take(4) {
+        drop(slab.remove(*key));
+    }
+    assert_eq!(num_dropped.load(Ordering::Relaxed), 4);
+
+    slab.insert(DropCounter::new(0, &num_dropped));
+    drop(slab);
+    assert_eq!(num_dropped.load(Ordering::Relaxed), 11);
+}
+
+#[test]
+fn concurrent_insert_remove_and_get() {
+    let slab = ConcurrentSlab::with_linear_growth(4, 1024);
+
+    let keys: Vec<_> = std::thread::scope(|s| {
+        let slab = &slab;
+        let handles: Vec<_> = (0..NUM_THREADS)
+            .map(|t| {
+                s.spawn(move || {
+                    let mut kept = vec![];
+                    for i in 0..NUM_ITEMS_PER_THREAD {
+                        let value = t * NUM_ITEMS_PER_THREAD + i;
+                        let key = slab.insert(value);
+                        assert_eq!(slab.get(key).as_deref(), Some(&value));
+                        match i % 3 {
+                            0 => kept.push((key, value)),
+                            _ => assert_eq!(slab.remove(key), Some(value)),
+                        }
+                    }
+                    kept
+                })
+            })
+            .collect();
+
+        // readers of the entries while the writers insert and remove
+        s.spawn(move || {
+            for _ in 0..8 {
+                for (key, value) in slab.iter() {
+                    assert_eq!(slab.get(key).as_deref(), Some(&*value));
+                }
+            }
+        });
+
+        handles
+            .into_iter()
+            .flat_map(|x| x.join().expect("writer panicked"))
+            .collect()
+    });
+
+    assert_eq!(slab.len(), keys.len());
+    let indices: HashSet<_> = keys.iter().map(|(k, _)| k.index()).collect();
+    assert_eq!(indices.len(), keys.len());
+    assert!(slab.capacity() < NUM_THREADS * NUM_ITEMS_PER_THREAD);
+    for (key, value) in keys {
+        assert_eq!(slab.get(key).as_deref(), Some(&value));
+    }
+}
+
+#[test]
+fn remove_waits_for_readers() {
+    let slab = ConcurrentSlab::new();
+    let key = slab.insert("x".to_string());
+    let removed = AtomicUsize::new(0);
+
+    std::thread::scope(|s| {
+        let reference = slab.get(key).expect("exists");
+        let (slab, removed) = (&slab, &removed);
+        s.spawn(move || {
+            assert_eq!(slab.remove(key), Some("x".to_string()));
+            removed.store(1, Ordering::Release);
+        });
+
+        std::thread::sleep(std::time::Duration::from_millis(20));
+        assert_eq!(removed.load(Ordering::Acquire), 0);
+        assert_eq!(&*reference, "x");
+        drop(reference);
+    });
+
+    assert_eq!(removed.load(Ordering::Acquire), 1);
+    assert!(slab.is_empty());
+}
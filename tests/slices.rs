@@ -0,0 +1,196 @@
+mod common;
+
+use common::{NUM_THREADS, SyncCol};
+use orx_fixed_vec::FixedVec;
+use orx_pinned_concurrent_col::states::*;
+use orx_pinned_concurrent_col::*;
+use orx_split_vec::SplitVec;
+use prelude::IntoConcurrentPinnedVec;
+use test_case::test_matrix;
+
+#[cfg(not(miri))]
+const LEN: usize = 2048;
+#[cfg(miri)]
+const LEN: usize = 157;
+
+fn written_col<P: IntoConcurrentPinnedVec<usize>>(
+    vec: P,
+) -> PinnedConcurrentCol<usize, P::ConPinnedVec, OrderedState<usize>> {
+    let col: PinnedConcurrentCol<_, _, OrderedState<_>> = PinnedConcurrentCol::new_from_pinned(vec);
+    unsafe { col.write_n_items(0, LEN, 0..LEN) };
+    col
+}
+
+#[test]
+fn committed_slices_not_maintained_by_default() {
+    let col: PinnedConcurrentCol<usize, _, AppendOnlyState<_>> =
+        PinnedConcurrentCol::with_doubling_growth();
+    unsafe { col.write_n_items(0, 10, 0..10) };
+
+    assert_eq!(col.committed_slices().count(), 0);
+    assert!(col.committed_slice_producer().is_empty());
+}
+
+#[test]
+fn committed_slices_stop_at_gap() {
+    let col: PinnedConcurrentCol<usize, _, OrderedState<_>> =
+        PinnedConcurrentCol::with_doubling_growth();
+    unsafe { col.write_n_items(0, 6, 0..6) };
+    unsafe { col.write(7, 7) };
+
+    let slices: Vec<_> = col.committed_slices().collect();
+    assert_eq!(slices, [&[0, 1, 2, 3][..], &[4, 5]]);
+
+    let producer = col.committed_slice_producer();
+    assert_eq!(producer.len(), 6);
+    assert_eq!(producer.num_slices(), 2);
+}
+
+#[test_matrix([
+    FixedVec::new(LEN),
+    SplitVec::with_doubling_growth_and_fragments_capacity(16),
+    SplitVec::with_linear_growth_and_fragments_capacity(4, 256)
+])]
+fn committed_slices_across_fragments<P: IntoConcurrentPinnedVec<usize>>(vec: P) {
+    let col = written_col(vec);
+
+    let slices: Vec<_> = col.committed_slices().into_iter().collect();
+    assert!(slices.iter().all(|x| !x.is_empty()));
+    let values: Vec<_> = slices.iter().flat_map(|x| x.iter().copied()).collect();
+    assert_eq!(values, (0..LEN).collect::<Vec<_>>());
+
+    let producer = col.committed_slice_producer();
+    assert_eq!(producer.len(), LEN);
+    assert_eq!(producer.num_slices(), slices.len());
+    assert_eq!(producer.slices().collect::<Vec<_>>(), slices);
+}
+
+#[test_matrix([
+    FixedVec::new(LEN),
+    SplitVec::with_doubling_growth_and_fragments_capacity(16),
+    SplitVec::with_linear_growth_and_fragments_capacity(4, 256)
+], [(0, 0), (0, 1), (3, 17), (5, 100), (100, LEN), (0, LEN)])]
+fn slices_over_range<P: IntoConcurrentPinnedVec<usize>>(vec: P, range: (usize, usize)) {
+    let col = written_col(vec);
+    let (begin, end) = range;
+
+    let values: Vec<_> = unsafe { col.slices(begin..end) }
+        .into_iter()
+        .flat_map(|x| x.iter().copied())
+        .collect();
+    assert_eq!(values, (begin..end).collect::<Vec<_>>());
+
+    let producer = unsafe { col.slice_producer(begin..end) };
+    assert_eq!(producer.len(), end - begin);
+    assert_eq!(producer.into_iter().copied().collect::<Vec<_>>(), values);
+}
+
+#[test_matrix([
+    FixedVec::new(LEN),
+    SplitVec::with_doubling_growth_and_fragments_capacity(16),
+    SplitVec::with_linear_growth_and_fragments_capacity(4, 256)
+], [0, 1, 4, 5, 33, LEN - 1, LEN])]
+fn split_at<P: IntoConcurrentPinnedVec<usize>>(vec: P, index: usize) {
+    let col = written_col(vec);
+    let producer = col.committed_slice_producer();
+
+    let (left, right) = producer.clone().split_at(index);
+    assert_eq!(left.len(), index);
+    assert_eq!(right.len(), LEN - index);
+    assert!(left.slices().chain(right.slices()).all(|x| !x.is_empty()));
+    assert!(left.num_slices() + right.num_slices() <= producer.num_slices() + 1);
+
+    let values: Vec<_> = left.into_iter().chain(right).copied().collect();
+    assert_eq!(values, (0..LEN).collect::<Vec<_>>());
+}
+
+#[test]
+#[should_panic]
+fn split_at_out_of_bounds() {
+    let col = written_col(SplitVec::with_doubling_growth_and_max_concurrent_capacity());
+    let _ = col.committed_slice_producer().split_at(LEN + 1);
+}
+
+#[test]
+fn iter_is_exact_size_and_double_ended() {
+    let col = written_col(SplitVec::with_doubling_growth_and_max_concurrent_capacity());
+    let mut iter = col.committed_slice_producer().into_iter();
+
+    let (mut front, mut back) = (0, LEN);
+    for i in 0..LEN {
+        assert_eq!(iter.len(), back - front);
+        match i % 3 {
+            0 => {
+                back -= 1;
+                assert_eq!(iter.next_back(), Some(&back));
+            }
+            _ => {
+                assert_eq!(iter.next(), Some(&front));
+                front += 1;
+            }
+        }
+    }
+    assert_eq!(iter.len(), 0);
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+}
+
+#[test_matrix([
+    FixedVec::new(LEN),
+    SplitVec::with_doubling_growth_and_fragments_capacity(16),
+    SplitVec::with_linear_growth_and_fragments_capacity(4, 256)
+])]
+fn split_among_threads<P: IntoConcurrentPinnedVec<usize>>(vec: P) {
+    let col = SyncCol(written_col(vec));
+
+    let mut parts = vec![col.0.committed_slice_producer()];
+    while parts.len() < NUM_THREADS {
+        let largest = parts.remove(0);
+        let mid = largest.len() / 2;
+        let (left, right) = largest.split_at(mid);
+        parts.extend([left, right]);
+    }
+
+    let sums: Vec<usize> = std::thread::scope(|s| {
+        let handles: Vec<_> = parts
+            .into_iter()
+            .map(|part| s.spawn(move || part.slices().map(|x| x.iter().sum::<usize>()).sum()))
+            .collect();
+        handles.into_iter().map(|x| x.join().unwrap()).collect()
+    });
+    assert_eq!(sums.iter().sum::<usize>(), (0..LEN).sum());
+}
+
+#[cfg(feature = "rayon")]
+#[test_matrix([
+    FixedVec::new(LEN),
+    SplitVec::with_doubling_growth_and_fragments_capacity(16),
+    SplitVec::with_linear_growth_and_fragments_capacity(4, 256)
+])]
+fn into_par_iter<P: IntoConcurrentPinnedVec<usize>>(vec: P) {
+    use rayon::prelude::*;
+
+    let col = written_col(vec);
+
+    let par_iter = col.committed_slice_producer().into_par_iter();
+    assert_eq!(par_iter.len(), LEN);
+    assert_eq!(par_iter.sum::<usize>(), (0..LEN).sum());
+
+    let doubled: Vec<_> = col
+        .committed_slice_producer()
+        .into_par_iter()
+        .map(|x| x * 2)
+        .collect();
+    assert_eq!(doubled, (0..LEN).map(|x| x * 2).collect::<Vec<_>>());
+
+    let evens: Vec<_> = unsafe { col.slice_producer(10..LEN - 10) }
+        .into_par_iter()
+        .with_min_len(7)
+        .filter(|x| *x % 2 == 0)
+        .copied()
+        .collect();
+    assert_eq!(
+        evens,
+        (10..LEN - 10).filter(|x| x % 2 == 0).collect::<Vec<_>>()
+    );
+}
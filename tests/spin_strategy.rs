@@ -0,0 +1,172 @@
+mod common;
+mod state;
+
+use common::{SyncCol, doubling_vec, fixed_vec, linear_vec};
+use orx_pinned_concurrent_col::*;
+use orx_pinned_vec::{ConcurrentPinnedVec, PinnedVec};
+use prelude::IntoConcurrentPinnedVec;
+use state::MyConState;
+use std::sync::{
+    Mutex,
+    atomic::{AtomicUsize, Ordering},
+};
+use test_case::test_matrix;
+
+/// Append-only state recording the spin strategies that the writers are asked to follow after writing.
+#[derive(Default)]
+struct RecordingState {
+    len: AtomicUsize,
+    strategies: Mutex<Vec<SpinStrategy>>,
+}
+
+impl ConcurrentState<String> for RecordingState {
+    fn fill_memory_with(&self) -> Option<MemoryFill<String>> {
+        None
+    }
+
+    fn new_for_pinned_vec<P: PinnedVec<String>>(pinned_vec: &P) -> Self {
+        Self {
+            len: pinned_vec.len().into(),
+            strategies: Default::default(),
+        }
+    }
+
+    fn new_for_con_pinned_vec<P: ConcurrentPinnedVec<String>>(_: &P, len: usize) -> Self {
+        Self {
+            len: len.into(),
+            strategies: Default::default(),
+        }
+    }
+
+    fn write_permit<P>(&self, col: &PinnedConcurrentCol<String, P, Self>, idx: usize) -> WritePermit
+    where
+        P: ConcurrentPinnedVec<String>,
+    {
+        match idx.cmp(&col.capacity()) {
+            std::cmp::Ordering::Less => WritePermit::JustWrite,
+            std::cmp::Ordering::Equal => WritePermit::GrowThenWrite,
+            std::cmp::Ordering::Greater => WritePermit::Spin,
+        }
+    }
+
+    fn release_growth_handle(&self) {}
+
+    fn update_after_write(&self, _: usize, _: usize, spin_strategy: SpinStrategy) {
+        self.strategies.lock().unwrap().push(spin_strategy);
+    }
+
+    fn try_get_no_gap_len(&self) -> Option<usize> {
+        Some(self.len.load(Ordering::Relaxed))
+    }
+}
+
+fn strategies() -> Vec<SpinStrategy> {
+    vec![
+        SpinStrategy::Spin,
+        SpinStrategy::ExponentialBackoff { max_exponent: 6 },
+        SpinStrategy::YieldAfter { num_spins: 4 },
+    ]
+}
+
+#[test]
+fn default_spin_strategy() {
+    let col: PinnedConcurrentCol<String, _, MyConState<_>> =
+        PinnedConcurrentCol::with_doubling_growth();
+    assert_eq!(col.spin_strategy(), SpinStrategy::Spin);
+    assert_eq!(
+        ConcurrentState::<String>::spin_strategy(col.state()),
+        SpinStrategy::Spin
+    );
+}
+
+#[test]
+fn set_spin_strategy() {
+    for strategy in strategies() {
+        let mut col: PinnedConcurrentCol<String, _, MyConState<_>> =
+            PinnedConcurrentCol::with_doubling_growth();
+        col.set_spin_strategy(strategy);
+        assert_eq!(col.spin_strategy(), strategy);
+
+        let clone = unsafe { col.clone_with_len(0) };
+        assert_eq!(clone.spin_strategy(), strategy);
+    }
+}
+
+#[test]
+fn update_after_write_follows_spin_strategy_of_col() {
+    for strategy in strategies() {
+        let mut col: PinnedConcurrentCol<String, _, RecordingState> =
+            PinnedConcurrentCol::with_doubling_growth();
+        col.set_spin_strategy(strategy);
+
+        unsafe { col.write(0, 0.to_string()) };
+        unsafe { col.write_n_items(1, 3, (1..4).map(|x| x.to_string())) };
+
+        let recorded = col.state().strategies.lock().unwrap().clone();
+        assert_eq!(recorded, [strategy, strategy]);
+    }
+}
+
+#[test]
+fn backoff_steps() {
+    for strategy in strategies() {
+        let mut backoff = strategy.backoff();
+        assert_eq!(backoff.num_steps(), 0);
+
+        for _ in 0..10 {
+            backoff.spin();
+        }
+        assert_eq!(backoff.num_steps(), 10);
+
+        backoff.reset();
+        assert_eq!(backoff.num_steps(), 0);
+    }
+}
+
+#[test_matrix([fixed_vec(), doubling_vec(), linear_vec()])]
+fn concurrent_write_with_spin_strategies<P: IntoConcurrentPinnedVec<String> + Clone>(vec: P) {
+    let num_threads = 4;
+
+    #[cfg(not(miri))]
+    let num_items_per_thread = 512;
+    #[cfg(miri)]
+    let num_items_per_thread = 16;
+
+    for strategy in strategies() {
+        let mut col: PinnedConcurrentCol<_, _, MyConState<_>> =
+            PinnedConcurrentCol::new_from_pinned(vec.clone());
+        col.set_spin_strategy(strategy);
+        let col = SyncCol(col);
+        let shared = &col;
+
+        std::thread::scope(|s| {
+            for _ in 0..num_threads {
+                s.spawn(move || {
+                    let col = &shared.0;
+                    for _ in 0..num_items_per_thread {
+                        let idx = col.state().fetch_increment_len(1);
+                        unsafe { col.write(idx, idx.to_string()) };
+                    }
+                });
+            }
+        });
+
+        let col = col.0;
+        let len = num_threads * num_items_per_thread;
+        for idx in 0..len {
+            assert_eq!(unsafe { col.get(idx) }, Some(&idx.to_string()));
+        }
+    }
+}
+
+#[test]
+fn long_wait_spin_strategy() {
+    assert_eq!(
+        SpinStrategy::LONG_WAIT,
+        SpinStrategy::YieldAfter { num_spins: 64 }
+    );
+
+    let col: PinnedConcurrentCol<String, _, states::LogState<_>> =
+        PinnedConcurrentCol::with_doubling_growth();
+    assert_eq!(col.spin_strategy(), SpinStrategy::LONG_WAIT);
+}
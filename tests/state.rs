@@ -1,10 +1,11 @@
 use orx_pinned_concurrent_col::*;
 use orx_pinned_vec::{ConcurrentPinnedVec, PinnedVec};
-use std::{
-    cmp::Ordering,
-    marker::PhantomData,
-    sync::atomic::{self, AtomicUsize},
-};
+use std::{cmp::Ordering, marker::PhantomData};
+
+#[cfg(loom)]
+use loom::sync::atomic::{self, AtomicUsize};
+#[cfg(not(loom))]
+use std::sync::atomic::{self, AtomicUsize};
 
 #[derive(Debug)]
 #[allow(dead_code)]
@@ -37,12 +38,12 @@ impl<T> MyConState<T> {
 
     #[allow(dead_code)]
     pub fn set_final_len(&self, len: usize) {
-        self.len.store(len, std::sync::atomic::Ordering::Relaxed);
+        self.len.store(len, atomic::Ordering::Relaxed);
     }
 }
 
 impl<T> ConcurrentState<T> for MyConState<T> {
-    fn fill_memory_with(&self) -> Option<fn() -> T> {
+    fn fill_memory_with(&self) -> Option<MemoryFill<T>> {
         None
     }
 
@@ -88,7 +89,7 @@ impl<T> ConcurrentState<T> for MyConState<T> {
 
     fn release_growth_handle(&self) {}
 
-    fn update_after_write(&self, _: usize, _: usize) {}
+    fn update_after_write(&self, _: usize, _: usize, _: SpinStrategy) {}
 
     fn try_get_no_gap_len(&self) -> Option<usize> {
         Some(self.len())
@@ -128,13 +129,13 @@ impl<T: Default> MyConStateFilled<T> {
 
     #[allow(dead_code)]
     pub fn set_final_len(&self, len: usize) {
-        self.len.store(len, std::sync::atomic::Ordering::Relaxed);
+        self.len.store(len, atomic::Ordering::Relaxed);
     }
 }
 
 impl<T: Default> ConcurrentState<T> for MyConStateFilled<T> {
-    fn fill_memory_with(&self) -> Option<fn() -> T> {
-        Some(|| Default::default())
+    fn fill_memory_with(&self) -> Option<MemoryFill<T>> {
+        Some(MemoryFill::from_default())
     }
 
     fn new_for_pinned_vec<P: PinnedVec<T>>(pinned_vec: &P) -> Self {
@@ -179,9 +180,64 @@ impl<T: Default> ConcurrentState<T> for MyConStateFilled<T> {
 
     fn release_growth_handle(&self) {}
 
-    fn update_after_write(&self, _: usize, _: usize) {}
+    fn update_after_write(&self, _: usize, _: usize, _: SpinStrategy) {}
 
     fn try_get_no_gap_len(&self) -> Option<usize> {
         Some(self.len())
     }
 }
+
+// COMMITTED
+
+/// Append-only state which neither fills the memory nor keeps track of the written positions, but maintains the committed length.
+#[derive(Debug)]
+#[allow(dead_code)]
+pub struct MyConStateCommitted<T> {
+    pub len: AtomicUsize,
+    phantom: PhantomData<T>,
+}
+
+impl<T> ConcurrentState<T> for MyConStateCommitted<T> {
+    fn fill_memory_with(&self) -> Option<MemoryFill<T>> {
+        None
+    }
+
+    fn new_for_pinned_vec<P: PinnedVec<T>>(pinned_vec: &P) -> Self {
+        Self {
+            len: pinned_vec.len().into(),
+            phantom: Default::default(),
+        }
+    }
+
+    fn new_for_con_pinned_vec<P: ConcurrentPinnedVec<T>>(_: &P, len: usize) -> Self {
+        Self {
+            len: len.into(),
+            phantom: Default::default(),
+        }
+    }
+
+    fn write_permit<P>(&self, col: &PinnedConcurrentCol<T, P, Self>, idx: usize) -> WritePermit
+    where
+        P: ConcurrentPinnedVec<T>,
+    {
+        match idx.cmp(&col.capacity()) {
+            Ordering::Less => WritePermit::JustWrite,
+            Ordering::Equal => WritePermit::GrowThenWrite,
+            Ordering::Greater => WritePermit::Spin,
+        }
+    }
+
+    fn release_growth_handle(&self) {}
+
+    fn update_after_write(&self, _: usize, end_idx: usize, _: SpinStrategy) {
+        self.len.fetch_max(end_idx, atomic::Ordering::AcqRel);
+    }
+
+    fn track_committed_len(&self) -> bool {
+        true
+    }
+
+    fn try_get_no_gap_len(&self) -> Option<usize> {
+        Some(self.len.load(atomic::Ordering::Acquire))
+    }
+}
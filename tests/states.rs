@@ -0,0 +1,158 @@
+mod common;
+
+use common::{NUM_ITEMS_PER_THREAD, NUM_THREADS, SyncCol, doubling_vec, fixed_vec, linear_vec};
+use orx_fixed_vec::FixedVec;
+use orx_pinned_concurrent_col::states::*;
+use orx_pinned_concurrent_col::*;
+use orx_split_vec::SplitVec;
+use prelude::IntoConcurrentPinnedVec;
+use test_case::test_matrix;
+
+#[test_matrix([fixed_vec(), doubling_vec(), linear_vec()])]
+fn append_only_concurrent_write<P: IntoConcurrentPinnedVec<String>>(vec: P) {
+    let col: PinnedConcurrentCol<_, _, AppendOnlyState<_>> =
+        PinnedConcurrentCol::new_from_pinned(vec);
+    let col = SyncCol(col);
+    let shared = &col;
+
+    std::thread::scope(|s| {
+        for _ in 0..NUM_THREADS {
+            s.spawn(move || {
+                let col = &shared.0;
+                for _ in 0..NUM_ITEMS_PER_THREAD {
+                    let idx = col.state().fetch_increment_len(1);
+                    unsafe { col.write(idx, idx.to_string()) };
+                }
+            });
+        }
+    });
+
+    let col = col.0;
+    let len = NUM_THREADS * NUM_ITEMS_PER_THREAD;
+    assert_eq!(col.state().len(), len);
+    assert_eq!(col.state().try_get_no_gap_len(), Some(len));
+
+    let vec = unsafe { col.into_inner(len) };
+    for idx in 0..len {
+        assert_eq!(vec.get(idx), Some(&idx.to_string()));
+    }
+}
+
+#[test_matrix([
+    FixedVec::new(4096),
+    SplitVec::with_doubling_growth_and_fragments_capacity(16),
+    SplitVec::with_linear_growth_and_fragments_capacity(4, 256)
+], [1, 7, 64])]
+fn append_only_concurrent_write_n_items<P: IntoConcurrentPinnedVec<String>>(vec: P, n: usize) {
+    let col: PinnedConcurrentCol<_, _, AppendOnlyState<_>> =
+        PinnedConcurrentCol::new_from_pinned(vec);
+    let col = SyncCol(col);
+    let shared = &col;
+
+    std::thread::scope(|s| {
+        for _ in 0..NUM_THREADS {
+            s.spawn(move || {
+                let col = &shared.0;
+                for _ in 0..(NUM_ITEMS_PER_THREAD / n) {
+                    let begin_idx = col.state().fetch_increment_len(n);
+                    let values = (begin_idx..(begin_idx + n)).map(|x| x.to_string());
+                    unsafe { col.write_n_items(begin_idx, n, values) };
+                }
+            });
+        }
+    });
+
+    let col = col.0;
+    let len = col.state().len();
+    assert_eq!(len, NUM_THREADS * (NUM_ITEMS_PER_THREAD / n) * n);
+    for idx in 0..len {
+        assert_eq!(unsafe { col.get(idx) }, Some(&idx.to_string()));
+    }
+}
+
+#[test_matrix([fixed_vec(), doubling_vec(), linear_vec()])]
+fn ordered_concurrent_write<P: IntoConcurrentPinnedVec<String>>(vec: P) {
+    let col: PinnedConcurrentCol<_, _, OrderedState<_>> = PinnedConcurrentCol::new_from_pinned(vec);
+    let col = SyncCol(col);
+    let shared = &col;
+
+    let len = NUM_THREADS * NUM_ITEMS_PER_THREAD;
+
+    std::thread::scope(|s| {
+        for t in 0..NUM_THREADS {
+            s.spawn(move || {
+                let col = &shared.0;
+                for i in (0..NUM_ITEMS_PER_THREAD).rev() {
+                    let idx = i * NUM_THREADS + t;
+                    unsafe { col.write(idx, idx.to_string()) };
+                }
+            });
+        }
+    });
+
+    let col = col.0;
+    assert_eq!(col.state().len(), len);
+    assert_eq!(col.state().num_written(), len);
+    assert_eq!(col.state().try_get_no_gap_len(), Some(len));
+
+    let vec = unsafe { col.into_inner(len) };
+    for idx in 0..len {
+        assert_eq!(vec.get(idx), Some(&idx.to_string()));
+    }
+}
+
+#[test_matrix([fixed_vec(), doubling_vec(), linear_vec()])]
+fn ordered_with_gaps<P: IntoConcurrentPinnedVec<String>>(vec: P) {
+    let col: PinnedConcurrentCol<_, _, OrderedState<_>> = PinnedConcurrentCol::new_from_pinned(vec);
+
+    let values = (100..110).map(|x| x.to_string());
+    unsafe { col.write_n_items(100, 10, values) };
+    unsafe { col.write(42, 42.to_string()) };
+
+    assert_eq!(col.state().len(), 110);
+    assert_eq!(col.state().num_written(), 11);
+    assert_eq!(col.state().try_get_no_gap_len(), None);
+    assert!(col.capacity() >= 110);
+
+    for idx in (0..100).filter(|x| *x != 42) {
+        unsafe { col.write(idx, idx.to_string()) };
+    }
+
+    assert_eq!(col.state().num_written(), 110);
+    assert_eq!(col.state().try_get_no_gap_len(), Some(110));
+}
+
+#[test_matrix([fixed_vec(), doubling_vec(), linear_vec()])]
+fn filled_concurrent_write<P: IntoConcurrentPinnedVec<Option<String>>>(vec: P) {
+    let col: PinnedConcurrentCol<_, _, FilledState<_>> = PinnedConcurrentCol::new_from_pinned(vec);
+    let col = SyncCol(col);
+    let shared = &col;
+
+    std::thread::scope(|s| {
+        for t in 0..NUM_THREADS {
+            s.spawn(move || {
+                let col = &shared.0;
+                for i in 0..NUM_ITEMS_PER_THREAD {
+                    let idx = (i * NUM_THREADS + t) * 2;
+                    unsafe { col.write(idx, Some(idx.to_string())) };
+                }
+            });
+        }
+    });
+
+    let col = col.0;
+    let len = (NUM_THREADS * NUM_ITEMS_PER_THREAD - 1) * 2 + 1;
+    assert_eq!(col.state().len(), len);
+
+    for idx in 0..col.capacity() {
+        let expected = match idx < len && idx % 2 == 0 {
+            true => Some(idx.to_string()),
+            false => None,
+        };
+        assert_eq!(unsafe { col.get(idx) }, Some(&expected));
+    }
+
+    let capacity = col.capacity();
+    let vec = unsafe { col.into_inner(capacity) };
+    assert_eq!(vec.len(), capacity);
+}
@@ -0,0 +1,181 @@
+mod state;
+
+use orx_fixed_vec::FixedVec;
+use orx_pinned_concurrent_col::*;
+use orx_split_vec::SplitVec;
+use prelude::IntoConcurrentPinnedVec;
+use state::MyConState;
+use test_case::test_matrix;
+
+#[test_matrix([
+    FixedVec::new(33),
+    SplitVec::with_doubling_growth_and_fragments_capacity(3),
+    SplitVec::with_linear_growth_and_fragments_capacity(2, 5)
+])]
+fn try_write_out_of_capacity<P: IntoConcurrentPinnedVec<String>>(vec: P) {
+    let col: PinnedConcurrentCol<_, _, MyConState<_>> = PinnedConcurrentCol::new_from_pinned(vec);
+    let max_cap = col.maximum_capacity();
+
+    for idx in 0..max_cap {
+        assert_eq!(unsafe { col.try_write(idx, idx.to_string()) }, Ok(()));
+    }
+
+    let result = unsafe { col.try_write(max_cap, max_cap.to_string()) };
+    assert_eq!(
+        result,
+        Err(WriteError {
+            error: PinnedConcurrentColError::OutOfMaximumCapacity {
+                required_capacity: max_cap + 1,
+                maximum_capacity: max_cap
+            },
+            value: max_cap.to_string()
+        })
+    );
+
+    for idx in 0..max_cap {
+        assert_eq!(unsafe { col.get(idx) }, Some(&idx.to_string()));
+    }
+
+    col.state().set_final_len(max_cap);
+}
+
+#[test_matrix([
+    FixedVec::new(33),
+    SplitVec::with_doubling_growth_and_fragments_capacity(3),
+    SplitVec::with_linear_growth_and_fragments_capacity(2, 5)
+])]
+fn try_write_n_items_out_of_capacity<P: IntoConcurrentPinnedVec<String>>(vec: P) {
+    let col: PinnedConcurrentCol<_, _, MyConState<_>> = PinnedConcurrentCol::new_from_pinned(vec);
+    let max_cap = col.maximum_capacity();
+
+    let len = max_cap - 2;
+    let values = (0..len).map(|x| x.to_string());
+    assert_eq!(unsafe { col.try_write_n_items(0, len, values) }, Ok(()));
+
+    let values = (len..(len + 3)).map(|x| x.to_string());
+    let result = unsafe { col.try_write_n_items(len, 3, values) };
+    assert_eq!(
+        result,
+        Err(PinnedConcurrentColError::OutOfMaximumCapacity {
+            required_capacity: max_cap + 1,
+            maximum_capacity: max_cap
+        })
+    );
+
+    let values = (len..(len + 2)).map(|x| x.to_string());
+    assert_eq!(unsafe { col.try_write_n_items(len, 2, values) }, Ok(()));
+
+    for idx in 0..max_cap {
+        assert_eq!(unsafe { col.get(idx) }, Some(&idx.to_string()));
+    }
+
+    col.state().set_final_len(max_cap);
+}
+
+#[test_matrix([
+    FixedVec::new(33),
+    SplitVec::with_doubling_growth_and_fragments_capacity(3),
+    SplitVec::with_linear_growth_and_fragments_capacity(2, 5)
+])]
+fn try_buffers_out_of_capacity<P: IntoConcurrentPinnedVec<String>>(vec: P) {
+    let col: PinnedConcurrentCol<_, _, MyConState<_>> = PinnedConcurrentCol::new_from_pinned(vec);
+    let max_cap = col.maximum_capacity();
+
+    let expected_error = PinnedConcurrentColError::OutOfMaximumCapacity {
+        required_capacity: max_cap + 1,
+        maximum_capacity: max_cap,
+    };
+
+    let result = unsafe { col.try_n_items_buffer_as_slices(max_cap - 1, 2) };
+    assert_eq!(result.err(), Some(expected_error));
+
+    let result = unsafe { col.try_n_items_buffer_as_mut_slices(max_cap - 1, 2) };
+    assert_eq!(result.err(), Some(expected_error));
+
+    let result = unsafe { col.try_single_item_as_ref(max_cap) };
+    assert_eq!(result.err(), Some(expected_error));
+
+    let result = unsafe { col.try_n_items_buffer_as_mut_slices(max_cap, 0) };
+    assert!(result.is_ok());
+}
+
+#[test]
+#[should_panic(
+    expected = "Out of capacity. Underlying pinned vector cannot grow any further while being concurrently safe."
+)]
+fn write_out_of_capacity_panics() {
+    let col: PinnedConcurrentCol<_, _, MyConState<_>> = PinnedConcurrentCol::with_fixed_capacity(4);
+    unsafe { col.write(4, "out".to_string()) };
+}
+
+#[test]
+#[should_panic(expected = "iterator is shorter than expected num_items")]
+fn write_n_items_short_iterator_panics() {
+    let col: PinnedConcurrentCol<_, _, MyConState<_>> = PinnedConcurrentCol::with_fixed_capacity(4);
+    unsafe { col.write_n_items(0, 3, ["a".to_string(), "b".to_string()]) };
+}
+
+#[test]
+fn error_display() {
+    let error = PinnedConcurrentColError::OutOfMaximumCapacity {
+        required_capacity: 5,
+        maximum_capacity: 4,
+    };
+    assert_eq!(
+        error.to_string(),
+        "Out of capacity. Underlying pinned vector cannot grow any further while being concurrently safe. Required capacity is 5 while maximum capacity is 4."
+    );
+
+    let error = PinnedConcurrentColError::RejectedByState;
+    assert_eq!(
+        error.to_string(),
+        "The concurrent state rejected the write request."
+    );
+}
+
+#[derive(Debug)]
+struct RejectingState;
+
+impl<T> ConcurrentState<T> for RejectingState {
+    fn fill_memory_with(&self) -> Option<MemoryFill<T>> {
+        None
+    }
+
+    fn new_for_pinned_vec<P: prelude::PinnedVec<T>>(_: &P) -> Self {
+        Self
+    }
+
+    fn new_for_con_pinned_vec<P: prelude::ConcurrentPinnedVec<T>>(_: &P, _: usize) -> Self {
+        Self
+    }
+
+    fn write_permit<P>(&self, _: &PinnedConcurrentCol<T, P, Self>, _: usize) -> WritePermit
+    where
+        P: prelude::ConcurrentPinnedVec<T>,
+    {
+        WritePermit::Reject
+    }
+
+    fn release_growth_handle(&self) {}
+
+    fn update_after_write(&self, _: usize, _: usize, _: SpinStrategy) {}
+
+    fn try_get_no_gap_len(&self) -> Option<usize> {
+        Some(0)
+    }
+}
+
+#[test]
+fn try_write_rejected_by_state() {
+    let col: PinnedConcurrentCol<String, _, RejectingState> =
+        PinnedConcurrentCol::with_doubling_growth();
+
+    let result = unsafe { col.try_write(0, "a".to_string()) };
+    let error = result.expect_err("state rejects all requests");
+    assert_eq!(error.error, PinnedConcurrentColError::RejectedByState);
+    assert_eq!(error.into_value(), "a".to_string());
+
+    let values = ["a".to_string(), "b".to_string()];
+    let result = unsafe { col.try_write_n_items(0, 2, values) };
+    assert_eq!(result, Err(PinnedConcurrentColError::RejectedByState));
+}
@@ -1,12 +1,49 @@
+mod common;
 mod state;
 
+use common::DropCounter;
 use orx_fixed_vec::FixedVec;
+use orx_pinned_concurrent_col::states::*;
 use orx_pinned_concurrent_col::*;
 use orx_split_vec::SplitVec;
 use prelude::IntoConcurrentPinnedVec;
 use state::MyConState;
+use std::{
+    panic::{AssertUnwindSafe, catch_unwind},
+    sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    },
+};
 use test_case::test_matrix;
 
+/// An iterator which claims to have `len` elements while yielding only `num_yielded` elements.
+struct LyingIter {
+    len: usize,
+    num_yielded: usize,
+}
+
+impl Iterator for LyingIter {
+    type Item = String;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.num_yielded {
+            0 => None,
+            _ => {
+                self.num_yielded -= 1;
+                self.len -= 1;
+                Some(self.num_yielded.to_string())
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl ExactSizeIterator for LyingIter {}
+
 #[test_matrix([
     FixedVec::new(2132),
     SplitVec::with_doubling_growth_and_fragments_capacity(16),
@@ -82,3 +119,121 @@ fn write_n_item_drop_vec<P: IntoConcurrentPinnedVec<String>>(mut vec: P, n: usiz
         assert_eq!(vec.get(idx), Some(&idx.to_string()));
     }
 }
+
+#[test_matrix([
+    FixedVec::new(2132),
+    SplitVec::with_doubling_growth_and_fragments_capacity(16),
+    SplitVec::with_linear_growth_and_fragments_capacity(10, 33)
+])]
+fn write_n_items_short_iter_rolls_back<P: IntoConcurrentPinnedVec<DropCounter>>(vec: P) {
+    let num_dropped = Arc::new(AtomicUsize::new(0));
+    let col: PinnedConcurrentCol<_, _, OrderedState<_>> = PinnedConcurrentCol::new_from_pinned(vec);
+
+    let values = (0..7).map(|_| DropCounter::new(0, &num_dropped));
+    let result = unsafe { col.try_write_n_items(10, 40, values) };
+    assert_eq!(
+        result,
+        Err(PinnedConcurrentColError::IteratorTooShort {
+            num_items: 40,
+            num_yielded: 7
+        })
+    );
+    assert_eq!(num_dropped.load(Ordering::Relaxed), 7);
+    assert_eq!(col.state().num_written(), 0);
+    for idx in 0..50 {
+        assert_eq!(col.is_written(idx), Some(false));
+    }
+
+    let values = (0..40).map(|_| DropCounter::new(0, &num_dropped));
+    let result = unsafe { col.try_write_n_items(10, 40, values) };
+    assert_eq!(result, Ok(()));
+
+    drop(col);
+    assert_eq!(num_dropped.load(Ordering::Relaxed), 47);
+}
+
+#[test]
+fn write_n_items_panicking_iter_rolls_back() {
+    let num_dropped = Arc::new(AtomicUsize::new(0));
+    let col: PinnedConcurrentCol<_, _, OrderedState<_>> =
+        PinnedConcurrentCol::with_doubling_growth();
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        let values = (0..10).map(|i| match i {
+            5 => panic!("failed to produce the value"),
+            _ => DropCounter::new(0, &num_dropped),
+        });
+        unsafe { col.write_n_items(0, 10, values) };
+    }));
+    assert!(result.is_err());
+
+    assert_eq!(num_dropped.load(Ordering::Relaxed), 5);
+    assert_eq!(col.state().num_written(), 0);
+    assert_eq!(col.is_written(0), Some(false));
+
+    drop(col);
+    assert_eq!(num_dropped.load(Ordering::Relaxed), 5);
+}
+
+#[test]
+fn write_n_items_short_iter_refills_memory() {
+    let col: PinnedConcurrentCol<Option<String>, _, FilledState<_>> =
+        PinnedConcurrentCol::with_doubling_growth();
+
+    let values = (0..3).map(|x| Some(x.to_string()));
+    let result = unsafe { col.try_write_n_items(2, 5, values) };
+    assert!(result.is_err());
+
+    for idx in 0..col.capacity() {
+        assert_eq!(unsafe { col.get(idx) }, Some(&None));
+    }
+}
+
+#[test_matrix([
+    FixedVec::new(2132),
+    SplitVec::with_doubling_growth_and_fragments_capacity(16),
+    SplitVec::with_linear_growth_and_fragments_capacity(10, 33)
+])]
+fn write_exact_n_items<P: IntoConcurrentPinnedVec<String>>(vec: P) {
+    let col: PinnedConcurrentCol<_, _, OrderedState<_>> = PinnedConcurrentCol::new_from_pinned(vec);
+
+    let values = (0..100).map(|x| x.to_string());
+    assert_eq!(unsafe { col.write_exact_n_items(0, values) }, 100);
+
+    let values = LyingIter {
+        len: 50,
+        num_yielded: 20,
+    };
+    assert_eq!(unsafe { col.write_exact_n_items(100, values) }, 20);
+
+    assert_eq!(col.state().num_written(), 120);
+    for idx in 0..200 {
+        assert_eq!(col.is_written(idx), Some(idx < 120));
+    }
+    for idx in 100..120 {
+        assert_eq!(unsafe { col.get(idx) }, Some(&(119 - idx).to_string()));
+    }
+}
+
+#[test]
+fn write_exact_n_items_panicking_iter_keeps_written() {
+    let num_dropped = Arc::new(AtomicUsize::new(0));
+    let col: PinnedConcurrentCol<_, _, OrderedState<_>> =
+        PinnedConcurrentCol::with_doubling_growth();
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        let values = (0..10).map(|i| match i {
+            5 => panic!("failed to produce the value"),
+            _ => DropCounter::new(0, &num_dropped),
+        });
+        unsafe { col.write_exact_n_items(0, values) };
+    }));
+    assert!(result.is_err());
+
+    assert_eq!(num_dropped.load(Ordering::Relaxed), 0);
+    assert_eq!(col.state().num_written(), 5);
+
+    let vec = col.try_into_inner(5).expect("first five are written");
+    drop(vec);
+    assert_eq!(num_dropped.load(Ordering::Relaxed), 5);
+}
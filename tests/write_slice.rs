@@ -0,0 +1,118 @@
+mod common;
+
+use common::{NUM_THREADS, SyncCol};
+use orx_fixed_vec::FixedVec;
+use orx_pinned_concurrent_col::states::*;
+use orx_pinned_concurrent_col::*;
+use orx_split_vec::SplitVec;
+use prelude::IntoConcurrentPinnedVec;
+use test_case::test_matrix;
+
+#[cfg(not(miri))]
+const LEN: usize = 2048;
+#[cfg(miri)]
+const LEN: usize = 157;
+
+#[test_matrix([
+    FixedVec::new(LEN),
+    SplitVec::with_doubling_growth_and_fragments_capacity(16),
+    SplitVec::with_linear_growth_and_fragments_capacity(4, 256)
+], [1, 3, 20, 151])]
+fn write_slice_across_fragments<P: IntoConcurrentPinnedVec<f64>>(vec: P, n: usize) {
+    let col: PinnedConcurrentCol<_, _, OrderedState<_>> = PinnedConcurrentCol::new_from_pinned(vec);
+    let values: Vec<_> = (0..LEN).map(|x| x as f64).collect();
+
+    for begin_idx in (0..LEN).step_by(n) {
+        let end_idx = (begin_idx + n).min(LEN);
+        unsafe { col.write_slice(begin_idx, &values[begin_idx..end_idx]) };
+    }
+    assert_eq!(col.committed_len(), Some(LEN));
+
+    let vec = col.try_into_inner(LEN).expect("is gap-free");
+    assert_eq!(vec.len(), LEN);
+    for i in 0..LEN {
+        assert_eq!(vec.get(i), Some(&(i as f64)));
+    }
+}
+
+#[test_matrix([
+    FixedVec::new(LEN),
+    SplitVec::with_doubling_growth_and_fragments_capacity(16),
+    SplitVec::with_linear_growth_and_fragments_capacity(4, 256)
+])]
+fn write_from_slices_across_fragments<P: IntoConcurrentPinnedVec<u32>>(vec: P) {
+    let col: PinnedConcurrentCol<_, _, OrderedState<_>> = PinnedConcurrentCol::new_from_pinned(vec);
+    let values: Vec<_> = (0..LEN as u32).collect();
+
+    // uneven source slices including empty ones
+    let mut slices: Vec<&[u32]> = vec![];
+    let (mut begin, mut size) = (0, 0);
+    while begin < LEN {
+        let end = (begin + size).min(LEN);
+        slices.push(&values[begin..end]);
+        (begin, size) = (end, (size * 7 + 3) % 41);
+    }
+
+    let (first, second) = slices.split_at(slices.len() / 2);
+    let first_len = first.iter().map(|x| x.len()).sum::<usize>();
+    unsafe { col.write_from_slices(first_len, second) };
+    unsafe { col.write_from_slices(0, first) };
+    unsafe { col.write_from_slices(LEN, &[]) };
+
+    let vec = col.try_into_inner(LEN).expect("is gap-free");
+    for i in 0..LEN {
+        assert_eq!(vec.get(i), Some(&(i as u32)));
+    }
+}
+
+#[test]
+fn try_write_slice_out_of_maximum_capacity() {
+    let col: PinnedConcurrentCol<u8, _, OrderedState<_>> =
+        PinnedConcurrentCol::new_from_pinned(FixedVec::new(4));
+
+    let result = unsafe { col.try_write_slice(2, &[1, 2, 3]) };
+    assert_eq!(
+        result,
+        Err(PinnedConcurrentColError::OutOfMaximumCapacity {
+            required_capacity: 5,
+            maximum_capacity: 4
+        })
+    );
+    assert_eq!(col.is_written(2), Some(false));
+
+    assert_eq!(unsafe { col.try_write_slice(1, &[1, 2, 3]) }, Ok(()));
+    assert_eq!(unsafe { col.try_write_slice(0, &[0]) }, Ok(()));
+    let vec = col.try_into_inner(4).expect("is gap-free");
+    assert_eq!(vec, &[0, 1, 2, 3]);
+}
+
+#[test_matrix([
+    FixedVec::new(NUM_THREADS * LEN),
+    SplitVec::with_doubling_growth_and_fragments_capacity(32),
+    SplitVec::with_linear_growth_and_fragments_capacity(6, 8192)
+])]
+fn concurrent_write_slice<P: IntoConcurrentPinnedVec<f64>>(vec: P) {
+    const BLOCK: usize = 100;
+    let col: PinnedConcurrentCol<_, _, AppendOnlyState<_>> =
+        PinnedConcurrentCol::new_from_pinned(vec);
+    let col = SyncCol(col);
+
+    std::thread::scope(|s| {
+        let col = &col;
+        for _ in 0..NUM_THREADS {
+            s.spawn(move || {
+                for _ in 0..LEN / BLOCK {
+                    let begin_idx = col.0.state().fetch_increment_len(BLOCK);
+                    let block: Vec<_> = (begin_idx..begin_idx + BLOCK).map(|x| x as f64).collect();
+                    unsafe { col.0.write_slice(begin_idx, &block) };
+                }
+            });
+        }
+    });
+
+    let len = col.0.state().len();
+    let vec = unsafe { col.0.into_inner(len) };
+    for i in 0..len {
+        assert_eq!(vec.get(i), Some(&(i as f64)));
+    }
+}
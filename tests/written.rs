@@ -0,0 +1,214 @@
+mod common;
+mod state;
+
+use common::{
+    DropCounter, NUM_ITEMS_PER_THREAD, NUM_THREADS, SyncCol, doubling_vec, fixed_vec, linear_vec,
+};
+use orx_pinned_concurrent_col::states::*;
+use orx_pinned_concurrent_col::*;
+use orx_pinned_vec::PinnedVec;
+use orx_split_vec::SplitVec;
+use prelude::IntoConcurrentPinnedVec;
+use state::MyConState;
+use std::sync::{
+    Arc,
+    atomic::{AtomicUsize, Ordering},
+};
+use test_case::test_matrix;
+
+#[test]
+fn tracks_written_positions() {
+    let col: PinnedConcurrentCol<String, _, MyConState<_>> =
+        PinnedConcurrentCol::with_doubling_growth();
+    assert!(!col.tracks_written_positions());
+    assert_eq!(col.is_written(0), None);
+
+    let col: PinnedConcurrentCol<String, _, OrderedState<_>> =
+        PinnedConcurrentCol::with_doubling_growth();
+    assert!(col.tracks_written_positions());
+    assert_eq!(col.is_written(0), Some(false));
+
+    unsafe { col.write(3, 3.to_string()) };
+    for idx in 0..10 {
+        assert_eq!(col.is_written(idx), Some(idx == 3));
+    }
+    assert_eq!(col.is_written(1_000_000), Some(false));
+}
+
+#[test]
+fn new_from_pinned_marks_existing_elements() {
+    let mut vec = SplitVec::new();
+    for i in 0..70 {
+        vec.push(i.to_string());
+    }
+
+    let col: PinnedConcurrentCol<_, _, OrderedState<_>> = PinnedConcurrentCol::new_from_pinned(vec);
+    for idx in 0..70 {
+        assert_eq!(col.is_written(idx), Some(true));
+    }
+    assert_eq!(col.is_written(70), Some(false));
+
+    let vec = col.try_into_inner(70).expect("is gap-free");
+    assert_eq!(vec.len(), 70);
+}
+
+#[test_matrix([fixed_vec(), doubling_vec(), linear_vec()])]
+fn write_n_items_across_words<P: IntoConcurrentPinnedVec<String>>(vec: P) {
+    let col: PinnedConcurrentCol<_, _, OrderedState<_>> = PinnedConcurrentCol::new_from_pinned(vec);
+
+    unsafe { col.write_n_items(60, 140, (60..200).map(|x| x.to_string())) };
+    for idx in 0..300 {
+        assert_eq!(col.is_written(idx), Some((60..200).contains(&idx)));
+    }
+}
+
+#[test_matrix([fixed_vec(), doubling_vec(), linear_vec()])]
+fn drop_with_gaps<P: IntoConcurrentPinnedVec<DropCounter>>(vec: P) {
+    let num_dropped = Arc::new(AtomicUsize::new(0));
+
+    let col: PinnedConcurrentCol<_, _, OrderedState<_>> = PinnedConcurrentCol::new_from_pinned(vec);
+    unsafe { col.write(5, DropCounter::new(5, &num_dropped)) };
+    unsafe { col.write(100, DropCounter::new(100, &num_dropped)) };
+    let values = (40..60).map(|x| DropCounter::new(x, &num_dropped));
+    unsafe { col.write_n_items(40, 20, values) };
+
+    assert_eq!(col.state().try_get_no_gap_len(), None);
+    drop(col);
+
+    assert_eq!(num_dropped.load(Ordering::Relaxed), 22);
+}
+
+#[test_matrix([fixed_vec(), doubling_vec(), linear_vec()])]
+fn concurrent_write_with_gaps<P: IntoConcurrentPinnedVec<DropCounter>>(vec: P) {
+    let num_dropped = Arc::new(AtomicUsize::new(0));
+
+    let col: PinnedConcurrentCol<_, _, OrderedState<_>> = PinnedConcurrentCol::new_from_pinned(vec);
+    let col = SyncCol(col);
+    let shared = &col;
+
+    std::thread::scope(|s| {
+        for t in 0..NUM_THREADS {
+            let num_dropped = &num_dropped;
+            s.spawn(move || {
+                let col = &shared.0;
+                // each thread exits early, skipping every third position
+                for i in (0..NUM_ITEMS_PER_THREAD)
+                    .rev()
+                    .filter(|i| !i.is_multiple_of(3))
+                {
+                    let idx = i * NUM_THREADS + t;
+                    unsafe { col.write(idx, DropCounter::new(idx, num_dropped)) };
+                }
+            });
+        }
+    });
+
+    let col = col.0;
+    let num_written = NUM_THREADS
+        * (0..NUM_ITEMS_PER_THREAD)
+            .filter(|i| !i.is_multiple_of(3))
+            .count();
+    assert_eq!(col.state().num_written(), num_written);
+    for idx in 0..(NUM_THREADS * NUM_ITEMS_PER_THREAD) {
+        let i = idx / NUM_THREADS;
+        assert_eq!(col.is_written(idx), Some(!i.is_multiple_of(3)));
+    }
+
+    let result = col.try_into_inner(NUM_THREADS * NUM_ITEMS_PER_THREAD);
+    assert_eq!(
+        result.err(),
+        Some(PinnedConcurrentColError::UnwrittenPosition { idx: 0 })
+    );
+    assert_eq!(num_dropped.load(Ordering::Relaxed), num_written);
+}
+
+#[test_matrix([fixed_vec(), doubling_vec(), linear_vec()])]
+fn try_into_inner<P: IntoConcurrentPinnedVec<DropCounter> + Clone>(vec: P) {
+    let num_dropped = Arc::new(AtomicUsize::new(0));
+
+    let col: PinnedConcurrentCol<_, _, OrderedState<_>> =
+        PinnedConcurrentCol::new_from_pinned(vec.clone());
+    for idx in (0..10).rev() {
+        unsafe { col.write(idx, DropCounter::new(idx, &num_dropped)) };
+    }
+    unsafe { col.write(12, DropCounter::new(12, &num_dropped)) };
+
+    let inner = col.try_into_inner(10).expect("0..10 is gap-free");
+    assert_eq!(num_dropped.load(Ordering::Relaxed), 1);
+    assert_eq!(inner.len(), 10);
+    for (idx, x) in inner.iter().enumerate() {
+        assert_eq!(x.value, idx);
+    }
+    drop(inner);
+    assert_eq!(num_dropped.load(Ordering::Relaxed), 11);
+
+    let col: PinnedConcurrentCol<_, _, OrderedState<_>> = PinnedConcurrentCol::new_from_pinned(vec);
+    for idx in (0..10).filter(|x| *x != 7) {
+        unsafe { col.write(idx, DropCounter::new(idx, &num_dropped)) };
+    }
+    let result = col.try_into_inner(10);
+    assert_eq!(
+        result.err(),
+        Some(PinnedConcurrentColError::UnwrittenPosition { idx: 7 })
+    );
+    assert_eq!(num_dropped.load(Ordering::Relaxed), 20);
+}
+
+#[test]
+fn try_into_inner_without_tracking() {
+    let col: PinnedConcurrentCol<String, _, AppendOnlyState<_>> =
+        PinnedConcurrentCol::with_doubling_growth();
+    for _ in 0..5 {
+        let idx = col.state().fetch_increment_len(1);
+        unsafe { col.write(idx, idx.to_string()) };
+    }
+    assert!(!col.tracks_written_positions());
+
+    let result = unsafe { col.clone_with_len(5) }.try_into_inner(6);
+    assert_eq!(
+        result.err(),
+        Some(PinnedConcurrentColError::UnwrittenPosition { idx: 5 })
+    );
+
+    let vec: SplitVec<String> = col.try_into_inner(5).expect("reported no-gap length is 5");
+    assert_eq!(vec.len(), 5);
+    for idx in 0..5 {
+        assert_eq!(vec.get(idx), Some(&idx.to_string()));
+    }
+}
+
+#[test]
+fn into_inner_drops_written_beyond_len() {
+    let num_dropped = Arc::new(AtomicUsize::new(0));
+
+    let col: PinnedConcurrentCol<_, _, OrderedState<_>> =
+        PinnedConcurrentCol::with_doubling_growth();
+    for idx in 0..4 {
+        unsafe { col.write(idx, DropCounter::new(idx, &num_dropped)) };
+    }
+    unsafe { col.write(9, DropCounter::new(9, &num_dropped)) };
+
+    let vec = unsafe { col.into_inner(4) };
+    assert_eq!(num_dropped.load(Ordering::Relaxed), 1);
+    assert_eq!(vec.len(), 4);
+}
+
+#[test]
+fn clear_with_gaps() {
+    let num_dropped = Arc::new(AtomicUsize::new(0));
+
+    let mut col: PinnedConcurrentCol<_, _, OrderedState<_>> =
+        PinnedConcurrentCol::with_doubling_growth();
+    unsafe { col.write(3, DropCounter::new(3, &num_dropped)) };
+    unsafe { col.write(77, DropCounter::new(77, &num_dropped)) };
+
+    unsafe { col.clear(0) };
+    assert_eq!(num_dropped.load(Ordering::Relaxed), 2);
+    assert_eq!(col.is_written(3), Some(false));
+    assert_eq!(col.is_written(77), Some(false));
+
+    unsafe { col.write(1, DropCounter::new(1, &num_dropped)) };
+    assert_eq!(col.is_written(1), Some(true));
+    drop(col);
+    assert_eq!(num_dropped.load(Ordering::Relaxed), 3);
+}